@@ -4002,6 +4002,43 @@ pub mod root {
     pub mod bindings {
         #[allow(unused_imports)]
         use self::super::super::root;
+        pub mod Error {
+            #[allow(unused_imports)]
+            use self::super::super::super::root;
+            pub const error_code_ERROR_NONE: root::bindings::Error::error_code = 0;
+            pub const error_code_ERROR_OUT_OF_MEMORY: root::bindings::Error::error_code = 1;
+            pub const error_code_ERROR_INVALID_ARGUMENT: root::bindings::Error::error_code = 2;
+            pub const error_code_ERROR_OUT_OF_RANGE: root::bindings::Error::error_code = 3;
+            pub const error_code_ERROR_ASSERTION: root::bindings::Error::error_code = 4;
+            pub const error_code_ERROR_UNKNOWN: root::bindings::Error::error_code = 5;
+            #[doc = " The error codes of the bindings, mapped to `ErrorKind` in src/error.rs."]
+            pub type error_code = u32;
+            extern "C" {
+                #[doc = " Return the code of the last error caught on the current thread, or ERROR_NONE."]
+                pub fn error_code() -> ::std::os::raw::c_int;
+            }
+            extern "C" {
+                #[doc = " Return the message of the last error caught on the current thread. The pointer is valid until the next error or error_clear."]
+                pub fn error_message() -> *const ::std::os::raw::c_char;
+            }
+            extern "C" {
+                #[doc = " Forget the last error caught on the current thread."]
+                pub fn error_clear();
+            }
+        }
+        pub mod Format {
+            #[allow(unused_imports)]
+            use self::super::super::super::root;
+            extern "C" {
+                pub fn format_block_size(Format: root::gli::format) -> usize;
+            }
+            extern "C" {
+                pub fn format_block_extent(Format: root::gli::format) -> root::gli::extent3d;
+            }
+            extern "C" {
+                pub fn format_component_count(Format: root::gli::format) -> usize;
+            }
+        }
         pub mod Texture {
             #[allow(unused_imports)]
             use self::super::super::super::root;
@@ -4149,6 +4186,34 @@ pub mod root {
                     tex: *const root::gli::texture,
                 ) -> ::std::os::raw::c_int;
             }
+            extern "C" {
+                #[doc = " Return the number of layers of the storage shared by a texture and its views, 0 for an empty texture."]
+                pub fn texture_storage_layers(tex: *const root::gli::texture) -> root::gli::texture_size_type;
+            }
+            extern "C" {
+                #[doc = " Return the number of faces of the storage shared by a texture and its views, 0 for an empty texture."]
+                pub fn texture_storage_faces(tex: *const root::gli::texture) -> root::gli::texture_size_type;
+            }
+            extern "C" {
+                #[doc = " Return the number of levels of the storage shared by a texture and its views, 0 for an empty texture."]
+                pub fn texture_storage_levels(tex: *const root::gli::texture) -> root::gli::texture_size_type;
+            }
+            extern "C" {
+                #[doc = " Copy the layers, faces and levels of a texture(or a texture view) into a new storage."]
+                pub fn texture_duplicate(tex: *const root::gli::texture) -> root::gli::texture;
+            }
+            extern "C" {
+                #[doc = " Create a texture with the target, format, layers, faces and swizzles of a texture, and a new extent and number of levels."]
+                pub fn texture_new_like(
+                    tex: *const root::gli::texture,
+                    extent: *const root::gli::texture_extent_type,
+                    levels: root::gli::texture_size_type,
+                ) -> root::gli::texture;
+            }
+            extern "C" {
+                #[doc = " Create a view of the layers, faces and levels of a texture, reinterpreted with another format."]
+                pub fn texture_view_as(tex: *const root::gli::texture, format: root::gli::format) -> root::gli::texture;
+            }
         }
         pub mod Image {
             #[allow(unused_imports)]
@@ -4207,6 +4272,10 @@ pub mod root {
                     img: *const root::gli::image,
                 ) -> ::std::os::raw::c_int;
             }
+            extern "C" {
+                #[doc = " Copy the texels of an image into a new storage."]
+                pub fn image_duplicate(img: *const root::gli::image) -> root::gli::image;
+            }
         }
         pub mod Texture1D {
             #[allow(unused_imports)]
@@ -4714,6 +4783,31 @@ pub mod root {
                     Path: *const ::std::os::raw::c_char,
                 ) -> bool;
             }
+            extern "C" {
+                pub fn save_dds_memory(
+                    Texture: *const root::gli::texture,
+                    Data: *mut *mut ::std::os::raw::c_char,
+                    Size: *mut usize,
+                ) -> bool;
+            }
+            extern "C" {
+                pub fn save_kmg_memory(
+                    Texture: *const root::gli::texture,
+                    Data: *mut *mut ::std::os::raw::c_char,
+                    Size: *mut usize,
+                ) -> bool;
+            }
+            extern "C" {
+                pub fn save_ktx_memory(
+                    Texture: *const root::gli::texture,
+                    Data: *mut *mut ::std::os::raw::c_char,
+                    Size: *mut usize,
+                ) -> bool;
+            }
+            extern "C" {
+                #[doc = " Release a buffer returned by the save_*_memory functions."]
+                pub fn save_free_memory(Data: *mut ::std::os::raw::c_char);
+            }
         }
         #[repr(C)]
         #[derive(Debug, Default)]
@@ -4768,6 +4862,15 @@ pub mod root {
                     Level: f32,
                 ) -> root::bindings::TexelType4F;
             }
+            extern "C" {
+                pub fn fsampler1d_texel_fetch_many(
+                    Sampler: *const root::gli::fsampler1D,
+                    TexelCoords: *const u32,
+                    Count: root::gli::texture_size_type,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
             extern "C" {
                 pub fn fsampler1d_target_texture(
                     Sampler: *const root::gli::fsampler1D,
@@ -4840,6 +4943,16 @@ pub mod root {
                     Level: f32,
                 ) -> root::bindings::TexelType4F;
             }
+            extern "C" {
+                pub fn fsampler1darray_texel_fetch_many(
+                    Sampler: *const root::gli::fsampler1DArray,
+                    TexelCoords: *const u32,
+                    Count: root::gli::texture_size_type,
+                    Layer: root::gli::texture_size_type,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
             extern "C" {
                 pub fn fsampler1darray_target_texture(
                     Sampler: *const root::gli::fsampler1DArray,
@@ -4911,6 +5024,24 @@ pub mod root {
                     Level: f32,
                 ) -> root::bindings::TexelType4F;
             }
+            extern "C" {
+                #[doc = " Decode every texel of a level of a texture, row by row, with the fetch functions of the samplers."]
+                #[doc = " Unlike the samplers, this also accepts the DXT1, DXT3, DXT5, BC4 and BC5 compressed formats."]
+                pub fn texture2d_decode_texels(
+                    Texture: *const root::gli::texture2d,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
+            extern "C" {
+                pub fn fsampler2d_texel_fetch_many(
+                    Sampler: *const root::gli::fsampler2D,
+                    TexelCoords: *const [u32; 2usize],
+                    Count: root::gli::texture_size_type,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
             extern "C" {
                 pub fn fsampler2d_target_texture(
                     Sampler: *const root::gli::fsampler2D,
@@ -4983,6 +5114,16 @@ pub mod root {
                     Level: f32,
                 ) -> root::bindings::TexelType4F;
             }
+            extern "C" {
+                pub fn fsampler2darray_texel_fetch_many(
+                    Sampler: *const root::gli::fsampler2DArray,
+                    TexelCoords: *const [u32; 2usize],
+                    Count: root::gli::texture_size_type,
+                    Layer: root::gli::texture_size_type,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
             extern "C" {
                 pub fn fsampler2darray_target_texture(
                     Sampler: *const root::gli::fsampler2DArray,
@@ -5054,6 +5195,24 @@ pub mod root {
                     Level: f32,
                 ) -> root::bindings::TexelType4F;
             }
+            extern "C" {
+                #[doc = " Decode every texel of a level of a texture, row by row, with the fetch functions of the samplers."]
+                #[doc = " Unlike the samplers, this also accepts the DXT1, DXT3, DXT5, BC4 and BC5 compressed formats."]
+                pub fn texture3d_decode_texels(
+                    Texture: *const root::gli::texture3d,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
+            extern "C" {
+                pub fn fsampler3d_texel_fetch_many(
+                    Sampler: *const root::gli::fsampler3D,
+                    TexelCoords: *const [u32; 3usize],
+                    Count: root::gli::texture_size_type,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
             extern "C" {
                 pub fn fsampler3d_target_texture(
                     Sampler: *const root::gli::fsampler3D,
@@ -5126,6 +5285,16 @@ pub mod root {
                     Level: f32,
                 ) -> root::bindings::TexelType4F;
             }
+            extern "C" {
+                pub fn fsampler_cube_texel_fetch_many(
+                    Sampler: *const root::gli::fsamplerCube,
+                    TexelCoords: *const [u32; 2usize],
+                    Count: root::gli::texture_size_type,
+                    Face: root::gli::texture_size_type,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
             extern "C" {
                 pub fn fsampler_cube_target_texture(
                     Sampler: *const root::gli::fsamplerCube,
@@ -5203,6 +5372,17 @@ pub mod root {
                     Level: f32,
                 ) -> root::bindings::TexelType4F;
             }
+            extern "C" {
+                pub fn fsampler_cube_array_texel_fetch_many(
+                    Sampler: *const root::gli::fsamplerCubeArray,
+                    TexelCoords: *const [u32; 2usize],
+                    Count: root::gli::texture_size_type,
+                    Layer: root::gli::texture_size_type,
+                    Face: root::gli::texture_size_type,
+                    Level: root::gli::texture_size_type,
+                    Texels: *mut root::bindings::TexelType4F,
+                );
+            }
             extern "C" {
                 pub fn fsampler_cube_array_target_texture(
                     Sampler: *const root::gli::fsamplerCubeArray,
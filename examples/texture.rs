@@ -22,7 +22,7 @@ fn main() {
     println!();
 
     { // access the base level image of the texture.
-        let image: GliImage = texture.get_level(0);
+        let image: GliImage = texture.get_level(0).unwrap();
         println!("Base level image info:");
         println!("\tExtent: ({}, {})", image.extent().width, image.extent().height);
         println!("\tFormat: {}", image.format());
@@ -32,7 +32,7 @@ fn main() {
     }
 
     { // access the level 1 image of the texture.
-        let image: GliImage = texture.get_level(1);
+        let image: GliImage = texture.get_level(1).unwrap();
         println!("Level 1 image info:");
         println!("\tExtent: ({}, {})", image.extent().width, image.extent().height);
         println!("\tFormat: {}", image.format());
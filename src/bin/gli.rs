@@ -0,0 +1,375 @@
+
+//! Command line tool to inspect and convert DDS, KTX and KMG files.
+//!
+//! Build it with `cargo build --features cli`.
+
+use gli_rs as gli;
+use gli::{AnyTexture, GliTexture, Format, with_texture};
+use gli::target::Target;
+use gli::{Texture1D, Texture1DArray, Texture2D, Texture2DArray, Texture3D, TextureCube, TextureCubeArray};
+
+use clap::{Arg, ArgMatches, Command};
+
+use std::fmt;
+use std::process;
+use std::slice;
+
+type CliResult<T> = Result<T, String>;
+
+fn main() {
+
+    let matches = Command::new("gli")
+        .about("Inspect and convert DDS, KTX and KMG texture files.")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(Command::new("info")
+            .about("Print the target, format, extent, levels, layers, faces and sizes of a texture.")
+            .arg(Arg::new("input").required(true)))
+        .subcommand(Command::new("convert")
+            .about("Save a texture to the container given by the output extension, optionally in another uncompressed format.")
+            .arg(Arg::new("input").required(true))
+            .arg(Arg::new("output").required(true))
+            .arg(Arg::new("format").long("format").help("The name of the destination format, e.g. RGBA8_UNORM_PACK8.")))
+        .subcommand(Command::new("mipgen")
+            .about("Generate the complete mipmap chain of a texture from its base level.")
+            .arg(Arg::new("input").required(true))
+            .arg(Arg::new("output").required(true))
+            .arg(Arg::new("filter").long("filter").default_value("linear")
+                .value_parser(["nearest", "linear", "cubic-bspline", "catmull-rom"])))
+        .subcommand(Command::new("extract")
+            .about("Save a single layer, face and level of a texture.")
+            .arg(Arg::new("input").required(true))
+            .arg(Arg::new("output").required(true))
+            .arg(Arg::new("layer").long("layer").default_value("0").value_parser(clap::value_parser!(usize)))
+            .arg(Arg::new("face").long("face").default_value("0").value_parser(clap::value_parser!(usize)))
+            .arg(Arg::new("level").long("level").default_value("0").value_parser(clap::value_parser!(usize))))
+        .subcommand(Command::new("diff")
+            .about("Compare two textures, exiting with a non zero status if they differ.")
+            .arg(Arg::new("first").required(true))
+            .arg(Arg::new("second").required(true)))
+        .get_matches();
+
+    let result = match matches.subcommand() {
+        | Some(("info", args))    => info(args),
+        | Some(("convert", args)) => convert(args),
+        | Some(("mipgen", args))  => mipgen(args),
+        | Some(("extract", args)) => extract(args),
+        | Some(("diff", args))    => diff(args),
+        | _ => unreachable!("A subcommand is required."),
+    };
+
+    match result {
+        | Ok(true) => {},
+        | Ok(false) => process::exit(1),
+        | Err(message) => {
+            eprintln!("error: {}", message);
+            process::exit(2);
+        },
+    }
+}
+
+fn info(args: &ArgMatches) -> CliResult<bool> {
+
+    let texture = load(args, "input")?;
+    with_texture!(&texture, texture => print_info(texture));
+    Ok(true)
+}
+
+fn print_info<T>(texture: &T) where T: GliTexture, T::ExtentType: fmt::Debug {
+
+    println!("Target: {}", texture.target());
+    println!("Format: {}", texture.format());
+    println!("Levels: {}", texture.levels());
+    println!("Layers: {}", texture.layers());
+    println!("Faces : {}", texture.faces());
+    println!("Size  : {} bytes", texture.size());
+
+    for level in 0..texture.levels() {
+        println!("\tLevel {}: {:?}, {} bytes", level, texture.extent(level), texture.size_at_level(level));
+    }
+}
+
+fn convert(args: &ArgMatches) -> CliResult<bool> {
+
+    let texture = load(args, "input")?;
+
+    let converted = match args.get_one::<String>("format") {
+        | Some(name) => {
+            let format: Format = name.parse().map_err(|e: gli::Error| e.to_string())?;
+            let src_format = texture_format(&texture);
+            if src_format.is_compressed() || format.is_compressed() {
+                return Err(format!("Converting from {} to {} is not supported, only uncompressed formats can be converted.", src_format, format))
+            }
+
+            let mut converted = new_like(&texture, format, false)?;
+            convert_texels(&texture, &mut converted)?;
+            converted
+        },
+        | None => texture,
+    };
+
+    save(&converted, args, "output")?;
+    Ok(true)
+}
+
+fn mipgen(args: &ArgMatches) -> CliResult<bool> {
+
+    let texture = load(args, "input")?;
+    let format = texture_format(&texture);
+    if format.is_compressed() {
+        return Err(format!("Generating the mipmaps of the compressed format {} is not supported.", format))
+    }
+
+    let filter = match args.get_one::<String>("filter").map(String::as_str) {
+        | Some("nearest")       => gli::sampler::Filter::NEAREST,
+        | Some("cubic-bspline") => gli::sampler::Filter::CUBIC_BSPLINE,
+        | Some("catmull-rom")   => gli::sampler::Filter::CATMULL_ROM,
+        | _                     => gli::sampler::Filter::LINEAR,
+    };
+
+    let mut chain = new_like(&texture, format, true)?;
+
+    // Copy the base level of every layer and face, then filter the other levels from it.
+    with_texture!(&texture, src => with_texture!(&mut chain, dst => {
+        for layer in 0..src.layers() {
+            for face in 0..src.faces() {
+                unsafe {
+                    let bytes = slice::from_raw_parts(src.data_detail(layer, face, 0) as *const u8, src.size_at_level(0));
+                    let dst_bytes = slice::from_raw_parts_mut(dst.data_detail_mut(layer, face, 0) as *mut u8, dst.size_at_level(0));
+                    dst_bytes.copy_from_slice(bytes);
+                }
+            }
+        }
+    }));
+
+    generate_mipmaps(&mut chain, filter)?;
+
+    save(&chain, args, "output")?;
+    Ok(true)
+}
+
+fn extract(args: &ArgMatches) -> CliResult<bool> {
+
+    let texture = load(args, "input")?;
+
+    let layer = *args.get_one::<usize>("layer").unwrap();
+    let face  = *args.get_one::<usize>("face").unwrap();
+    let level = *args.get_one::<usize>("level").unwrap();
+
+    let (layers, faces, levels) = with_texture!(&texture, texture => (texture.layers(), texture.faces(), texture.levels()));
+    if layer >= layers || face >= faces || level >= levels {
+        return Err(format!("The texture has {} layers, {} faces and {} levels, the layer {}, face {} and level {} do not exist.", layers, faces, levels, layer, face, level))
+    }
+
+    let format = texture_format(&texture);
+    let slice = if texture.target() == Target::TARGET_3D {
+        AnyTexture::Texture3D(with_texture!(&texture, texture => Texture3D::share_from_detail(texture, format, layer, layer, face, face, level, level)).map_err(|e| e.to_string())?)
+    } else {
+        AnyTexture::Texture2D(with_texture!(&texture, texture => Texture2D::share_from_detail(texture, format, layer, layer, face, face, level, level)).map_err(|e| e.to_string())?)
+    };
+
+    save(&slice, args, "output")?;
+    Ok(true)
+}
+
+fn diff(args: &ArgMatches) -> CliResult<bool> {
+
+    let first  = load(args, "first")?;
+    let second = load(args, "second")?;
+
+    let describe = |texture: &AnyTexture| with_texture!(texture, texture => {
+        (texture.target(), texture.format(), texture.layers(), texture.faces(), texture.levels())
+    });
+    let (first_shape, second_shape) = (describe(&first), describe(&second));
+
+    if first_shape != second_shape {
+        println!("The textures differ in their target, format, layers, faces or levels:");
+        println!("\t{:?}", first_shape);
+        println!("\t{:?}", second_shape);
+        return Ok(false)
+    }
+
+    let (_, _, layers, faces, levels) = first_shape;
+    let mut identical = true;
+
+    for layer in 0..layers {
+        for face in 0..faces {
+            for level in 0..levels {
+
+                let first_bytes  = with_texture!(&first, texture => subresource_bytes(texture, layer, face, level));
+                let second_bytes = with_texture!(&second, texture => subresource_bytes(texture, layer, face, level));
+
+                if first_bytes.len() != second_bytes.len() {
+                    println!("Layer {}, face {}, level {}: the extents differ.", layer, face, level);
+                    identical = false;
+                    continue
+                }
+
+                let different_bytes = first_bytes.iter().zip(second_bytes.iter()).filter(|(a, b)| a != b).count();
+                if different_bytes > 0 {
+                    println!("Layer {}, face {}, level {}: {} of {} bytes differ.", layer, face, level, different_bytes, first_bytes.len());
+                    identical = false;
+                }
+            }
+        }
+    }
+
+    if identical {
+        println!("The textures are identical.");
+    }
+
+    Ok(identical)
+}
+
+fn load(args: &ArgMatches, name: &str) -> CliResult<AnyTexture> {
+
+    let path = args.get_one::<String>(name).unwrap();
+    gli::load_any(path).map_err(|e| format!("Failed to load {}: {}", path, e))
+}
+
+fn save(texture: &AnyTexture, args: &ArgMatches, name: &str) -> CliResult<()> {
+
+    let path = args.get_one::<String>(name).unwrap();
+    with_texture!(texture, texture => gli::save(texture, path)).map_err(|e| format!("Failed to save {}: {}", path, e))
+}
+
+fn texture_format(texture: &AnyTexture) -> Format {
+    with_texture!(texture, texture => texture.format())
+}
+
+fn subresource_bytes<T: GliTexture>(texture: &T, layer: usize, face: usize, level: usize) -> &[u8] {
+    unsafe { slice::from_raw_parts(texture.data_detail(layer, face, level) as *const u8, texture.size_at_level(level)) }
+}
+
+/// Copy `bytes` into a layer, face and level of `texture`, which must have the same size.
+fn write_subresource(texture: &mut AnyTexture, layer: usize, face: usize, level: usize, bytes: &[u8]) {
+    with_texture!(texture, texture => unsafe {
+        slice::from_raw_parts_mut(texture.data_detail_mut(layer, face, level) as *mut u8, texture.size_at_level(level)).copy_from_slice(bytes)
+    })
+}
+
+/// Create a texture with the same target, extent, layers and faces as `texture`.
+///
+/// The new texture has a complete mipmap chain if `mipmap_chain` is true, and the levels of `texture` otherwise.
+fn new_like(texture: &AnyTexture, format: Format, mipmap_chain: bool) -> CliResult<AnyTexture> {
+
+    let texture = match (texture, mipmap_chain) {
+        | (AnyTexture::Texture1D(t), false)        => Texture1D::new(format, t.extent(0), t.levels()).map(AnyTexture::Texture1D),
+        | (AnyTexture::Texture1D(t), true)         => Texture1D::new_with_mipmap_chain(format, t.extent(0)).map(AnyTexture::Texture1D),
+        | (AnyTexture::Texture1DArray(t), false)   => Texture1DArray::new(format, t.extent(0), t.layers(), t.levels()).map(AnyTexture::Texture1DArray),
+        | (AnyTexture::Texture1DArray(t), true)    => Texture1DArray::new_with_mipmap_chain(format, t.extent(0), t.layers()).map(AnyTexture::Texture1DArray),
+        | (AnyTexture::Texture2D(t), false)        => Texture2D::new(format, t.extent(0), t.levels()).map(AnyTexture::Texture2D),
+        | (AnyTexture::Texture2D(t), true)         => Texture2D::new_with_mipmap_chain(format, t.extent(0)).map(AnyTexture::Texture2D),
+        | (AnyTexture::Texture2DArray(t), false)   => Texture2DArray::new(format, t.extent(0), t.layers(), t.levels()).map(AnyTexture::Texture2DArray),
+        | (AnyTexture::Texture2DArray(t), true)    => Texture2DArray::new_with_mipmap_chain(format, t.extent(0), t.layers()).map(AnyTexture::Texture2DArray),
+        | (AnyTexture::Texture3D(t), false)        => Texture3D::new(format, t.extent(0), t.levels()).map(AnyTexture::Texture3D),
+        | (AnyTexture::Texture3D(t), true)         => Texture3D::new_with_mipmap_chain(format, t.extent(0)).map(AnyTexture::Texture3D),
+        | (AnyTexture::TextureCube(t), false)      => TextureCube::new(format, t.extent(0), t.levels()).map(AnyTexture::TextureCube),
+        | (AnyTexture::TextureCube(t), true)       => TextureCube::new_with_mipmap_chain(format, t.extent(0)).map(AnyTexture::TextureCube),
+        | (AnyTexture::TextureCubeArray(t), false) => TextureCubeArray::new(format, t.extent(0), t.layers(), t.levels()).map(AnyTexture::TextureCubeArray),
+        | (AnyTexture::TextureCubeArray(t), true)  => TextureCubeArray::new_with_mipmap_chain(format, t.extent(0), t.layers()).map(AnyTexture::TextureCubeArray),
+    };
+
+    texture.map_err(|e| e.to_string())
+}
+
+/// Convert the texels of `src` to the format of `dst`, which must have the same shape.
+///
+/// Each layer, face and level is viewed as a 2d texture (or a 3d texture for 3d targets), converted through float
+/// samplers into a texture of its own, then copied into `dst`.
+#[cfg(not(target_os = "windows"))]
+fn convert_texels(src: &AnyTexture, dst: &mut AnyTexture) -> CliResult<()> {
+
+    use gli::sampler::{FSampler2D, FSampler3D, Wrap, Filter};
+    use gli::{Extent2d, Extent3d};
+
+    let (src_format, dst_format) = (texture_format(src), texture_format(dst));
+    let (layers, faces, levels) = with_texture!(src, texture => (texture.layers(), texture.faces(), texture.levels()));
+
+    for layer in 0..layers {
+        for face in 0..faces {
+            for level in 0..levels {
+
+                if src.target() == Target::TARGET_3D {
+
+                    let src_view = with_texture!(src, texture => Texture3D::share_from_detail(texture, src_format.clone(), 0, 0, 0, 0, level, level)).map_err(|e| e.to_string())?;
+                    let extent = src_view.extent(0);
+                    let mut converted = Texture3D::new(dst_format.clone(), extent, 1).map_err(|e| e.to_string())?;
+                    let src_sampler = FSampler3D::new(&src_view, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+                    let mut dst_sampler = FSampler3D::new(&mut converted, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+
+                    for z in 0..extent.depth {
+                        for y in 0..extent.height {
+                            for x in 0..extent.width {
+                                let coord = Extent3d { width: x, height: y, depth: z };
+                                dst_sampler.texel_write(coord, 0, src_sampler.texel_fetch(coord, 0));
+                            }
+                        }
+                    }
+
+                    drop(dst_sampler);
+                    write_subresource(dst, layer, face, level, subresource_bytes(&converted, 0, 0, 0));
+                } else {
+
+                    let src_view = with_texture!(src, texture => Texture2D::share_from_detail(texture, src_format.clone(), layer, layer, face, face, level, level)).map_err(|e| e.to_string())?;
+                    let extent = src_view.extent(0);
+                    let mut converted = Texture2D::new(dst_format.clone(), extent, 1).map_err(|e| e.to_string())?;
+                    let src_sampler = FSampler2D::new(&src_view, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+                    let mut dst_sampler = FSampler2D::new(&mut converted, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+
+                    for y in 0..extent.height {
+                        for x in 0..extent.width {
+                            let coord = Extent2d { width: x, height: y };
+                            dst_sampler.texel_write(coord, 0, src_sampler.texel_fetch(coord, 0));
+                        }
+                    }
+
+                    drop(dst_sampler);
+                    write_subresource(dst, layer, face, level, subresource_bytes(&converted, 0, 0, 0));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate the levels of `texture` from its base level, for every layer and face.
+///
+/// Except for 3d textures, the levels of each layer and face are generated in a 2d texture of their own, then copied into `texture`.
+#[cfg(not(target_os = "windows"))]
+fn generate_mipmaps(texture: &mut AnyTexture, filter: gli::sampler::Filter) -> CliResult<()> {
+
+    use gli::sampler::{FSampler2D, FSampler3D, Wrap, Filter};
+
+    let format = texture_format(texture);
+
+    if let AnyTexture::Texture3D(texture) = texture {
+        FSampler3D::new(texture, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR).generate_mipmaps(filter);
+        return Ok(())
+    }
+
+    let (layers, faces, levels) = with_texture!(&*texture, texture => (texture.layers(), texture.faces(), texture.levels()));
+    for layer in 0..layers {
+        for face in 0..faces {
+            let mut chain = with_texture!(&*texture, texture => Texture2D::share_from_detail(texture, format.clone(), layer, layer, face, face, 0, levels - 1)).map_err(|e| e.to_string())?;
+            FSampler2D::new(&mut chain, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR).generate_mipmaps(filter.clone());
+
+            for level in 1..levels {
+                write_subresource(texture, layer, face, level, subresource_bytes(&chain, 0, 0, level));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(target_os = "windows")]
+fn convert_texels(_src: &AnyTexture, _dst: &mut AnyTexture) -> CliResult<()> {
+    Err(String::from("Format conversion relies on the samplers, which are not available on Windows."))
+}
+
+#[cfg(target_os = "windows")]
+fn generate_mipmaps(_texture: &mut AnyTexture, _filter: gli::sampler::Filter) -> CliResult<()> {
+    Err(String::from("Mipmaps generation relies on the samplers, which are not available on Windows."))
+}
@@ -2,6 +2,10 @@
 use crate::ffi::root::gli;
 use crate::ffi::root::bindings::DX as bindings;
 
+pub use self::footprint::{PlacedFootprints, PlacedSubresourceFootprint, TEXTURE_DATA_PITCH_ALIGNMENT, TEXTURE_DATA_PLACEMENT_ALIGNMENT};
+
+mod footprint;
+
 /// Translation class to convert GLI enums into DirectX enums.
 #[repr(transparent)]
 pub struct DxConverter {
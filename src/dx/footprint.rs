@@ -0,0 +1,126 @@
+
+//! Direct3D 12 placed footprints of gli textures, as computed by `ID3D12Device::GetCopyableFootprints`.
+
+use crate::ffi::root::glm;
+use crate::ffi::root::bindings::Texture as texture_bindings;
+use crate::target::Target;
+use crate::texture::GliTexture;
+
+use std::slice;
+
+/// The alignment of the row pitch of a footprint, `D3D12_TEXTURE_DATA_PITCH_ALIGNMENT`.
+pub const TEXTURE_DATA_PITCH_ALIGNMENT: u64 = 256;
+/// The alignment of the offset of a footprint, `D3D12_TEXTURE_DATA_PLACEMENT_ALIGNMENT`.
+pub const TEXTURE_DATA_PLACEMENT_ALIGNMENT: u64 = 512;
+
+/// The placement of a single subresource in a buffer, like `D3D12_PLACED_SUBRESOURCE_FOOTPRINT`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PlacedSubresourceFootprint {
+    /// The Direct3D 12 subresource index, `level + (layer * faces + face) * levels`.
+    pub subresource: u32,
+    /// The offset in bytes of the subresource in the buffer.
+    pub offset: u64,
+    /// The width of the subresource in texels, rounded up to whole blocks for compressed formats.
+    pub width: u32,
+    /// The height of the subresource in texels, rounded up to whole blocks for compressed formats.
+    pub height: u32,
+    pub depth: u32,
+    /// The distance in bytes between two rows of blocks in the buffer.
+    pub row_pitch: u32,
+    /// The number of rows of blocks of a depth slice.
+    pub num_rows: u32,
+    /// The size in bytes of the data of a row of blocks, without padding.
+    pub row_size: u64,
+}
+
+/// The placed footprints of all the subresources of a texture in a single buffer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PlacedFootprints {
+    /// The footprints in the order of their subresource index.
+    pub footprints: Vec<PlacedSubresourceFootprint>,
+    /// The size in bytes of the buffer holding all the subresources.
+    pub total_size: u64,
+}
+
+impl PlacedFootprints {
+
+    /// Compute the footprints of all the layers, faces and levels of `texture`.
+    ///
+    /// The faces of cube maps are array slices, as in Direct3D 12.
+    /// Planar formats, like the depth-stencil ones, are laid out as a single plane.
+    pub fn new<T: GliTexture>(texture: &T) -> PlacedFootprints {
+
+        let format = texture.format();
+        let block_size = format.block_size() as u64;
+        let block_extent = format.block_extent();
+        let is_3d = texture.target() == Target::TARGET_3D;
+
+        let (layers, faces, levels) = (texture.layers(), texture.faces(), texture.levels());
+        let mut footprints = Vec::with_capacity(layers * faces * levels);
+        let mut total_size = 0;
+
+        for array_slice in 0..(layers * faces) {
+            for level in 0..levels {
+
+                let extent: glm::ivec3 = unsafe { texture_bindings::texture_extent(texture.raw_texture(), level) };
+                let blocks_x = extent[0].div_ceil(block_extent.width);
+                let num_rows = extent[1].div_ceil(block_extent.height);
+                let depth = if is_3d { extent[2] } else { 1 };
+
+                let row_size = blocks_x as u64 * block_size;
+                let row_pitch = align_up(row_size, TEXTURE_DATA_PITCH_ALIGNMENT);
+                let offset = align_up(total_size, TEXTURE_DATA_PLACEMENT_ALIGNMENT);
+
+                footprints.push(PlacedSubresourceFootprint {
+                    subresource: (level + array_slice * levels) as u32,
+                    offset,
+                    width : blocks_x * block_extent.width,
+                    height: num_rows * block_extent.height,
+                    depth,
+                    row_pitch: row_pitch as u32,
+                    num_rows,
+                    row_size,
+                });
+
+                // The last row is not padded.
+                total_size = offset + row_pitch * (num_rows as u64 * depth as u64 - 1) + row_size;
+            }
+        }
+
+        PlacedFootprints { footprints, total_size }
+    }
+
+    /// Copy the subresources of `texture` to `buffer`, laid out as described by the footprints.
+    ///
+    /// `texture` must be the texture the footprints were computed from, and `buffer` must be at least `total_size` bytes long.
+    /// The padding bytes of `buffer` are left untouched.
+    pub fn copy_to<T: GliTexture>(&self, texture: &T, buffer: &mut [u8]) {
+
+        assert!(buffer.len() as u64 >= self.total_size, "The buffer is smaller than the footprints.");
+
+        let (faces, levels) = (texture.faces(), texture.levels());
+        assert_eq!(self.footprints.len(), texture.layers() * faces * levels, "The footprints do not match the texture.");
+
+        for (index, footprint) in self.footprints.iter().enumerate() {
+
+            let (array_slice, level) = (index / levels, index % levels);
+            let (layer, face) = (array_slice / faces, array_slice % faces);
+
+            let src = unsafe {
+                slice::from_raw_parts(texture.data_detail(layer, face, level) as *const u8, texture.size_at_level(level))
+            };
+
+            let row_size = footprint.row_size as usize;
+            let rows = (footprint.num_rows * footprint.depth) as usize;
+
+            for (row, src_row) in src.chunks_exact(row_size).take(rows).enumerate() {
+                let offset = footprint.offset as usize + row * footprint.row_pitch as usize;
+                buffer[offset..(offset + row_size)].copy_from_slice(src_row);
+            }
+        }
+    }
+}
+
+fn align_up(value: u64, alignment: u64) -> u64 {
+    value.div_ceil(alignment) * alignment
+}
@@ -2,10 +2,14 @@
 #![allow(dead_code)]
 
 use failure::{Backtrace, Context, Fail};
+use std::ffi::CStr;
 use std::fmt;
+use std::io;
 use std::path::{Path, PathBuf};
 use std::result;
 
+use crate::ffi::root::bindings::Error as bindings;
+
 /// A type alias for handling errors throughout this crate.
 pub type Result<T> = result::Result<T, Error>;
 
@@ -29,9 +33,60 @@ impl Error {
         Error::from(ErrorKind::SaveTexture(msg.as_ref().to_string()))
     }
 
+    pub fn unsupported_format(msg: impl AsRef<str>) -> Error {
+        Error::from(ErrorKind::UnsupportedFormat(msg.as_ref().to_string()))
+    }
+
+    pub fn invalid_argument(msg: impl AsRef<str>) -> Error {
+        Error::from(ErrorKind::InvalidArgument(msg.as_ref().to_string()))
+    }
+
     pub fn bug<T: AsRef<str>>(msg: T) -> Error {
         Error::from(ErrorKind::Bug(msg.as_ref().to_string()))
     }
+
+    pub fn out_of_memory(msg: impl AsRef<str>) -> Error {
+        Error::from(ErrorKind::OutOfMemory(msg.as_ref().to_string()))
+    }
+
+    pub fn assertion(msg: impl AsRef<str>) -> Error {
+        Error::from(ErrorKind::Assertion(msg.as_ref().to_string()))
+    }
+
+    pub fn exception(msg: impl AsRef<str>) -> Error {
+        Error::from(ErrorKind::Exception(msg.as_ref().to_string()))
+    }
+
+    /// Wrap an error that occurred while loading or saving the file at `path`.
+    ///
+    /// The returned error is of kind `ErrorKind::Path`, and its cause is this error.
+    pub(crate) fn with_path(self, path: impl AsRef<Path>) -> Error {
+        Error::from(self.context(ErrorKind::path(path)))
+    }
+}
+
+/// Call a binding, and return the exception or the failed assertion it caught on the C++ side as an error.
+pub(crate) fn catch_ffi<T>(call: impl FnOnce() -> T) -> Result<T> {
+
+    unsafe { bindings::error_clear(); }
+    let value = call();
+
+    let code = unsafe { bindings::error_code() } as bindings::error_code;
+    if code == bindings::error_code_ERROR_NONE {
+        return Ok(value)
+    }
+
+    let msg = unsafe { CStr::from_ptr(bindings::error_message()) }.to_string_lossy().into_owned();
+    unsafe { bindings::error_clear(); }
+
+    let error = match code {
+        | bindings::error_code_ERROR_OUT_OF_MEMORY => Error::out_of_memory(msg),
+        | bindings::error_code_ERROR_INVALID_ARGUMENT
+        | bindings::error_code_ERROR_OUT_OF_RANGE  => Error::invalid_argument(msg),
+        | bindings::error_code_ERROR_ASSERTION     => Error::assertion(msg),
+        | _                                        => Error::exception(msg),
+    };
+    Err(error)
 }
 
 impl Fail for Error {
@@ -47,7 +102,11 @@ impl Fail for Error {
 impl fmt::Display for Error {
 
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.ctx.fmt(f)
+        self.ctx.fmt(f)?;
+        if let Some(cause) = self.ctx.cause() {
+            write!(f, ": {}", cause)?;
+        }
+        Ok(())
     }
 }
 
@@ -61,9 +120,24 @@ pub enum ErrorKind {
     /// An error that occurred while saving texture to specific image format.
     SaveTexture(String),
 
+    /// An error that occurred because the format of a texture is not supported by an operation.
+    UnsupportedFormat(String),
+
+    /// An error that occurred because the arguments of an operation are inconsistent or out of range.
+    InvalidArgument(String),
+
     /// An error that occurred while working with a file path.
     Path(PathBuf),
 
+    /// An error that occurred because gli failed to allocate the storage of a texture or an image.
+    OutOfMemory(String),
+
+    /// An error that occurred because an assertion of gli failed, usually due to an argument it does not support.
+    Assertion(String),
+
+    /// An error that occurred because gli threw an unexpected exception.
+    Exception(String),
+
     /// Generally, these errors correspond to bugs in this library.
     Bug(String),
 
@@ -90,9 +164,24 @@ impl fmt::Display for ErrorKind {
             | ErrorKind::SaveTexture(ref msg) => {
                 write!(f, "Save texture error: {}", msg)
             },
+            | ErrorKind::UnsupportedFormat(ref msg) => {
+                write!(f, "Unsupported format: {}", msg)
+            },
+            | ErrorKind::InvalidArgument(ref msg) => {
+                write!(f, "Invalid argument: {}", msg)
+            },
             | ErrorKind::Path(ref path) => {
                 write!(f, "{}", path.display())
             },
+            | ErrorKind::OutOfMemory(ref msg) => {
+                write!(f, "Out of memory: {}", msg)
+            },
+            | ErrorKind::Assertion(ref msg) => {
+                write!(f, "Assertion failed: {}", msg)
+            },
+            | ErrorKind::Exception(ref msg) => {
+                write!(f, "Exception: {}", msg)
+            },
             | ErrorKind::Bug(ref msg) => {
                 let report = "Please report this bug with a backtrace for this repository";
                 write!(f, "Bug: {}\n{}", msg, report)
@@ -117,3 +206,10 @@ impl From<Context<ErrorKind>> for Error {
         Error { ctx }
     }
 }
+
+impl From<io::Error> for Error {
+
+    fn from(error: io::Error) -> Error {
+        Error::from(error.context(ErrorKind::Io))
+    }
+}
@@ -34,6 +34,13 @@ impl From<[u32; 3]> for Extent1d {
     }
 }
 
+impl From<Extent1d> for [u32; 3] {
+
+    fn from(v: Extent1d) -> [u32; 3] {
+        [v.width, 1, 1]
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Extent2d {
     pub width : u32,
@@ -61,6 +68,13 @@ impl From<[u32; 3]> for Extent2d {
     }
 }
 
+impl From<Extent2d> for [u32; 3] {
+
+    fn from(v: Extent2d) -> [u32; 3] {
+        [v.width, v.height, 1]
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Extent3d {
     pub width : u32,
@@ -1,6 +1,10 @@
 
 use crate::ffi::root::gli;
+use crate::ffi::root::glm;
+use crate::ffi::root::bindings::Format as bindings;
+use crate::extent::Extent3d;
 
+use std::convert::TryFrom;
 use std::fmt;
 
 pub enum TexFormatType {
@@ -10,7 +14,7 @@ pub enum TexFormatType {
 }
 
 /// Texture data format.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 #[repr(transparent)]
 pub struct Format(pub(crate) gli::format);
 
@@ -40,22 +44,44 @@ impl Format {
         unsafe { gli::is_srgb(self.0) }
     }
 
-    // TODO: Implement block_size, block_extent and component_count.
-//    /// Return the size in bytes of a block for a format.
-//    #[inline]
-//    pub fn block_size(&self) -> usize {
-//        unsafe { gli::block_size(self.0) }
-//    }
+    /// Return the sRGB variant of a `UNORM` format, like `RGBA8_SRGB_PACK8` for `RGBA8_UNORM_PACK8`, or `None` if it has none.
+    pub fn to_srgb(&self) -> Option<Format> {
+        self.variant("_UNORM_", "_SRGB_")
+    }
+
+    /// Return the `UNORM` variant of an sRGB format, like `RGBA8_UNORM_PACK8` for `RGBA8_SRGB_PACK8`, or `None` if it has none.
+    pub fn to_linear(&self) -> Option<Format> {
+        self.variant("_SRGB_", "_UNORM_")
+    }
+
+    /// Return the format named like this format, with its numeric type replaced.
+    fn variant(&self, numeric_type: &str, variant_type: &str) -> Option<Format> {
+        let name = self.to_string();
+        if name.contains(numeric_type) {
+            name.replace(numeric_type, variant_type).parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Return the size in bytes of a block for a format.
+    #[inline]
+    pub fn block_size(&self) -> usize {
+        unsafe { bindings::format_block_size(self.0) }
+    }
 
-//    /// Return the dimensions in texels of the block for a format
-//    #[inline]
-//    pub fn block_extent(&self) -> [u32; 3usize] {
-//
-//    }
+    /// Return the dimensions in texels of the block for a format.
+    #[inline]
+    pub fn block_extent(&self) -> Extent3d {
+        let extent: glm::ivec3 = unsafe { bindings::format_block_extent(self.0) };
+        Extent3d::from(*extent)
+    }
 
-//    /// Return the number of components of a format
-//    #[inline]
-//    size_t component_count(&self) -> bool {}
+    /// Return the number of components of a format.
+    #[inline]
+    pub fn component_count(&self) -> usize {
+        unsafe { bindings::format_component_count(self.0) }
+    }
 
     /// Evaluate whether a format is unsigned.
     #[inline]
@@ -134,6 +160,53 @@ impl Format {
     pub fn is_depth_stencil(&self) -> bool {
         unsafe { gli::is_depth_stencil(self.0) }
     }
+
+    /// Return the view compatibility class of the format, or `None` if the format is not valid.
+    ///
+    /// The texels of a texture can be reinterpreted with any format of the same class, see `is_view_compatible`.
+    pub fn compatibility_class(&self) -> Option<CompatibilityClass> {
+
+        if !self.is_valid() {
+            None
+        } else if self.is_depth() || self.is_stencil() {
+            Some(CompatibilityClass::DepthStencil(self.clone()))
+        } else if self.is_compressed() {
+            // The formats of a compression scheme only differ by the numeric type in their name.
+            let name = self.to_string()
+                .replace("_SRGB_", "_UNORM_")
+                .replace("_SNORM_", "_UNORM_")
+                .replace("_SFLOAT_", "_UFLOAT_");
+            Some(CompatibilityClass::Compressed(name.parse().unwrap_or_else(|_| self.clone())))
+        } else {
+            Some(CompatibilityClass::Bits(self.block_size() * 8))
+        }
+    }
+
+    /// Evaluate whether a view of this format can reinterpret the texels of a texture of `other` format.
+    ///
+    /// Uncompressed color formats are compatible when their texels have the same size, like `RGBA8_UNORM_PACK8` and
+    /// `R32_UINT_PACK32`. Compressed formats are compatible with the formats of the same compression scheme, like
+    /// `RGBA_DXT5_UNORM_BLOCK16` and `RGBA_DXT5_SRGB_BLOCK16`. Depth and stencil formats are only compatible with themselves.
+    pub fn is_view_compatible(&self, other: &Format) -> bool {
+        match (self.compatibility_class(), other.compatibility_class()) {
+            | (Some(class), Some(other_class)) => class == other_class,
+            | _ => false,
+        }
+    }
+}
+
+/// The class of the formats which can reinterpret the texels of each other, see `Format::compatibility_class`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum CompatibilityClass {
+
+    /// Uncompressed color formats, with the number of bits of a texel.
+    Bits(usize),
+
+    /// Compressed formats of the same compression scheme, identified by its `UNORM` (or `UFLOAT`) format.
+    Compressed(Format),
+
+    /// A depth, stencil or depth stencil format.
+    DepthStencil(Format),
 }
 
 /// Represent the source of a channel.
@@ -412,6 +485,14 @@ impl Swizzle {
     pub const LAST          : Swizzle = Swizzle(gli::swizzle_SWIZZLE_LAST);
 }
 
+impl From<Swizzle> for u32 {
+
+    /// Convert a swizzle to an element of `Swizzles`.
+    fn from(swizzle: Swizzle) -> u32 {
+        swizzle.0
+    }
+}
+
 impl fmt::Display for Swizzle {
 
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -431,6 +512,42 @@ macro_rules! impl_format_display {
     ($(
         $format:ident,
     )*) => {
+        /// Texture data format, as an enum which can be matched without comparing to the constants of `Format`.
+        ///
+        /// The variants are the formats of gli, named as the constants of `Format`.
+        #[non_exhaustive]
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+        pub enum FormatKind {
+        $(
+            $format,
+        )*
+        }
+
+        impl Format {
+
+            /// Return the variant of `FormatKind` for this format, or `None` if the value is not a format of gli.
+            pub fn kind(&self) -> Option<FormatKind> {
+                match *self {
+                $(
+                    | Format::$format => Some(FormatKind::$format),
+                )*
+                    | _ => None,
+                }
+            }
+        }
+
+        impl From<FormatKind> for Format {
+
+            fn from(kind: FormatKind) -> Format {
+                match kind {
+                $(
+                    | FormatKind::$format => Format::$format,
+                )*
+                }
+            }
+        }
+
         impl fmt::Display for Format {
 
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -438,13 +555,60 @@ macro_rules! impl_format_display {
                 $(
                     | Format::$format => write!(f, stringify!($format)),
                 )*
-                    | _ => write!(f, "Unknown Swizzle"),
+                    | _ => write!(f, "Unknown Format ({})", self.0),
+                }
+            }
+        }
+
+        impl std::str::FromStr for Format {
+            type Err = crate::error::Error;
+
+            /// Parse the name of a format, as printed by `Display`.
+            fn from_str(name: &str) -> crate::error::Result<Format> {
+                match name {
+                $(
+                    | stringify!($format) => Ok(Format::$format),
+                )*
+                    | _ => Err(crate::error::Error::unsupported_format(format!("{} is not the name of a format.", name))),
                 }
             }
         }
     };
 }
 
+impl Format {
+
+    /// Iterate over all the valid formats, from `Format::FIRST` to `Format::LAST`.
+    ///
+    /// `Format::UNDEFINED` is not included.
+    pub fn all() -> impl Iterator<Item = Format> {
+        (Format::FIRST.0..=Format::LAST.0).map(Format)
+    }
+}
+
+impl TryFrom<u32> for Format {
+    type Error = crate::error::Error;
+
+    /// Convert the value of a `gli::format`. Return an error if the value is neither a valid format nor `Format::UNDEFINED`.
+    fn try_from(value: u32) -> crate::error::Result<Format> {
+
+        let format = Format(value);
+
+        if format.is_valid() || format == Format::UNDEFINED {
+            Ok(format)
+        } else {
+            Err(crate::error::Error::unsupported_format(format!("{} is not the value of a format.", value)))
+        }
+    }
+}
+
+impl From<Format> for u32 {
+
+    fn from(format: Format) -> u32 {
+        format.0
+    }
+}
+
 impl_format_display!(
     UNDEFINED,
     RG4_UNORM_PACK8,
@@ -472,6 +636,7 @@ impl_format_display!(
     RGB8_UNORM_PACK8,
     RGB8_SNORM_PACK8, 
     RGB8_USCALED_PACK8,
+    RGB8_SSCALED_PACK8,
     RGB8_UINT_PACK8,
     RGB8_SINT_PACK8,  
     RGB8_SRGB_PACK8,  
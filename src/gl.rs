@@ -2,6 +2,10 @@
 use crate::ffi::root::gli;
 use crate::ffi::root::bindings::GL as bindings;
 
+pub use self::upload::{GLUploadCommand, GLSubImage, GLSubImageFormat};
+
+mod upload;
+
 /// Translation class to convert GLI enums into OpenGL values.
 #[repr(transparent)]
 pub struct GLConverter {
@@ -32,6 +36,11 @@ impl GLConverter {
         let raw = unsafe { bindings::gl_find(&mut self.inner, internal.0, external.0, typ.0) };
         crate::format::Format(raw)
     }
+
+    /// Return the profile which the converter translates to.
+    pub fn profile(&self) -> GLProfile {
+        GLProfile(self.inner.Profile)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -44,6 +53,25 @@ impl GLProfile {
     pub const GL32 : GLProfile = GLProfile(gli::gl_profile_PROFILE_GL32);
     pub const GL33 : GLProfile = GLProfile(gli::gl_profile_PROFILE_GL33);
     pub const KTX  : GLProfile = GLProfile(gli::gl_profile_PROFILE_KTX);
+
+    /// Return whether the profile supports texture swizzles, like gli does.
+    ///
+    /// Without swizzles, gli translates the formats to the legacy formats which need none, like the luminance formats.
+    pub fn has_swizzle(&self) -> bool {
+        *self == GLProfile::ES30 || *self == GLProfile::GL33
+    }
+
+    /// Return whether the profile allocates the textures with immutable storage(`glTexStorage*`).
+    ///
+    /// Like the samples of gli, `GL33` assumes OpenGL 4.2 or `ARB_texture_storage`.
+    pub fn has_texture_storage(&self) -> bool {
+        *self == GLProfile::ES30 || *self == GLProfile::GL33
+    }
+
+    /// Return whether the profile has the `GL_TEXTURE_BASE_LEVEL` and `GL_TEXTURE_MAX_LEVEL` texture parameters.
+    pub fn has_level_range(&self) -> bool {
+        *self != GLProfile::ES20
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
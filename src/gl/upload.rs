@@ -0,0 +1,238 @@
+
+//! Upload of gli textures to OpenGL, expressed as the sequence of OpenGL calls to perform.
+//!
+//! The commands only hold raw OpenGL enum values and byte slices, so that they can drive any OpenGL binding.
+
+use crate::ffi::root::glm;
+use crate::ffi::root::bindings::Texture as texture_bindings;
+use crate::target::Target;
+use crate::texture::GliTexture;
+
+use super::GLConverter;
+
+use std::slice;
+
+const GL_TEXTURE_BASE_LEVEL: u32 = 0x813C;
+const GL_TEXTURE_MAX_LEVEL: u32 = 0x813D;
+const GL_TEXTURE_CUBE_MAP_POSITIVE_X: u32 = 0x8515;
+
+/// A single OpenGL call of the upload of a texture.
+#[derive(Debug, Clone)]
+pub enum GLUploadCommand<'t> {
+    /// `glTexParameteri(target, pname, param)`.
+    TexParameter { target: u32, pname: u32, param: i32 },
+    /// `glTexParameteriv(target, GL_TEXTURE_SWIZZLE_RGBA, swizzles)`.
+    TexSwizzle { target: u32, swizzles: [i32; 4] },
+    /// `glTexStorage1D`, `glTexStorage2D` or `glTexStorage3D` according to `dimensions`.
+    ///
+    /// Only the first `dimensions` components of `extent` are meaningful.
+    TexStorage { target: u32, dimensions: usize, levels: i32, internal_format: u32, extent: [i32; 3] },
+    /// `glTexImage*` or `glCompressedTexImage*` call according to `dimensions` and `format`, which allocates a level
+    /// when the profile has no immutable storage.
+    ///
+    /// `data` is `None` for the array targets, whose levels are allocated for all the layers at once and then filled
+    /// by `TexSubImage` commands.
+    TexImage { target: u32, dimensions: usize, level: i32, internal_format: u32, extent: [i32; 3], format: GLSubImageFormat, data: Option<&'t [u8]> },
+    /// `glTexSubImage*` or `glCompressedTexSubImage*` call for a single layer, face and level.
+    TexSubImage(GLSubImage<'t>),
+}
+
+/// The arguments of a `glTexSubImage*` or `glCompressedTexSubImage*` call.
+#[derive(Debug, Clone)]
+pub struct GLSubImage<'t> {
+    /// The target of the call, which is the face target for cube maps.
+    pub target: u32,
+    /// Select `glTexSubImage1D`, `glTexSubImage2D` or `glTexSubImage3D`, or their compressed variants.
+    ///
+    /// Only the first `dimensions` components of `offset` and `extent` are meaningful.
+    pub dimensions: usize,
+    pub level: i32,
+    pub offset: [i32; 3],
+    pub extent: [i32; 3],
+    pub format: GLSubImageFormat,
+    /// The layer and face of the gli texture this image comes from.
+    pub layer: usize,
+    pub face: usize,
+    pub data: &'t [u8],
+}
+
+/// The format arguments of an image or sub-image call.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GLSubImageFormat {
+    /// Call `glCompressedTex(Sub)Image*` with the internal format and the size of the image in bytes.
+    Compressed { internal_format: u32, image_size: i32 },
+    /// Call `glTex(Sub)Image*` with the external format and the type of the texels.
+    Uncompressed { external_format: u32, typ: u32 },
+}
+
+impl GLConverter {
+
+    /// Return the sequence of OpenGL calls uploading all the layers, faces and levels of `texture`.
+    ///
+    /// The texture object is expected to be bound to the target of the commands before performing them.
+    /// The commands depend on the profile of the converter:
+    ///
+    /// - With `GLProfile::ES30` and `GLProfile::GL33`, the storage is allocated at once by `TexStorage`, which needs
+    ///   OpenGL ES 3.0, OpenGL 4.2 or `ARB_texture_storage`, and the swizzles are set by `TexSwizzle`.
+    /// - With the other profiles, the levels are allocated by `TexImage` and no swizzle is set. gli then translates
+    ///   the formats to legacy formats which need no swizzle, and the swizzles of the texture itself are ignored.
+    /// - `GLProfile::ES20` has no base and max level parameters, so they are not set either.
+    pub fn upload_commands<'t, T: GliTexture>(&self, texture: &'t T) -> Vec<GLUploadCommand<'t>> {
+
+        let target = texture.target();
+        let gl_target = self.translate(target.clone()).0;
+        let format = texture.format();
+        let swizzles = *texture.raw_texture().Swizzles;
+        let gl_format = self.translate2(format.clone(), swizzles);
+
+        let profile = self.profile();
+        let (layers, faces, levels) = (texture.layers(), texture.faces(), texture.levels());
+        let dimensions = storage_dimensions(&target);
+        let is_array = is_array_target(&target);
+
+        let mut commands = Vec::with_capacity(4 + levels + layers * faces * levels);
+
+        if profile.has_level_range() {
+            commands.push(GLUploadCommand::TexParameter { target: gl_target, pname: GL_TEXTURE_BASE_LEVEL, param: 0 });
+            commands.push(GLUploadCommand::TexParameter { target: gl_target, pname: GL_TEXTURE_MAX_LEVEL, param: levels as i32 - 1 });
+        }
+
+        if profile.has_swizzle() {
+            commands.push(GLUploadCommand::TexSwizzle {
+                target: gl_target,
+                swizzles: [
+                    gl_format.swizzles[0] as i32,
+                    gl_format.swizzles[1] as i32,
+                    gl_format.swizzles[2] as i32,
+                    gl_format.swizzles[3] as i32,
+                ],
+            });
+        }
+
+        if profile.has_texture_storage() {
+            commands.push(GLUploadCommand::TexStorage {
+                target: gl_target,
+                dimensions,
+                levels: levels as i32,
+                internal_format: gl_format.internal.0,
+                extent: array_extent(&target, level_extent(texture, 0), layers * faces),
+            });
+        } else if is_array {
+            // The layers of an array level can only be allocated together, so they are filled by sub-images afterwards.
+            for level in 0..levels {
+                let image_format = if format.is_compressed() {
+                    GLSubImageFormat::Compressed {
+                        internal_format: gl_format.internal.0,
+                        image_size: (texture.size_at_level(level) * layers * faces) as i32,
+                    }
+                } else {
+                    GLSubImageFormat::Uncompressed { external_format: gl_format.external.0, typ: gl_format.typ.0 }
+                };
+
+                commands.push(GLUploadCommand::TexImage {
+                    target: gl_target,
+                    dimensions,
+                    level: level as i32,
+                    internal_format: gl_format.internal.0,
+                    extent: array_extent(&target, level_extent(texture, level), layers * faces),
+                    format: image_format,
+                    data: None,
+                });
+            }
+        }
+
+        for layer in 0..layers {
+            for face in 0..faces {
+                for level in 0..levels {
+
+                    let extent = level_extent(texture, level);
+                    let image_size = texture.size_at_level(level);
+
+                    let (image_target, offset, extent) = match target {
+                        | Target::TARGET_1D_ARRAY => (gl_target, [0, layer as i32, 0], [extent[0], 1, 1]),
+                        | Target::TARGET_CUBE => (GL_TEXTURE_CUBE_MAP_POSITIVE_X + face as u32, [0, 0, 0], extent),
+                        | Target::TARGET_2D_ARRAY
+                        | Target::TARGET_RECT_ARRAY
+                        | Target::TARGET_CUBE_ARRAY => (gl_target, [0, 0, (layer * faces + face) as i32], [extent[0], extent[1], 1]),
+                        | _ => (gl_target, [0, 0, 0], extent),
+                    };
+
+                    let image_format = if format.is_compressed() {
+                        GLSubImageFormat::Compressed { internal_format: gl_format.internal.0, image_size: image_size as i32 }
+                    } else {
+                        GLSubImageFormat::Uncompressed { external_format: gl_format.external.0, typ: gl_format.typ.0 }
+                    };
+
+                    let data = unsafe {
+                        slice::from_raw_parts(texture.data_detail(layer, face, level) as *const u8, image_size)
+                    };
+
+                    if profile.has_texture_storage() || is_array {
+                        commands.push(GLUploadCommand::TexSubImage(GLSubImage {
+                            target: image_target,
+                            dimensions,
+                            level: level as i32,
+                            offset,
+                            extent,
+                            format: image_format,
+                            layer,
+                            face,
+                            data,
+                        }));
+                    } else {
+                        commands.push(GLUploadCommand::TexImage {
+                            target: image_target,
+                            dimensions,
+                            level: level as i32,
+                            internal_format: gl_format.internal.0,
+                            extent,
+                            format: image_format,
+                            data: Some(data),
+                        });
+                    }
+                }
+            }
+        }
+
+        commands
+    }
+}
+
+/// Return the number of dimensions of the storage and the sub-image calls of a target.
+fn storage_dimensions(target: &Target) -> usize {
+    match *target {
+        | Target::TARGET_1D => 1,
+        | Target::TARGET_1D_ARRAY
+        | Target::TARGET_2D
+        | Target::TARGET_RECT
+        | Target::TARGET_CUBE => 2,
+        | _ => 3,
+    }
+}
+
+fn is_array_target(target: &Target) -> bool {
+    match *target {
+        | Target::TARGET_1D_ARRAY
+        | Target::TARGET_2D_ARRAY
+        | Target::TARGET_RECT_ARRAY
+        | Target::TARGET_CUBE_ARRAY => true,
+        | _ => false,
+    }
+}
+
+/// Return the extent of a whole level for the storage and the image calls, where the layers of the array targets
+/// are stacked along the last dimension.
+fn array_extent(target: &Target, extent: [i32; 3], layers: usize) -> [i32; 3] {
+    match *target {
+        | Target::TARGET_1D_ARRAY => [extent[0], layers as i32, 1],
+        | Target::TARGET_2D_ARRAY
+        | Target::TARGET_RECT_ARRAY
+        | Target::TARGET_CUBE_ARRAY => [extent[0], extent[1], layers as i32],
+        | _ => extent,
+    }
+}
+
+fn level_extent<T: GliTexture>(texture: &T, level: usize) -> [i32; 3] {
+    let extent: glm::ivec3 = unsafe { texture_bindings::texture_extent(texture.raw_texture(), level) };
+    [extent[0] as i32, extent[1] as i32, extent[2] as i32]
+}
@@ -1,52 +1,78 @@
 
 use std::os::raw::c_void;
+use std::marker::PhantomData;
 
 use crate::ffi::root::gli;
 use crate::ffi::root::glm;
 use crate::ffi::root::bindings::Image as bindings;
 
 use crate::format::Format;
-use crate::texture::GliTexture;
+use crate::texture::{GliTexture, check_storage, check_view_format};
 use crate::Extent3d;
+use crate::error::{Result, Error, catch_ffi};
+
+#[cfg(feature = "image")]
+pub(crate) use self::dynamic::texture_level_to_dynamic_image;
+
+#[cfg(feature = "image")]
+mod dynamic;
 
 /// GliImage representation for a single texture level.
 #[cfg(not(feature = "rc_debug"))]
 #[repr(transparent)]
 pub struct GliImage {
     ffi: gli::image,
+    phantom_type: PhantomData<*const ()>,
 }
 
 #[cfg(feature = "rc_debug")]
 #[repr(transparent)]
 pub struct GliImage {
     pub ffi: gli::image,
+    phantom_type: PhantomData<*const ()>,
 }
 
 impl GliImage {
 
     /// Create an image object and allocate an image storage for it.
+    ///
+    /// Return an error if the extent is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new(format: Format, extent: Extent3d) -> GliImage {
-        GliImage { ffi: unsafe { bindings::image_new_(format.0, glm::ivec3(extent.into())) } }
+    pub fn new(format: Format, extent: Extent3d) -> Result<GliImage> {
+
+        check_storage(&format, [extent.width, extent.height, extent.depth], 1, 1, 1)?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::image_new_(format.0, glm::ivec3(extent.into())) })?;
+        Ok(GliImage { ffi, phantom_type: PhantomData })
     }
 
     /// Create an empty image instance.
     #[inline]
     pub fn new_empty() -> GliImage {
-        GliImage { ffi: unsafe { bindings::image_new_empty() } }
+        GliImage { ffi: unsafe { bindings::image_new_empty() }, phantom_type: PhantomData }
     }
 
     /// Create an image object by sharing an existing image storage_linear from another image instance.
     /// This image object is effectively an image view where format can be reinterpreted with a different compatible image format.
-    /// For formats to be compatible, the block size of source and destination must match.
+    /// Return an error if `format` is not view compatible with the format of `image`, see `Format::is_view_compatible`.
     #[inline]
-    pub fn share_from(image: &GliImage, format: Format) -> GliImage {
-        GliImage { ffi: unsafe { bindings::image_share_from(&image.ffi, format.0) } }
+    pub fn share_from(image: &GliImage, format: Format) -> Result<GliImage> {
+
+        if image.empty() {
+            return Err(Error::invalid_argument("Can not create a view of an empty image."))
+        }
+        check_view_format(&image.format(), &format)?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::image_share_from(&image.ffi, format.0) })?;
+        Ok(GliImage { ffi, phantom_type: PhantomData })
     }
 
     /// Clear the entire image storage_linear with zeros.
+    ///
+    /// If the storage is shared, the image first gets its own copy, see `make_unique`.
     #[inline]
     pub fn clear(&mut self) {
+        self.make_unique();
         unsafe { bindings::image_clear(&mut self.ffi) }
     }
 
@@ -59,8 +85,17 @@ impl GliImage {
     }
 
     /// Return a mutable pointer to the beginning of the texture instance data.
+    ///
+    /// If the storage is shared, the image first gets its own copy, see `make_unique`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is valid for `size()` bytes, until the image is dropped or its storage is replaced.
+    /// Since this call may replace the storage, the pointers obtained earlier from `data` may point to the previous
+    /// storage, which is no longer the storage of this image, or has been freed.
     #[inline]
     pub unsafe fn data_mut(&mut self) -> *mut c_void {
+        self.make_unique();
         bindings::image_data_mut(&mut self.ffi)
     }
 
@@ -96,13 +131,37 @@ impl GliImage {
 
     // TODO: another size(&self) method is missing, due to template specialization.
 
+    /// Return the number of images, textures and samplers sharing the storage of this image, itself included.
+    ///
+    /// The images returned by `get_level` share the storage of their texture. Return 0 for an empty image.
+    #[inline]
+    pub fn shared_count(&self) -> usize {
+        unsafe { bindings::get_image_shared_storage_count(&self.ffi) as usize }
+    }
+
+    /// Return whether the storage of this image is shared with another image, texture or sampler.
+    #[inline]
+    pub fn is_shared(&self) -> bool {
+        self.shared_count() > 1
+    }
+
+    /// Give the image its own storage if it shares it, by copying its texels.
+    ///
+    /// The textures and images sharing the storage are not affected by later writes. `clear` and `data_mut` call it before writing.
+    pub fn make_unique(&mut self) {
+        if self.is_shared() {
+            *self = self.clone();
+        }
+    }
+
     // TODO: store(..) methods is missing, due to template specialization.
 
     /// This function is just for inner crate usage. Don't call this function.
     #[inline]
     pub(crate) fn shared_from_texture(texture: &impl GliTexture, format: Format, base_layer: usize, base_face: usize, base_level: usize) -> GliImage {
         GliImage {
-            ffi: unsafe { bindings::image_share_from_texture(texture.raw_texture(), format.0, base_layer, base_face, base_level) }
+            ffi: unsafe { bindings::image_share_from_texture(texture.raw_texture(), format.0, base_layer, base_face, base_level) },
+            phantom_type: PhantomData,
         }
     }
 }
@@ -144,3 +203,16 @@ impl std::cmp::PartialEq for GliImage {
 }
 
 impl std::cmp::Eq for GliImage {}
+
+impl Clone for GliImage {
+
+    /// Copy the texels of the image into a new storage.
+    ///
+    /// Panic if the new storage can not be allocated, like the standard collections do.
+    fn clone(&self) -> GliImage {
+        match catch_ffi(|| unsafe { bindings::image_duplicate(&self.ffi) }) {
+            | Ok(ffi) => GliImage { ffi, phantom_type: PhantomData },
+            | Err(error) => panic!("Failed to copy the storage of the image: {}", error),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+
+//! Conversion between gli images and the images of the `image` crate.
+//!
+//! This module is only available with the `image` feature.
+
+use ::image::{DynamicImage, ImageBuffer, Pixel, Rgb, Rgba, Luma};
+
+use crate::ffi::root::glm;
+use crate::ffi::root::bindings::Texture as texture_bindings;
+use crate::format::Format;
+use crate::texture::{GliTexture, Texture2D};
+use crate::extent::{Extent2d, Extent3d};
+use crate::error::{Result, Error};
+
+use super::GliImage;
+
+use std::slice;
+
+/// The memory layout of the texels of the formats that can be converted.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Layout {
+    /// 8 bits unsigned normalized channels, in the RGBA order.
+    U8(usize),
+    /// 4 channels of 8 bits unsigned normalized, in the BGRA order.
+    Bgra8,
+    /// 16 bits unsigned normalized channels, in the RGBA order.
+    U16(usize),
+    /// 32 bits float channels, in the RGBA order.
+    F32(usize),
+}
+
+impl Layout {
+
+    fn of(format: &Format) -> Result<Layout> {
+
+        let layout = match *format {
+            | Format::R8_UNORM_PACK8     | Format::R8_SRGB_PACK8     => Layout::U8(1),
+            | Format::RG8_UNORM_PACK8    | Format::RG8_SRGB_PACK8    => Layout::U8(2),
+            | Format::RGB8_UNORM_PACK8   | Format::RGB8_SRGB_PACK8   => Layout::U8(3),
+            | Format::RGBA8_UNORM_PACK8  | Format::RGBA8_SRGB_PACK8  => Layout::U8(4),
+            | Format::BGRA8_UNORM_PACK8  | Format::BGRA8_SRGB_PACK8  => Layout::Bgra8,
+            | Format::R16_UNORM_PACK16    => Layout::U16(1),
+            | Format::RG16_UNORM_PACK16   => Layout::U16(2),
+            | Format::RGB16_UNORM_PACK16  => Layout::U16(3),
+            | Format::RGBA16_UNORM_PACK16 => Layout::U16(4),
+            | Format::R32_SFLOAT_PACK32    => Layout::F32(1),
+            | Format::RG32_SFLOAT_PACK32   => Layout::F32(2),
+            | Format::RGB32_SFLOAT_PACK32  => Layout::F32(3),
+            | Format::RGBA32_SFLOAT_PACK32 => Layout::F32(4),
+            | _ => return Err(Error::unsupported_format(format!("{} can not be converted from or to an image of the image crate.", format))),
+        };
+
+        Ok(layout)
+    }
+}
+
+impl Texture2D {
+
+    /// Create a texture with a single level holding `image`, converted to `format`.
+    ///
+    /// The channels of `image` are converted to RGBA, then the channels missing from `format` are dropped.
+    /// Only uncompressed 8 bits or 16 bits unsigned normalized and 32 bits float formats are supported.
+    pub fn from_dynamic_image(image: &DynamicImage, format: Format) -> Result<Texture2D> {
+
+        let layout = Layout::of(&format)?;
+
+        let bytes: Vec<u8> = match layout {
+            | Layout::U8(channels) => {
+                image.to_rgba8().pixels()
+                    .flat_map(|p| p.0[..channels].to_vec())
+                    .collect()
+            },
+            | Layout::Bgra8 => {
+                image.to_rgba8().pixels()
+                    .flat_map(|p| vec![p.0[2], p.0[1], p.0[0], p.0[3]])
+                    .collect()
+            },
+            | Layout::U16(channels) => {
+                image.to_rgba16().pixels()
+                    .flat_map(|p| p.0[..channels].to_vec())
+                    .flat_map(u16::to_ne_bytes)
+                    .collect()
+            },
+            | Layout::F32(channels) => {
+                image.to_rgba32f().pixels()
+                    .flat_map(|p| p.0[..channels].to_vec())
+                    .flat_map(f32::to_ne_bytes)
+                    .collect()
+            },
+        };
+
+        let mut texture = Texture2D::new(format, Extent2d { width: image.width(), height: image.height() }, 1)?;
+        debug_assert_eq!(texture.size(), bytes.len());
+
+        unsafe {
+            let dst = slice::from_raw_parts_mut(texture.data_mut() as *mut u8, bytes.len());
+            dst.copy_from_slice(&bytes);
+        }
+
+        Ok(texture)
+    }
+
+    /// Create a texture with a single level holding `buffer`, converted to `format`.
+    ///
+    /// See `from_dynamic_image` for the supported formats.
+    pub fn from_image_buffer<P>(buffer: ImageBuffer<P, Vec<P::Subpixel>>, format: Format) -> Result<Texture2D>
+        where
+            P: Pixel,
+            DynamicImage: From<ImageBuffer<P, Vec<P::Subpixel>>> {
+
+        Texture2D::from_dynamic_image(&DynamicImage::from(buffer), format)
+    }
+}
+
+impl GliImage {
+
+    /// Convert the image to an image of the `image` crate.
+    ///
+    /// Single channel 8 bits and 16 bits formats become gray images, and the blue channel of two channels formats is set to zero.
+    /// The `image` crate has no gray float image, so single channel 32 bits float formats become RGB float images
+    /// with the channel repeated, not `Luma` images.
+    /// Return an error for 3d images and for the formats not supported by `Texture2D::from_dynamic_image`.
+    pub fn to_dynamic_image(&self) -> Result<DynamicImage> {
+
+        let bytes = unsafe { slice::from_raw_parts(self.data() as *const u8, self.size()) };
+        to_dynamic_image(&self.format(), self.extent(), bytes)
+    }
+}
+
+/// Convert the texels of a 2d image in `format` to an image of the `image` crate.
+pub(crate) fn to_dynamic_image(format: &Format, extent: Extent3d, bytes: &[u8]) -> Result<DynamicImage> {
+
+    if extent.depth > 1 {
+        return Err(Error::unsupported_format("Only 2d images can be converted to an image of the image crate."))
+    }
+
+    let layout = Layout::of(format)?;
+    let (width, height) = (extent.width, extent.height);
+
+    let image = match layout {
+        | Layout::U8(channels) => {
+            let texels = bytes.chunks_exact(channels);
+            match channels {
+                | 1 => DynamicImage::ImageLuma8(from_texels(width, height, texels, |t| Luma([t[0]]))),
+                | 2 => DynamicImage::ImageRgb8(from_texels(width, height, texels, |t| Rgb([t[0], t[1], 0]))),
+                | 3 => DynamicImage::ImageRgb8(from_texels(width, height, texels, |t| Rgb([t[0], t[1], t[2]]))),
+                | _ => DynamicImage::ImageRgba8(from_texels(width, height, texels, |t| Rgba([t[0], t[1], t[2], t[3]]))),
+            }
+        },
+        | Layout::Bgra8 => {
+            let texels = bytes.chunks_exact(4);
+            DynamicImage::ImageRgba8(from_texels(width, height, texels, |t| Rgba([t[2], t[1], t[0], t[3]])))
+        },
+        | Layout::U16(channels) => {
+            let values: Vec<u16> = bytes.chunks_exact(2).map(|b| u16::from_ne_bytes([b[0], b[1]])).collect();
+            let texels = values.chunks_exact(channels);
+            match channels {
+                | 1 => DynamicImage::ImageLuma16(from_texels(width, height, texels, |t| Luma([t[0]]))),
+                | 2 => DynamicImage::ImageRgb16(from_texels(width, height, texels, |t| Rgb([t[0], t[1], 0]))),
+                | 3 => DynamicImage::ImageRgb16(from_texels(width, height, texels, |t| Rgb([t[0], t[1], t[2]]))),
+                | _ => DynamicImage::ImageRgba16(from_texels(width, height, texels, |t| Rgba([t[0], t[1], t[2], t[3]]))),
+            }
+        },
+        | Layout::F32(channels) => {
+            let values: Vec<f32> = bytes.chunks_exact(4).map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]])).collect();
+            let texels = values.chunks_exact(channels);
+            match channels {
+                | 1 => DynamicImage::ImageRgb32F(from_texels(width, height, texels, |t| Rgb([t[0], t[0], t[0]]))),
+                | 2 => DynamicImage::ImageRgb32F(from_texels(width, height, texels, |t| Rgb([t[0], t[1], 0.0]))),
+                | 3 => DynamicImage::ImageRgb32F(from_texels(width, height, texels, |t| Rgb([t[0], t[1], t[2]]))),
+                | _ => DynamicImage::ImageRgba32F(from_texels(width, height, texels, |t| Rgba([t[0], t[1], t[2], t[3]]))),
+            }
+        },
+    };
+
+    Ok(image)
+}
+
+fn from_texels<'a, P, T, F>(width: u32, height: u32, texels: slice::ChunksExact<'a, T>, pixel: F) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel,
+        T: 'a,
+        F: Fn(&[T]) -> P {
+
+    let mut buffer = ImageBuffer::new(width, height);
+    for (dst, texel) in buffer.pixels_mut().zip(texels) {
+        *dst = pixel(texel);
+    }
+    buffer
+}
+
+/// Convert a layer, face and level of `texture` to an image of the `image` crate.
+///
+/// See `GliImage::to_dynamic_image` for the supported formats.
+pub(crate) fn texture_level_to_dynamic_image<T: GliTexture>(texture: &T, layer: usize, face: usize, level: usize) -> Result<DynamicImage> {
+
+    let extent: glm::ivec3 = unsafe { texture_bindings::texture_extent(texture.raw_texture(), level) };
+    let bytes = unsafe {
+        slice::from_raw_parts(texture.data_detail(layer, face, level) as *const u8, texture.size_at_level(level))
+    };
+
+    to_dynamic_image(&texture.format(), Extent3d::from(*extent), bytes)
+}
@@ -1,5 +1,5 @@
 
-pub use self::format::{Format, Swizzle, Swizzles, TexFormatType};
+pub use self::format::{Format, FormatKind, CompatibilityClass, Swizzle, Swizzles, TexFormatType};
 pub use self::extent::{Extent1d, Extent2d, Extent3d};
 
 pub use self::image::GliImage;
@@ -8,13 +8,16 @@ pub use self::texture::*;
 pub use self::load::*;
 pub use self::save::*;
 
-pub use self::error::{Result, Error};
+pub use self::error::{Result, Error, ErrorKind};
 
 pub mod target;
 pub mod dx;
 pub mod gl;
+pub mod vk;
 pub mod sampler;
 pub mod extent;
+#[cfg(feature = "wgpu")]
+pub mod wgpu;
 
 mod load;
 mod save;
@@ -1,12 +1,13 @@
 
 use std::path::Path;
-use std::ffi::{CString, CStr};
+use std::os::raw::c_char;
 
+use crate::ffi::root::gli;
 use crate::ffi::root::bindings::Load as bindings;
 
 use crate::format::TexFormatType;
-use crate::texture::GliTexture;
-use crate::error::{Result, Error, ErrorKind};
+use crate::texture::{GliTexture, AnyTexture};
+use crate::error::{Result, Error, catch_ffi};
 
 
 /// Loads a texture storage_linear from file.
@@ -18,39 +19,19 @@ pub fn load<T>(path: impl AsRef<Path>) -> Result<T>
     where
         T: GliTexture {
 
-    let c_path = path_to_cstring(path)?;
-
-    let raw_texture = unsafe {
-        bindings::load_by_path(c_path.as_ptr())
-    };
-
-    construct_texture(raw_texture)
+    load_file(path.as_ref(), None)
 }
 
-/// Construct a `GliTexture` by sharing another texture data in memory.
+/// Construct a `GliTexture` from the content of a texture file in memory.
 ///
 /// The `format` type and resulting texture type must be specified correctly.
 ///
-/// The lifetime of `data` must be longer than constructed `GliTexture`, which is not guaranteed by this crate.
+/// The texels are copied into the storage of the texture, so `data` may be dropped after the call.
 pub fn load_from_memory<T>(data: &[u8], format: TexFormatType) -> Result<T>
     where
         T: GliTexture {
 
-    // Read the texture file content into bytes in Rust.
-    let bytes_length = data.len();
-
-    let raw_texture = unsafe {
-        let texture_data = CStr::from_bytes_with_nul_unchecked(data);
-
-        // TODO: bytes_length may be invalid.
-        match format {
-            TexFormatType::DDS => bindings::load_dds_memory(texture_data.as_ptr(), bytes_length),
-            TexFormatType::KMG => bindings::load_kmg_memory(texture_data.as_ptr(), bytes_length),
-            TexFormatType::KTX => bindings::load_ktx_memory(texture_data.as_ptr(), bytes_length),
-        }
-    };
-
-    construct_texture(raw_texture)
+    load_raw(data, Some(format)).and_then(construct_texture)
 }
 
 /// Loads a texture storage_linear from DDS file.
@@ -62,13 +43,7 @@ pub fn load_dds<T>(path: impl AsRef<Path>) -> Result<T>
     where
         T: GliTexture {
 
-    let c_path = path_to_cstring(path)?;
-
-    let raw_texture = unsafe {
-        bindings::load_dds_by_path(c_path.as_ptr())
-    };
-
-    construct_texture(raw_texture)
+    load_file(path.as_ref(), Some(TexFormatType::DDS))
 }
 
 /// Loads a texture storage_linear from KTX file.
@@ -80,13 +55,7 @@ pub fn load_ktx<T>(path: impl AsRef<Path>) -> Result<T>
     where
         T: GliTexture {
 
-    let c_path = path_to_cstring(path)?;
-
-    let raw_texture = unsafe {
-        bindings::load_ktx_by_path(c_path.as_ptr())
-    };
-
-    construct_texture(raw_texture)
+    load_file(path.as_ref(), Some(TexFormatType::KTX))
 }
 
 /// Loads a texture storage_linear from KMG (Khronos Image) file.
@@ -98,17 +67,61 @@ pub fn load_kmg<T>(path: impl AsRef<Path>) -> Result<T>
     where
         T: GliTexture {
 
-    let c_path = path_to_cstring(path)?;
+    load_file(path.as_ref(), Some(TexFormatType::KMG))
+}
 
-    let raw_texture = unsafe {
-        bindings::load_kmg_by_path(c_path.as_ptr())
-    };
+/// Loads a texture storage_linear from file, without knowing its target in advance.
+///
+/// The file must be a valid (DDS, KTX or KMG) file.
+///
+/// Return the texture wrapped in the type matching the target stored in the file, or an error in case of failure.
+pub fn load_any(path: impl AsRef<Path>) -> Result<AnyTexture> {
 
-    construct_texture(raw_texture)
+    let path = path.as_ref();
+
+    let raw_texture = std::fs::read(path)
+        .map_err(Error::from)
+        .and_then(|data| load_raw(&data, None))
+        .map_err(|error| error.with_path(path))?;
+
+    // gli failed to load the texture, if its return variable is empty.
+    match AnyTexture::from_raw(raw_texture) {
+        | Some(texture) if !texture.empty() => Ok(texture),
+        | _ => Err(Error::load_texture("Failed to load.").with_path(path)),
+    }
+}
+
+/// Read the file at `path` on the Rust side, so any path supported by the platform can be loaded.
+///
+/// Errors are of kind `ErrorKind::Path`, caused by the I/O or the load error.
+fn load_file<T>(path: &Path, format: Option<TexFormatType>) -> Result<T>
+    where
+        T: GliTexture {
+
+    std::fs::read(path)
+        .map_err(Error::from)
+        .and_then(|data| load_raw(&data, format))
+        .and_then(construct_texture)
+        .map_err(|error| error.with_path(path))
+}
+
+/// Load the content of a texture file in memory. Detect the container if `format` is `None`.
+fn load_raw(data: &[u8], format: Option<TexFormatType>) -> Result<gli::texture> {
+
+    let (data, size) = (data.as_ptr() as *const c_char, data.len());
+
+    catch_ffi(|| unsafe {
+        match format {
+            | None                      => bindings::load_memory(data, size),
+            | Some(TexFormatType::DDS)  => bindings::load_dds_memory(data, size),
+            | Some(TexFormatType::KMG)  => bindings::load_kmg_memory(data, size),
+            | Some(TexFormatType::KTX)  => bindings::load_ktx_memory(data, size),
+        }
+    })
 }
 
 #[inline]
-fn construct_texture<T>(raw_texture: crate::ffi::root::gli::texture) -> Result<T>
+fn construct_texture<T>(raw_texture: gli::texture) -> Result<T>
     where
         T: GliTexture {
 
@@ -121,16 +134,3 @@ fn construct_texture<T>(raw_texture: crate::ffi::root::gli::texture) -> Result<T
         Ok(dst_texture)
     }
 }
-
-#[inline]
-fn path_to_cstring(path: impl AsRef<Path>) -> Result<CString> {
-
-    // Some conversion from Path to CString.
-    // If you find better way to do this, welcome to create a pull request.
-
-    let path_str = path.as_ref().to_str()
-        .ok_or(ErrorKind::Io)?;
-
-    CString::new(path_str)
-        .map_err(|_| ErrorKind::Io.into())
-}
@@ -6,6 +6,7 @@ pub use self::sampler2d_array::FSampler2DArray;
 pub use self::sampler3d::FSampler3D;
 pub use self::sampler_cube::FSamplerCube;
 pub use self::sampler_cube_array::FSamplerCubeArray;
+pub use self::sampler2d_shadow::FSampler2DShadow;
 
 mod sampler1d;
 mod sampler1d_array;
@@ -14,8 +15,57 @@ mod sampler2d_array;
 mod sampler3d;
 mod sampler_cube;
 mod sampler_cube_array;
+mod sampler2d_shadow;
+
+mod batch;
+pub(crate) mod cube;
+pub(crate) mod filtering;
 
 use crate::ffi::root::gli;
+use crate::texture::GliTexture;
+
+pub(crate) use self::sealed::SamplerTexture;
+
+/// Access of the samplers created from a shared reference to their texture, which can only read it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ReadOnly;
+
+/// Access of the samplers created from a mutable reference to their texture, which can also write to it.
+///
+/// The write methods(`clear`, `texel_write` and the mipmaps generation) are only available with this access.
+/// The texture first gets its own storage if it shares it with views or other textures, see `GliTexture::make_unique`,
+/// so the writes never reach the textures it shared its storage with.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ReadWrite;
+
+mod sealed {
+
+    /// A reference to the texture of a sampler, which selects the access of the sampler. It can not be
+    /// implemented outside of this module.
+    pub trait SamplerTexture<'a, T> {
+        type Access;
+
+        /// Return the texture to sample, after giving it its own storage for a write access.
+        fn into_texture(self) -> &'a T;
+    }
+}
+
+impl<'a, T: GliTexture> SamplerTexture<'a, T> for &'a T {
+    type Access = ReadOnly;
+
+    fn into_texture(self) -> &'a T {
+        self
+    }
+}
+
+impl<'a, T: GliTexture> SamplerTexture<'a, T> for &'a mut T {
+    type Access = ReadWrite;
+
+    fn into_texture(self) -> &'a T {
+        self.make_unique();
+        self
+    }
+}
 
 /// Texture coordinate wrapping mode.
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -44,4 +94,82 @@ impl Filter {
     pub const FIRST   : Filter = Filter(gli::filter_FILTER_FIRST  );
     pub const LINEAR  : Filter = Filter(gli::filter_FILTER_LINEAR );
     pub const LAST    : Filter = Filter(gli::filter_FILTER_LAST   );
+
+    /// Bicubic filtering with a uniform cubic B-spline. It is smooth but slightly blurs the texture.
+    ///
+    /// This filter is not supported by gli, and only implemented by the sampling done on the Rust side.
+    /// Used as a mipmap filter, it blends the two nearest levels linearly.
+    pub const CUBIC_BSPLINE : Filter = Filter(gli::filter_FILTER_LAST + 1);
+    /// Bicubic filtering with a Catmull-Rom spline. It keeps the texture sharp, but may overshoot near hard edges.
+    ///
+    /// This filter is not supported by gli, and only implemented by the sampling done on the Rust side.
+    /// Used as a mipmap filter, it blends the two nearest levels linearly.
+    pub const CATMULL_ROM   : Filter = Filter(gli::filter_FILTER_LAST + 2);
+
+    /// Return whether the filter is one of the bicubic filters.
+    #[inline]
+    pub fn is_cubic(&self) -> bool {
+        *self == Filter::CUBIC_BSPLINE || *self == Filter::CATMULL_ROM
+    }
+
+    /// Return the filter passed to gli, which falls back to linear filtering for the filters it does not support.
+    #[inline]
+    pub(crate) fn native(&self) -> gli::filter {
+        if self.is_cubic() { gli::filter_FILTER_LINEAR } else { self.0 }
+    }
+}
+
+/// Comparison function used by depth comparison samplers.
+///
+/// The reference value is on the left side of the comparison, and the texel value on the right side.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CompareFunc {
+    Never,
+    Less,
+    Equal,
+    LessOrEqual,
+    Greater,
+    NotEqual,
+    GreaterOrEqual,
+    Always,
+}
+
+impl CompareFunc {
+
+    /// Return whether the comparison between `reference` and `texel` passes.
+    #[inline]
+    pub fn compare(&self, reference: f32, texel: f32) -> bool {
+        match *self {
+            | CompareFunc::Never          => false,
+            | CompareFunc::Less           => reference <  texel,
+            | CompareFunc::Equal          => reference == texel,
+            | CompareFunc::LessOrEqual    => reference <= texel,
+            | CompareFunc::Greater        => reference >  texel,
+            | CompareFunc::NotEqual       => reference != texel,
+            | CompareFunc::GreaterOrEqual => reference >= texel,
+            | CompareFunc::Always         => true,
+        }
+    }
+}
+
+/// Create the C++ sampler of a texture.
+///
+/// gli asserts that the texture is not empty and that its format is not compressed. Panic if one of its assertions fails.
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn new_native<S>(create: impl FnOnce() -> S) -> S {
+    match crate::error::catch_ffi(create) {
+        | Ok(sampler) => sampler,
+        | Err(error) => panic!("Failed to create the sampler: {}", error),
+    }
+}
+
+/// Call a binding which fetches or writes the texels of a sampler.
+///
+/// gli asserts that the texel coordinates, layers, faces and levels are inside the texture.
+/// Panic if one of its assertions fails, like indexing a slice out of its bounds does.
+pub(crate) fn call_native<T>(call: impl FnOnce() -> T) -> T {
+    match crate::error::catch_ffi(call) {
+        | Ok(value) => value,
+        | Err(error) => panic!("The sampler failed: {}", error),
+    }
 }
@@ -0,0 +1,65 @@
+// Helpers to run the batched sampling operations on several threads.
+
+use crate::sampler::{FSampler1D, FSampler1DArray, FSampler2D, FSampler2DArray, FSampler3D, FSamplerCube, FSamplerCubeArray};
+
+use self::sealed::BatchSampler;
+
+use std::thread;
+
+/// The minimum number of elements processed by a thread, smaller batches are not worth spawning threads for.
+const MIN_CHUNK_LEN: usize = 4096;
+
+mod sealed {
+
+    /// The samplers whose batched fetching and sampling can run on several threads. It can not be implemented
+    /// outside of this module, and must only be implemented for the samplers whose `texel_fetch` and `texture_lod`
+    /// are `const` in gli.
+    pub trait BatchSampler {}
+}
+
+impl<'a, A> BatchSampler for FSampler1D<'a, A> {}
+impl<'a, A> BatchSampler for FSampler1DArray<'a, A> {}
+impl<'a, A> BatchSampler for FSampler2D<'a, A> {}
+impl<'a, A> BatchSampler for FSampler2DArray<'a, A> {}
+impl<'a, A> BatchSampler for FSampler3D<'a, A> {}
+impl<'a, A> BatchSampler for FSamplerCube<'a, A> {}
+impl<'a, A> BatchSampler for FSamplerCubeArray<'a, A> {}
+
+/// A sampler shared between the threads of a batch.
+struct SharedSampler<'s, S: BatchSampler>(&'s S);
+
+// SAFETY: `texel_fetch` and `texture_lod` are `const` member functions of the gli samplers: they only read the
+// texels of the storage and the wrap, filter and border color of the sampler, and the Rust side fallbacks only
+// call them or read the same state. The errors of the bindings are recorded in thread local variables.
+// The storage is not written during a batch, since `for_each_chunk` borrows the sampler for its whole duration,
+// and the samplers and textures are not `Send`, so no other thread holds a handle to the storage.
+unsafe impl<'s, S: BatchSampler> Sync for SharedSampler<'s, S> {}
+
+/// Call `process` on matching chunks of `inputs` and `outputs`, which must have the same length.
+///
+/// Large batches are split evenly over the available threads.
+pub(crate) fn for_each_chunk<S, I, O, F>(sampler: &S, inputs: &[I], outputs: &mut [O], process: F)
+    where
+        S: BatchSampler,
+        I: Sync,
+        O: Send,
+        F: Fn(&S, &[I], &mut [O]) + Sync {
+
+    debug_assert_eq!(inputs.len(), outputs.len());
+
+    let threads = thread::available_parallelism().map(|count| count.get()).unwrap_or(1);
+    let chunk_len = inputs.len().div_ceil(threads).max(MIN_CHUNK_LEN);
+
+    if chunk_len >= inputs.len() {
+        process(sampler, inputs, outputs);
+        return
+    }
+
+    let shared = &SharedSampler(sampler);
+    let process = &process;
+    thread::scope(|scope| {
+        for (inputs, outputs) in inputs.chunks(chunk_len).zip(outputs.chunks_mut(chunk_len)) {
+            scope.spawn(move || process(shared.0, inputs, outputs));
+        }
+    });
+}
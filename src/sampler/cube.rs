@@ -0,0 +1,136 @@
+// Helper functions shared by the cube map samplers.
+//
+// Face indices follow the order used by `TextureCube::get_face`: +X, -X, +Y, -Y, +Z, -Z.
+// The projection of a direction onto a face follows the OpenGL cube map convention.
+
+use crate::extent::Extent2d;
+
+/// The number of faces in a cube map.
+pub(crate) const CUBE_FACES: usize = 6;
+
+/// Select the face a direction points at, and project the direction onto this face.
+///
+/// Return the face index and the normalized coordinate on the face.
+pub(crate) fn select_face(direction: [f32; 3]) -> (usize, [f32; 2]) {
+
+    let [x, y, z] = direction;
+    let (ax, ay, az) = (x.abs(), y.abs(), z.abs());
+
+    let (face, sc, tc, ma) = if ax >= ay && ax >= az {
+        if x >= 0.0 { (0, -z, -y, ax) } else { (1, z, -y, ax) }
+    } else if ay >= az {
+        if y >= 0.0 { (2, x, z, ay) } else { (3, x, -z, ay) }
+    } else {
+        if z >= 0.0 { (4, x, -y, az) } else { (5, -x, -y, az) }
+    };
+
+    // A zero direction does not point at any face, just avoid producing NaN.
+    let ma = if ma > 0.0 { ma } else { 1.0 };
+
+    (face, [(sc / ma + 1.0) * 0.5, (tc / ma + 1.0) * 0.5])
+}
+
+/// Return the direction pointing at a normalized coordinate of a cube face.
+///
+/// This is the inverse operation of `select_face`. The returned direction is not normalized.
+pub(crate) fn face_direction(face: usize, coord: [f32; 2]) -> [f32; 3] {
+
+    let sc = coord[0] * 2.0 - 1.0;
+    let tc = coord[1] * 2.0 - 1.0;
+
+    match face {
+        | 0 => [ 1.0, -tc, -sc],
+        | 1 => [-1.0, -tc,  sc],
+        | 2 => [  sc, 1.0,  tc],
+        | 3 => [  sc,-1.0, -tc],
+        | 4 => [  sc, -tc, 1.0],
+        | 5 => [ -sc, -tc,-1.0],
+        | _ => unreachable!("Cube face index must be less than {}.", CUBE_FACES),
+    }
+}
+
+/// Sample a cube map along a direction with bilinear filtering across the face edges,
+/// and linear filtering between the two nearest mipmap levels.
+///
+/// `extent` returns the width of a face at the specific level,
+/// and `fetch` reads a texel at a texel coordinate, a face and a level.
+pub(crate) fn sample_seamless<E, F>(direction: [f32; 3], level: f32, levels: usize, extent: E, fetch: F) -> [f32; 4]
+    where
+        E: Fn(usize) -> u32,
+        F: Fn(Extent2d, usize, usize) -> [f32; 4] {
+
+    let (face, coord) = select_face(direction);
+
+    let max_level = levels.saturating_sub(1) as f32;
+    let level = level.max(0.0).min(max_level);
+
+    let level_floor = level.floor();
+    let level_ceil = level.ceil();
+
+    let texel_floor = bilinear_seamless(face, coord, level_floor as usize, &extent, &fetch);
+    if level_ceil == level_floor {
+        texel_floor
+    } else {
+        let texel_ceil = bilinear_seamless(face, coord, level_ceil as usize, &extent, &fetch);
+        mix(texel_floor, texel_ceil, level - level_floor)
+    }
+}
+
+fn bilinear_seamless<E, F>(face: usize, coord: [f32; 2], level: usize, extent: &E, fetch: &F) -> [f32; 4]
+    where
+        E: Fn(usize) -> u32,
+        F: Fn(Extent2d, usize, usize) -> [f32; 4] {
+
+    let size = extent(level).max(1);
+    let size_f = size as f32;
+
+    let texel_x = coord[0] * size_f - 0.5;
+    let texel_y = coord[1] * size_f - 0.5;
+    let x0 = texel_x.floor();
+    let y0 = texel_y.floor();
+    let blend_x = texel_x - x0;
+    let blend_y = texel_y - y0;
+
+    let fetch_tap = |x: i64, y: i64| -> [f32; 4] {
+        let (tap_face, tap_coord) = resolve_texel(face, x, y, size);
+        fetch(tap_coord, tap_face, level)
+    };
+
+    let (x0, y0) = (x0 as i64, y0 as i64);
+    let texel00 = fetch_tap(x0, y0);
+    let texel10 = fetch_tap(x0 + 1, y0);
+    let texel01 = fetch_tap(x0, y0 + 1);
+    let texel11 = fetch_tap(x0 + 1, y0 + 1);
+
+    mix(mix(texel00, texel10, blend_x), mix(texel01, texel11, blend_x), blend_y)
+}
+
+/// Find the face and the texel coordinate of a texel which may lie outside its own face.
+fn resolve_texel(face: usize, x: i64, y: i64, size: u32) -> (usize, Extent2d) {
+
+    let max = i64::from(size) - 1;
+    if x >= 0 && x <= max && y >= 0 && y <= max {
+        return (face, Extent2d { width: x as u32, height: y as u32 });
+    }
+
+    // Go through the direction of the texel center to find the texel on the adjacent face.
+    let size_f = size as f32;
+    let coord = [(x as f32 + 0.5) / size_f, (y as f32 + 0.5) / size_f];
+    let (adjacent_face, adjacent_coord) = select_face(face_direction(face, coord));
+
+    let to_texel = |c: f32| -> u32 {
+        ((c * size_f).floor() as i64).max(0).min(max) as u32
+    };
+
+    (adjacent_face, Extent2d { width: to_texel(adjacent_coord[0]), height: to_texel(adjacent_coord[1]) })
+}
+
+#[inline]
+fn mix(a: [f32; 4], b: [f32; 4], factor: f32) -> [f32; 4] {
+    [
+        a[0] + (b[0] - a[0]) * factor,
+        a[1] + (b[1] - a[1]) * factor,
+        a[2] + (b[2] - a[2]) * factor,
+        a[3] + (b[3] - a[3]) * factor,
+    ]
+}
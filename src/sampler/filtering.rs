@@ -0,0 +1,359 @@
+// Texel addressing and filtering helpers for the sampling operations implemented on the Rust side.
+//
+// Unlike gli, which wraps the normalized coordinate before filtering,
+// these helpers wrap every texel of a filtering footprint independently, like GPU samplers do.
+
+use crate::sampler::{Wrap, Filter};
+use crate::extent::Extent2d;
+
+/// Map an integer texel coordinate into the texture according to a wrap mode.
+///
+/// Return `None` if the texel lies outside the texture and the border color must be used instead.
+pub(crate) fn wrap_texel(wrap: &Wrap, texel: i64, size: u32) -> Option<u32> {
+
+    let size = i64::from(size.max(1));
+    let mirror = |texel: i64| if texel < 0 { -1 - texel } else { texel };
+
+    let wrapped = match *wrap {
+        | Wrap::CLAMP_TO_BORDER => {
+            if texel < 0 || texel >= size { return None } else { texel }
+        },
+        | Wrap::REPEAT => texel.rem_euclid(size),
+        | Wrap::MIRROR_REPEAT => {
+            let period = texel.rem_euclid(size * 2);
+            if period < size { period } else { size * 2 - 1 - period }
+        },
+        | Wrap::MIRROR_CLAMP_TO_EDGE => mirror(texel).min(size - 1),
+        | Wrap::MIRROR_CLAMP_TO_BORDER => {
+            let mirrored = mirror(texel);
+            if mirrored >= size { return None } else { mirrored }
+        },
+        // Wrap::CLAMP_TO_EDGE and any invalid value.
+        | _ => texel.max(0).min(size - 1),
+    };
+
+    Some(wrapped as u32)
+}
+
+/// Return the first texel and the blend factor of the linear filtering footprint along an axis.
+#[inline]
+pub(crate) fn linear_footprint(coord: f32, size: u32) -> (i64, f32) {
+    let texel = coord * size as f32 - 0.5;
+    let floor = texel.floor();
+    (floor as i64, texel - floor)
+}
+
+/// Return the texel selected by nearest filtering along an axis.
+#[inline]
+pub(crate) fn nearest_texel(coord: f32, size: u32) -> i64 {
+    (coord * size as f32).floor() as i64
+}
+
+/// Fetch the four texels of the bilinear footprint at `coord`.
+///
+/// The texels are returned in the order used by `textureGather` in GLSL: (i0, j1), (i1, j1), (i1, j0), (i0, j0),
+/// together with the horizontal and vertical blend factors of the footprint.
+pub(crate) fn gather_2d<F>(coord: [f32; 2], extent: [u32; 2], wraps: [&Wrap; 2], border_color: [f32; 4], fetch: F) -> ([[f32; 4]; 4], [f32; 2])
+    where
+        F: Fn(Extent2d) -> [f32; 4] {
+
+    let (x0, blend_x) = linear_footprint(coord[0], extent[0]);
+    let (y0, blend_y) = linear_footprint(coord[1], extent[1]);
+
+    let fetch_tap = |x: i64, y: i64| -> [f32; 4] {
+        match (wrap_texel(wraps[0], x, extent[0]), wrap_texel(wraps[1], y, extent[1])) {
+            | (Some(width), Some(height)) => fetch(Extent2d { width, height }),
+            | _ => border_color,
+        }
+    };
+
+    let texels = [
+        fetch_tap(x0,     y0 + 1),
+        fetch_tap(x0 + 1, y0 + 1),
+        fetch_tap(x0 + 1, y0),
+        fetch_tap(x0,     y0),
+    ];
+
+    (texels, [blend_x, blend_y])
+}
+
+/// Return the bilinear weights of the texels returned by `gather_2d`.
+#[inline]
+pub(crate) fn gather_weights(blend: [f32; 2]) -> [f32; 4] {
+    let [blend_x, blend_y] = blend;
+    [
+        (1.0 - blend_x) * blend_y,
+        blend_x * blend_y,
+        blend_x * (1.0 - blend_y),
+        (1.0 - blend_x) * (1.0 - blend_y),
+    ]
+}
+
+/// Return the texels and their weights of the filtering footprint along an axis.
+///
+/// Only the first `n` elements of the returned array are used, where `n` is the last returned value.
+fn footprint(filter: &Filter, coord: f32, size: u32) -> ([(i64, f32); 4], usize) {
+
+    match *filter {
+        | Filter::LINEAR => {
+            let (texel, blend) = linear_footprint(coord, size);
+            ([(texel, 1.0 - blend), (texel + 1, blend), (0, 0.0), (0, 0.0)], 2)
+        },
+        | Filter::CUBIC_BSPLINE | Filter::CATMULL_ROM => {
+            let (texel, blend) = linear_footprint(coord, size);
+            let weights = cubic_weights(filter, blend);
+            ([(texel - 1, weights[0]), (texel, weights[1]), (texel + 1, weights[2]), (texel + 2, weights[3])], 4)
+        },
+        | _ => ([(nearest_texel(coord, size), 1.0), (0, 0.0), (0, 0.0), (0, 0.0)], 1),
+    }
+}
+
+/// Return the weights of the four texels of a cubic filter, where `t` is the position between the two middle texels.
+fn cubic_weights(filter: &Filter, t: f32) -> [f32; 4] {
+    [kernel(filter, t + 1.0), kernel(filter, t), kernel(filter, 1.0 - t), kernel(filter, 2.0 - t)]
+}
+
+/// Filter the texels of a single level around `coord`, wrapping each axis with its own wrap mode.
+///
+/// `coord`, `extent` and `wraps` contain one element per axis(up to 3 axes),
+/// and `fetch` reads a texel at a texel coordinate of the level.
+pub(crate) fn filter_level<F>(coord: &[f32], extent: &[u32], wraps: &[Wrap], filter: &Filter, border_color: [f32; 4], fetch: F) -> [f32; 4]
+    where
+        F: Fn(&[u32]) -> [f32; 4] {
+
+    let axes = coord.len();
+    debug_assert!(axes <= 3 && extent.len() >= axes && wraps.len() >= axes);
+
+    // The wrapped texels of the footprint along each axis, `None` for the texels replaced by the border color.
+    let mut taps = [[(None, 0.0_f32); 4]; 3];
+    let mut counts = [1_usize; 3];
+    for axis in 0..axes {
+        let (texels, count) = footprint(filter, coord[axis], extent[axis]);
+        for (tap, &(texel, weight)) in taps[axis].iter_mut().zip(texels[..count].iter()) {
+            *tap = (wrap_texel(&wraps[axis], texel, extent[axis]), weight);
+        }
+        counts[axis] = count;
+    }
+
+    let mut result = [0.0_f32; 4];
+    // Walk through every combination of the taps of each axis.
+    for index in 0..(counts[0] * counts[1] * counts[2]) {
+
+        let selected = [index % counts[0], index / counts[0] % counts[1], index / (counts[0] * counts[1])];
+
+        let mut texel_coord = [0_u32; 3];
+        let mut weight = 1.0_f32;
+        let mut inside = true;
+
+        for axis in 0..axes {
+            let (texel, tap_weight) = taps[axis][selected[axis]];
+            weight *= tap_weight;
+
+            match texel {
+                | Some(texel) => texel_coord[axis] = texel,
+                | None => inside = false,
+            }
+        }
+
+        if weight == 0.0 { continue }
+
+        let texel = if inside { fetch(&texel_coord[..axes]) } else { border_color };
+        for (component, value) in result.iter_mut().zip(texel.iter()) {
+            *component += value * weight;
+        }
+    }
+
+    result
+}
+
+/// The state of a sampler used by the sampling done on the Rust side.
+pub(crate) struct SamplerState<'s> {
+    /// The wrap mode of each axis.
+    pub wraps: &'s [Wrap],
+    /// How the texels of the two nearest levels are combined.
+    pub mip: &'s Filter,
+    /// How the texels within a level are filtered.
+    pub min: &'s Filter,
+    pub border_color: [f32; 4],
+}
+
+/// Sample a texture at a specific level, wrapping each axis with its own wrap mode.
+///
+/// `extent` returns the extent of a level, and `fetch` reads a texel at a texel coordinate and a level.
+pub(crate) fn sample_lod<E, F>(coord: &[f32], level: f32, levels: usize, state: SamplerState<'_>, extent: E, fetch: F) -> [f32; 4]
+    where
+        E: Fn(usize) -> [u32; 3],
+        F: Fn(&[u32], usize) -> [f32; 4] {
+
+    let max_level = levels.saturating_sub(1) as f32;
+    let level = level.max(0.0).min(max_level);
+
+    let filter_at = |level: usize| {
+        filter_level(coord, &extent(level), state.wraps, state.min, state.border_color, |texel_coord| fetch(texel_coord, level))
+    };
+
+    // The cubic filters only apply within a level, the levels are blended linearly.
+    if *state.mip == Filter::LINEAR || state.mip.is_cubic() {
+
+        let level_floor = level.floor();
+        let level_ceil = level.ceil();
+
+        let texel_floor = filter_at(level_floor as usize);
+        if level_ceil == level_floor {
+            texel_floor
+        } else {
+            let texel_ceil = filter_at(level_ceil as usize);
+            let factor = level - level_floor;
+            [
+                texel_floor[0] + (texel_ceil[0] - texel_floor[0]) * factor,
+                texel_floor[1] + (texel_ceil[1] - texel_floor[1]) * factor,
+                texel_floor[2] + (texel_ceil[2] - texel_floor[2]) * factor,
+                texel_floor[3] + (texel_ceil[3] - texel_floor[3]) * factor,
+            ]
+        }
+    } else {
+        filter_at(level.round() as usize)
+    }
+}
+
+/// Compute the texels of a level by filtering the texels of the previous level at the center of each destination texel.
+///
+/// Return the texels in the order of their coordinate, with the first axis varying the fastest.
+pub(crate) fn downsample<F>(src_extent: &[u32], dst_extent: &[u32], wraps: &[Wrap], filter: &Filter, border_color: [f32; 4], fetch: F) -> Vec<[f32; 4]>
+    where
+        F: Fn(&[u32]) -> [f32; 4] {
+
+    let axes = src_extent.len();
+    let size = |axis: usize| if axis < axes { dst_extent[axis].max(1) } else { 1 };
+
+    let mut texels = Vec::with_capacity((size(0) * size(1) * size(2)) as usize);
+    for z in 0..size(2) {
+        for y in 0..size(1) {
+            for x in 0..size(0) {
+                let dst_texel = [x, y, z];
+                let mut coord = [0.0_f32; 3];
+                for axis in 0..axes {
+                    coord[axis] = (dst_texel[axis] as f32 + 0.5) / size(axis) as f32;
+                }
+
+                texels.push(filter_level(&coord[..axes], src_extent, wraps, filter, border_color, &fetch));
+            }
+        }
+    }
+
+    texels
+}
+
+/// Resample the texels of a level to another extent, filtering each axis in turn.
+///
+/// Unlike `downsample`, the filter is stretched over all the source texels covered by a destination texel when
+/// an axis is shrunk, so that no texel is skipped. When an axis is enlarged, the texels are filtered like the samplers do.
+/// `texels` and the returned texels are in the order of their coordinate, with the first axis varying the fastest.
+pub(crate) fn resample(src_extent: &[u32], dst_extent: &[u32], wraps: &[Wrap], filter: &Filter, border_color: [f32; 4], texels: Vec<[f32; 4]>) -> Vec<[f32; 4]> {
+
+    let axes = src_extent.len();
+    debug_assert!(axes <= 3 && dst_extent.len() >= axes && wraps.len() >= axes);
+
+    let mut extent = [1_u32; 3];
+    extent[..axes].copy_from_slice(src_extent);
+    let mut texels = texels;
+
+    for axis in 0..axes {
+
+        let taps = resample_taps(filter, &wraps[axis], extent[axis], dst_extent[axis].max(1));
+        let mut resampled_extent = extent;
+        resampled_extent[axis] = dst_extent[axis].max(1);
+
+        let mut resampled = Vec::with_capacity((resampled_extent[0] * resampled_extent[1] * resampled_extent[2]) as usize);
+        for z in 0..resampled_extent[2] {
+            for y in 0..resampled_extent[1] {
+                for x in 0..resampled_extent[0] {
+
+                    let mut coord = [x, y, z];
+                    let mut value = [0.0_f32; 4];
+                    for &(texel, weight) in taps[coord[axis] as usize].iter() {
+                        let source = match texel {
+                            | Some(texel) => {
+                                coord[axis] = texel;
+                                texels[((coord[2] * extent[1] + coord[1]) * extent[0] + coord[0]) as usize]
+                            },
+                            | None => border_color,
+                        };
+                        for (component, source) in value.iter_mut().zip(source.iter()) {
+                            *component += source * weight;
+                        }
+                    }
+                    resampled.push(value);
+                }
+            }
+        }
+
+        texels = resampled;
+        extent = resampled_extent;
+    }
+
+    texels
+}
+
+/// Return the wrapped source texels and their weights for each destination texel along an axis.
+fn resample_taps(filter: &Filter, wrap: &Wrap, src_size: u32, dst_size: u32) -> Vec<Vec<(Option<u32>, f32)>> {
+
+    let ratio = src_size as f32 / dst_size as f32;
+    // The kernel covers all the source texels of a destination texel when the axis is shrunk.
+    let scale = ratio.max(1.0);
+    let support = match *filter {
+        | Filter::LINEAR => 1.0,
+        | Filter::CUBIC_BSPLINE | Filter::CATMULL_ROM => 2.0,
+        | _ => 0.0,
+    } * scale;
+
+    (0..dst_size).map(|dst_texel| {
+
+        // The center of the destination texel, in source texels.
+        let center = (dst_texel as f32 + 0.5) * ratio;
+        if support == 0.0 {
+            return vec![(wrap_texel(wrap, center.floor() as i64, src_size), 1.0)]
+        }
+
+        let first = (center - 0.5 - support).floor() as i64;
+        let last = (center - 0.5 + support).ceil() as i64;
+        let mut taps: Vec<(i64, f32)> = (first..=last)
+            .map(|texel| (texel, kernel(filter, (texel as f32 + 0.5 - center) / scale)))
+            .filter(|&(_, weight)| weight != 0.0)
+            .collect();
+
+        let total: f32 = taps.iter().map(|&(_, weight)| weight).sum();
+        taps.iter_mut().for_each(|tap| tap.1 /= total);
+
+        taps.into_iter().map(|(texel, weight)| (wrap_texel(wrap, texel, src_size), weight)).collect()
+    }).collect()
+}
+
+/// Return the weight of a filter at the distance `x` from the sample, in texels. Match `footprint` when scaled by one.
+///
+/// It is also the source of the weights of `cubic_weights`, so the sampling and the resizing share the same cubic filters.
+fn kernel(filter: &Filter, x: f32) -> f32 {
+
+    let x = x.abs();
+    match *filter {
+        | Filter::CATMULL_ROM => {
+            if x < 1.0 {
+                1.5 * x * x * x - 2.5 * x * x + 1.0
+            } else if x < 2.0 {
+                -0.5 * x * x * x + 2.5 * x * x - 4.0 * x + 2.0
+            } else {
+                0.0
+            }
+        },
+        | Filter::CUBIC_BSPLINE => {
+            if x < 1.0 {
+                (3.0 * x * x * x - 6.0 * x * x + 4.0) / 6.0
+            } else if x < 2.0 {
+                (2.0 - x) * (2.0 - x) * (2.0 - x) / 6.0
+            } else {
+                0.0
+            }
+        },
+        | _ => (1.0 - x).max(0.0),
+    }
+}
@@ -1,69 +1,160 @@
 
 use crate::ffi::root::gli;
 use crate::ffi::root::bindings::FSampler1DArray as bindings;
+use crate::ffi::root::bindings::TexelType4F;
+use crate::ffi::root::bindings::Texture as texture_bindings;
 
-use crate::sampler::{Wrap, Filter};
+use crate::sampler::{Wrap, Filter, ReadOnly, ReadWrite, SamplerTexture};
+use crate::sampler::filtering;
+use crate::sampler::batch;
 use crate::texture::Texture1DArray;
 use crate::extent::{Extent1d, NormalizeCoord1d};
 
 use std::marker::PhantomData;
+use std::ops::RangeInclusive;
 
 /// 1d array texture sampler.
 ///
 /// It interprets the texture data as float.
-#[repr(transparent)]
-pub struct FSampler1DArray<'a> {
+/// It only writes to its texture when it is created from a mutable reference, see `ReadWrite`.
+pub struct FSampler1DArray<'a, A = ReadOnly> {
 
     ffi: gli::fsampler1DArray,
-    phantom_type: PhantomData<&'a ()>,
+    wraps: [Wrap; 1],
+    mip: Filter,
+    min: Filter,
+    border_color: [f32; 4],
+    phantom_type: PhantomData<&'a *const ()>,
+    access: PhantomData<A>,
 }
 
-impl<'a, 'b: 'a> FSampler1DArray<'a> {
+impl<'a, A> FSampler1DArray<'a, A> {
 
     /// Constructor of `FSampler1DArray`.
+    ///
+    /// Panic if the texture is empty or its format is compressed.
     #[cfg(not(target_os = "windows"))]
-    pub fn new(texture: &'b Texture1DArray, wrap: Wrap, mip: Filter, min: Filter) -> FSampler1DArray {
+    pub fn new(texture: impl SamplerTexture<'a, Texture1DArray, Access = A>, wrap: Wrap, mip: Filter, min: Filter) -> FSampler1DArray<'a, A> {
         FSampler1DArray {
-            ffi: unsafe { bindings::fsampler1darray_new(texture.raw_ffi(), wrap.0, mip.0, min.0) },
+            ffi: crate::sampler::new_native(|| unsafe { bindings::fsampler1darray_new(texture.into_texture().raw_ffi(), wrap.0, mip.native(), min.native()) }),
+            wraps: [wrap], mip, min,
+            border_color: [0.0, 0.0, 0.0, 1.0],
             phantom_type: PhantomData,
+            access: PhantomData,
         }
     }
 
     /// Set the border color used by sampler. Default is `[0.0, 0.0, 0.0, 1.0]`.
     pub fn set_border_color(&mut self, color: [f32; 4]) {
         unsafe { bindings::fsampler1darray_set_border_color(&mut self.ffi, color.into()); }
+        self.border_color = color;
     }
 
+    /// Fetch a texel from the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
+    pub fn texel_fetch(&self, texel_coord: Extent1d, layer: usize, level: usize) -> [f32; 4] {
+        let raw = crate::sampler::call_native(|| unsafe { bindings::fsampler1darray_texel_fetch(&self.ffi, texel_coord.into(), layer, level) });
+        raw.content
+    }
+
+    /// Sample the sampler texture at a specific level.
+    pub fn texel_lod(&self, sample_coord: NormalizeCoord1d, layer: usize, level: f32) -> [f32; 4] {
+        let texture = self.target_texture();
+        filtering::sample_lod(&[sample_coord.x], level, unsafe { texture_bindings::texture_levels(texture) },
+            self.state(),
+            |level| *unsafe { texture_bindings::texture_extent(texture, level) },
+            |texel_coord, level| self.texel_fetch(Extent1d { width: texel_coord[0] }, layer, level))
+    }
+
+    /// Fetch the texels of a layer at many texel coordinates from the sampler texture.
+    ///
+    /// `texels` receives one texel for each element of `texel_coords`, and must have the same length.
+    /// The loop runs in the native library, and large batches are split over several threads.
+    /// Panic if one of the texels is outside the texture.
+    pub fn texel_fetch_many(&self, texel_coords: &[Extent1d], layer: usize, level: usize, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(texel_coords.len(), texels.len(), "The number of texels must match the number of texel coordinates.");
+
+        batch::for_each_chunk(self, texel_coords, texels, |sampler, texel_coords, texels| {
+            let coords: Vec<u32> = texel_coords.iter().map(|&texel_coord| texel_coord.into()).collect();
+            crate::sampler::call_native(|| unsafe { bindings::fsampler1darray_texel_fetch_many(&sampler.ffi, coords.as_ptr(), coords.len(), layer, level, texels.as_mut_ptr() as *mut TexelType4F) });
+        });
+    }
+
+    /// Sample the sampler texture of a layer at many coordinates at a specific level.
+    ///
+    /// `texels` receives one texel for each element of `sample_coords`, and must have the same length.
+    /// The texels are sampled on the Rust side like `texel_lod`, and large batches are split over several threads.
+    pub fn texel_lod_many(&self, sample_coords: &[NormalizeCoord1d], layer: usize, level: f32, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(sample_coords.len(), texels.len(), "The number of texels must match the number of sample coordinates.");
+
+        batch::for_each_chunk(self, sample_coords, texels, |sampler, sample_coords, texels| {
+            for (sample_coord, texel) in sample_coords.iter().zip(texels.iter_mut()) {
+                *texel = sampler.texel_lod(*sample_coord, layer, level);
+            }
+        });
+    }
+
+    /// Return the state used by the sampling done on the Rust side.
+    fn state(&self) -> filtering::SamplerState<'_> {
+        filtering::SamplerState { wraps: &self.wraps, mip: &self.mip, min: &self.min, border_color: self.border_color }
+    }
+
+    /// Return the texture which the sampler reads from.
+    fn target_texture(&self) -> &gli::texture {
+        unsafe { &(*bindings::fsampler1darray_target_texture(&self.ffi))._base }
+    }
+}
+
+impl<'a> FSampler1DArray<'a, ReadWrite> {
+
     /// Clear the sampler texture with a uniform texel.
     pub fn clear(&mut self, texel: [f32; 4]) {
-        unsafe { bindings::fsampler1darray_clear(&mut self.ffi, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler1darray_clear(&mut self.ffi, texel.into()) });
     }
 
     /// Generate all the mipmaps of the sampler texture from the texture base level.
     pub fn generate_mipmaps(&mut self, minification: Filter) {
-        unsafe { bindings::fsampler1darray_generate_mipmaps1(&mut self.ffi, minification.0); }
+        let texture = self.target_texture();
+        let (base_layer, max_layer, base_level, max_level) = unsafe {(
+            texture_bindings::texture_base_layer(texture), texture_bindings::texture_max_layer(texture),
+            texture_bindings::texture_base_level(texture), texture_bindings::texture_max_level(texture),
+        )};
+        self.downsample_levels(base_layer..=max_layer, base_level, max_level, &minification);
     }
 
     /// Generate the mipmaps of the sampler texture from the texture base level to the texture max level included.
     pub fn generate_mipmaps_detail(&mut self, base_layer: usize, max_layer: usize, base_level: usize, max_level: usize, minification: Filter) {
-        unsafe { bindings::fsampler1darray_generate_mipmaps2(&mut self.ffi, base_layer, max_layer, base_level, max_level, minification.0); }
-    }
-
-    /// Fetch a texel from the sampler texture.
-    pub fn texel_fetch(&self, texel_coord: Extent1d, layer: usize, level: usize) -> [f32; 4] {
-        let raw = unsafe { bindings::fsampler1darray_texel_fetch(&self.ffi, texel_coord.into(), layer, level) };
-        raw.content
+        self.downsample_levels(base_layer..=max_layer, base_level, max_level, &minification);
     }
 
     /// Write a texel in the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
     pub fn texel_write(&mut self, texel_coord: Extent1d, layer: usize, level: usize, texel: [f32; 4]) {
-        unsafe { bindings::fsampler1darray_texel_write(&mut self.ffi, texel_coord.into(), layer, level, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler1darray_texel_write(&mut self.ffi, texel_coord.into(), layer, level, texel.into()) });
     }
 
-    /// Sample the sampler texture at a specific level.
-    pub fn texel_lod(&self, sample_coord: NormalizeCoord1d, layer: usize, level: f32) -> [f32; 4] {
-        let raw = unsafe { bindings::fsampler1darray_texel_lod(&self.ffi, sample_coord.into(), layer, level) };
-        raw.content
+    /// Generate the levels after `base_level` up to `max_level` of each layer by filtering the previous level with the wrap mode of the sampler.
+    fn downsample_levels(&mut self, layers: RangeInclusive<usize>, base_level: usize, max_level: usize, minification: &Filter) {
+
+        for layer in layers {
+            for level in base_level..max_level {
+
+                let texture = self.target_texture();
+                let src_extent = *unsafe { texture_bindings::texture_extent(texture, level) };
+                let dst_extent = *unsafe { texture_bindings::texture_extent(texture, level + 1) };
+
+                let texels = filtering::downsample(&src_extent[..1], &dst_extent[..1], &self.wraps, minification, self.border_color,
+                    |texel_coord| self.texel_fetch(Extent1d { width: texel_coord[0] }, layer, level));
+
+                for (i, texel) in texels.into_iter().enumerate() {
+                    self.texel_write(Extent1d { width: i as u32 }, layer, level + 1, texel);
+                }
+            }
+        }
     }
 }
 
@@ -1,8 +1,13 @@
 
 use crate::ffi::root::gli;
+use crate::ffi::root::glm;
 use crate::ffi::root::bindings::FSampler2D as bindings;
+use crate::ffi::root::bindings::TexelType4F;
+use crate::ffi::root::bindings::Texture as texture_bindings;
 
-use crate::sampler::{Wrap, Filter};
+use crate::sampler::{Wrap, Filter, ReadOnly, ReadWrite, SamplerTexture};
+use crate::sampler::filtering;
+use crate::sampler::batch;
 use crate::texture::Texture2D;
 use crate::extent::{Extent2d, NormalizeCoord2d};
 
@@ -11,60 +16,174 @@ use std::marker::PhantomData;
 /// 2d texture sampler.
 ///
 /// It interprets the texture data as float.
-#[repr(transparent)]
-pub struct FSampler2D<'a> {
+/// It only writes to its texture when it is created from a mutable reference, see `ReadWrite`.
+pub struct FSampler2D<'a, A = ReadOnly> {
 
     ffi: gli::fsampler2D,
-    phantom_type: PhantomData<&'a ()>,
+    wraps: [Wrap; 2],
+    mip: Filter,
+    min: Filter,
+    border_color: [f32; 4],
+    phantom_type: PhantomData<&'a *const ()>,
+    access: PhantomData<A>,
 }
 
-impl<'a, 'b: 'a> FSampler2D<'a> {
+impl<'a, A> FSampler2D<'a, A> {
 
     /// Constructor of `FSampler2D`.
     #[cfg(not(target_os = "windows"))]
-    pub fn new(texture: &'b Texture2D, wrap: Wrap, mip: Filter, min: Filter) -> FSampler2D {
+    pub fn new(texture: impl SamplerTexture<'a, Texture2D, Access = A>, wrap: Wrap, mip: Filter, min: Filter) -> FSampler2D<'a, A> {
+        FSampler2D::with_wraps(texture, [wrap.clone(), wrap], mip, min)
+    }
+
+    /// Constructor of `FSampler2D` with a wrap mode for each axis(s, t).
+    ///
+    /// The sampling and the mipmaps generation are done on the Rust side, where each texel of a filtering footprint
+    /// is wrapped independently like GPU samplers do, so the wrap modes may differ.
+    ///
+    /// Panic if the texture is empty or its format is compressed.
+    #[cfg(not(target_os = "windows"))]
+    pub fn with_wraps(texture: impl SamplerTexture<'a, Texture2D, Access = A>, wraps: [Wrap; 2], mip: Filter, min: Filter) -> FSampler2D<'a, A> {
         FSampler2D {
-            ffi: unsafe { bindings::fsampler2d_new(texture.raw_ffi(), wrap.0, mip.0, min.0) },
+            ffi: crate::sampler::new_native(|| unsafe { bindings::fsampler2d_new(texture.into_texture().raw_ffi(), wraps[0].0, mip.native(), min.native()) }),
+            wraps, mip, min,
+            border_color: [0.0, 0.0, 0.0, 1.0],
             phantom_type: PhantomData,
+            access: PhantomData,
         }
     }
 
     /// Set the border color used by sampler. Default is `[0.0, 0.0, 0.0, 1.0]`.
     pub fn set_border_color(&mut self, color: [f32; 4]) {
         unsafe { bindings::fsampler2d_set_border_color(&mut self.ffi, color.into()); }
+        self.border_color = color;
     }
 
+    /// Return the wrap mode of each axis(s, t).
+    pub fn wraps(&self) -> &[Wrap; 2] {
+        &self.wraps
+    }
+
+    /// Fetch a texel from the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
+    pub fn texel_fetch(&self, texel_coord: Extent2d, level: usize) -> [f32; 4] {
+        let raw = crate::sampler::call_native(|| unsafe { bindings::fsampler2d_texel_fetch(&self.ffi, &texel_coord.into(), level) });
+        raw.content
+    }
+
+    /// Sample the sampler texture at a specific level.
+    pub fn texel_lod(&self, sample_coord: NormalizeCoord2d, level: f32) -> [f32; 4] {
+        let coord: [f32; 2] = sample_coord.into();
+
+        let texture = self.target_texture();
+        filtering::sample_lod(&coord, level, unsafe { texture_bindings::texture_levels(texture) },
+            self.state(),
+            |level| *unsafe { texture_bindings::texture_extent(texture, level) },
+            |texel_coord, level| self.texel_fetch(Extent2d { width: texel_coord[0], height: texel_coord[1] }, level))
+    }
+
+    /// Fetch the texels at many texel coordinates from the sampler texture.
+    ///
+    /// `texels` receives one texel for each element of `texel_coords`, and must have the same length.
+    /// The loop runs in the native library, and large batches are split over several threads.
+    /// Panic if one of the texels is outside the texture.
+    pub fn texel_fetch_many(&self, texel_coords: &[Extent2d], level: usize, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(texel_coords.len(), texels.len(), "The number of texels must match the number of texel coordinates.");
+
+        batch::for_each_chunk(self, texel_coords, texels, |sampler, texel_coords, texels| {
+            let coords: Vec<[u32; 2]> = texel_coords.iter().map(|&texel_coord| texel_coord.into()).collect();
+            crate::sampler::call_native(|| unsafe { bindings::fsampler2d_texel_fetch_many(&sampler.ffi, coords.as_ptr(), coords.len(), level, texels.as_mut_ptr() as *mut TexelType4F) });
+        });
+    }
+
+    /// Sample the sampler texture at many coordinates at a specific level.
+    ///
+    /// `texels` receives one texel for each element of `sample_coords`, and must have the same length.
+    /// The texels are sampled on the Rust side like `texel_lod`, and large batches are split over several threads.
+    pub fn texel_lod_many(&self, sample_coords: &[NormalizeCoord2d], level: f32, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(sample_coords.len(), texels.len(), "The number of texels must match the number of sample coordinates.");
+
+        batch::for_each_chunk(self, sample_coords, texels, |sampler, sample_coords, texels| {
+            for (sample_coord, texel) in sample_coords.iter().zip(texels.iter_mut()) {
+                *texel = sampler.texel_lod(*sample_coord, level);
+            }
+        });
+    }
+
+    /// Gather a component from the four texels that would be used for bilinear filtering at the base level.
+    ///
+    /// The texels are returned in the same order as `textureGather` in GLSL: (i0, j1), (i1, j1), (i1, j0), (i0, j0).
+    /// `component` is the index of the component to gather(0 for red, 1 for green, 2 for blue and 3 for alpha).
+    pub fn gather(&self, sample_coord: NormalizeCoord2d, component: usize) -> [f32; 4] {
+
+        debug_assert!(component < 4);
+
+        let extent: glm::ivec3 = unsafe { texture_bindings::texture_extent(self.target_texture(), 0) };
+        let (texels, _) = filtering::gather_2d(
+            sample_coord.into(), [extent[0], extent[1]], [&self.wraps[0], &self.wraps[1]], self.border_color,
+            |texel_coord| self.texel_fetch(texel_coord, 0));
+
+        [texels[0][component], texels[1][component], texels[2][component], texels[3][component]]
+    }
+
+    /// Return the state used by the sampling done on the Rust side.
+    fn state(&self) -> filtering::SamplerState<'_> {
+        filtering::SamplerState { wraps: &self.wraps, mip: &self.mip, min: &self.min, border_color: self.border_color }
+    }
+
+    /// Return the texture which the sampler reads from.
+    fn target_texture(&self) -> &gli::texture {
+        unsafe { &(*bindings::fsampler2d_target_texture(&self.ffi))._base }
+    }
+}
+
+impl<'a> FSampler2D<'a, ReadWrite> {
+
     /// Clear the sampler texture with a uniform texel.
     pub fn clear(&mut self, texel: [f32; 4]) {
-        unsafe { bindings::fsampler2d_clear(&mut self.ffi, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler2d_clear(&mut self.ffi, texel.into()) });
     }
 
     /// Generate all the mipmaps of the sampler texture from the texture base level.
     pub fn generate_mipmaps(&mut self, minification: Filter) {
-        unsafe { bindings::fsampler2d_generate_mipmaps1(&mut self.ffi, minification.0); }
+        let texture = self.target_texture();
+        let (base_level, max_level) = unsafe { (texture_bindings::texture_base_level(texture), texture_bindings::texture_max_level(texture)) };
+        self.downsample_levels(base_level, max_level, &minification);
     }
 
     /// Generate the mipmaps of the sampler texture from the texture base level to the texture max level included.
     pub fn generate_mipmaps_detail(&mut self, base_level: usize, max_level: usize, minification: Filter) {
-        unsafe { bindings::fsampler2d_generate_mipmaps2(&mut self.ffi, base_level, max_level, minification.0); }
-    }
-
-    /// Fetch a texel from the sampler texture.
-    pub fn texel_fetch(&self, texel_coord: Extent2d, level: usize) -> [f32; 4] {
-        let raw = unsafe { bindings::fsampler2d_texel_fetch(&self.ffi, &texel_coord.into(), level) };
-        raw.content
+        self.downsample_levels(base_level, max_level, &minification);
     }
 
     /// Write a texel in the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
     pub fn texel_write(&mut self, texel_coord: Extent2d, level: usize, texel: [f32; 4]) {
-        unsafe { bindings::fsampler2d_texel_write(&mut self.ffi, &texel_coord.into(), level, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler2d_texel_write(&mut self.ffi, &texel_coord.into(), level, texel.into()) });
     }
 
-    /// Sample the sampler texture at a specific level.
-    pub fn texel_lod(&self, sample_coord: NormalizeCoord2d, level: f32) -> [f32; 4] {
-        let coord: [f32; 2] = sample_coord.into();
-        let raw = unsafe { bindings::fsampler2d_texel_lod(&self.ffi, &coord, level) };
-        raw.content
+    /// Generate the levels after `base_level` up to `max_level` by filtering the previous level with the wrap mode of each axis.
+    fn downsample_levels(&mut self, base_level: usize, max_level: usize, minification: &Filter) {
+
+        for level in base_level..max_level {
+
+            let texture = self.target_texture();
+            let src_extent = *unsafe { texture_bindings::texture_extent(texture, level) };
+            let dst_extent = *unsafe { texture_bindings::texture_extent(texture, level + 1) };
+
+            let texels = filtering::downsample(&src_extent[..2], &dst_extent[..2], &self.wraps, minification, self.border_color,
+                |texel_coord| self.texel_fetch(Extent2d { width: texel_coord[0], height: texel_coord[1] }, level));
+
+            let width = dst_extent[0].max(1);
+            for (i, texel) in texels.into_iter().enumerate() {
+                let i = i as u32;
+                self.texel_write(Extent2d { width: i % width, height: i / width }, level + 1, texel);
+            }
+        }
     }
 }
 
@@ -0,0 +1,129 @@
+
+use crate::sampler::{Wrap, Filter, CompareFunc};
+use crate::sampler::filtering;
+use crate::texture::{GliTexture, Texture2D};
+use crate::format::Format;
+use crate::extent::{Extent2d, NormalizeCoord2d};
+use crate::error::{Result, Error};
+
+use std::slice;
+
+/// 2d depth texture sampler with depth comparison.
+///
+/// It compares a reference value with the depth texels of the texture,
+/// and returns the filtered result of the comparisons(percentage-closer filtering).
+///
+/// The depth texels are decoded on the Rust side, since the samplers of gli fetch the depth and depth-stencil
+/// formats as raw integers.
+pub struct FSampler2DShadow<'a> {
+
+    texture: &'a Texture2D,
+    wraps: [Wrap; 2],
+    filter: Filter,
+    compare: CompareFunc,
+    border_depth: f32,
+}
+
+impl<'a, 'b: 'a> FSampler2DShadow<'a> {
+
+    /// Constructor of `FSampler2DShadow`.
+    ///
+    /// With `Filter::LINEAR`, the results of the comparisons with the four texels of the bilinear footprint are blended.
+    /// Otherwise only the nearest texel is compared.
+    ///
+    /// Return an error if the format of `texture` is not a depth format.
+    pub fn new(texture: &'b Texture2D, wrap: Wrap, filter: Filter, compare: CompareFunc) -> Result<FSampler2DShadow<'a>> {
+        FSampler2DShadow::with_wraps(texture, [wrap.clone(), wrap], filter, compare)
+    }
+
+    /// Constructor of `FSampler2DShadow` with a wrap mode for each axis(s, t).
+    ///
+    /// Return an error if the format of `texture` is not a depth format.
+    pub fn with_wraps(texture: &'b Texture2D, wraps: [Wrap; 2], filter: Filter, compare: CompareFunc) -> Result<FSampler2DShadow<'a>> {
+
+        let format = texture.format();
+        if !is_comparable(&format) {
+            return Err(Error::unsupported_format(format!("{} is not a depth format.", format)));
+        }
+
+        let sampler = FSampler2DShadow {
+            texture, wraps, filter, compare,
+            border_depth: 0.0,
+        };
+        Ok(sampler)
+    }
+
+    /// Set the depth value used for the texels outside the texture with border wrap modes. Default is `0.0`.
+    pub fn set_border_depth(&mut self, depth: f32) {
+        self.border_depth = depth;
+    }
+
+    /// Set the function used to compare the reference value with the depth texels.
+    pub fn set_compare_func(&mut self, compare: CompareFunc) {
+        self.compare = compare;
+    }
+
+    /// Fetch the depth of a texel from the sampler texture, normalized to `[0.0, 1.0]` for normalized depth formats.
+    ///
+    /// Panic if `texel_coord` or `level` is outside the texture.
+    pub fn depth_fetch(&self, texel_coord: Extent2d, level: usize) -> f32 {
+
+        assert!(level < self.texture.levels(), "The level {} is outside the texture.", level);
+        let extent = self.texture.extent(level);
+        assert!(texel_coord.width < extent.width && texel_coord.height < extent.height,
+            "The texel {:?} is outside the level {} of the texture.", texel_coord, level);
+
+        let texel_size = self.texture.size_at_level(level) / (extent.width * extent.height) as usize;
+        let level_offset: usize = (0..level).map(|previous| self.texture.size_at_level(previous)).sum();
+        let texel_offset = level_offset + (texel_coord.height * extent.width + texel_coord.width) as usize * texel_size;
+
+        let texel = unsafe { slice::from_raw_parts((self.texture.data() as *const u8).add(texel_offset), texel_size) };
+
+        // The stencil bits are stored after the depth bits, and ignored.
+        match self.texture.format() {
+            | Format::D16_UNORM_PACK16
+            | Format::D16_UNORM_S8_UINT_PACK32 => f32::from(u16::from_ne_bytes([texel[0], texel[1]])) / 65_535.0,
+            | Format::D24_UNORM_PACK32
+            | Format::D24_UNORM_S8_UINT_PACK32 => (u32::from_ne_bytes([texel[0], texel[1], texel[2], texel[3]]) & 0x00FF_FFFF) as f32 / 16_777_215.0,
+            | _ => f32::from_ne_bytes([texel[0], texel[1], texel[2], texel[3]]),
+        }
+    }
+
+    /// Compare `reference` with the depth texels at a specific level, and return the filtered result in `[0.0, 1.0]`.
+    pub fn texel_compare(&self, sample_coord: NormalizeCoord2d, reference: f32, level: usize) -> f32 {
+
+        let extent = self.texture.extent(level);
+        let coord: [f32; 2] = sample_coord.into();
+        let compare = |depth: f32| if self.compare.compare(reference, depth) { 1.0 } else { 0.0 };
+
+        if self.filter == Filter::LINEAR {
+
+            let (texels, blend) = filtering::gather_2d(
+                coord, [extent.width, extent.height], [&self.wraps[0], &self.wraps[1]], [self.border_depth; 4],
+                |texel_coord| [self.depth_fetch(texel_coord, level); 4]);
+
+            texels.iter().zip(filtering::gather_weights(blend).iter())
+                .map(|(texel, weight)| compare(texel[0]) * weight)
+                .sum()
+        } else {
+
+            let x = filtering::wrap_texel(&self.wraps[0], filtering::nearest_texel(coord[0], extent.width), extent.width);
+            let y = filtering::wrap_texel(&self.wraps[1], filtering::nearest_texel(coord[1], extent.height), extent.height);
+
+            let depth = match (x, y) {
+                | (Some(width), Some(height)) => self.depth_fetch(Extent2d { width, height }, level),
+                | _ => self.border_depth,
+            };
+            compare(depth)
+        }
+    }
+}
+
+/// Return whether the depth texels of `format` can be compared.
+fn is_comparable(format: &Format) -> bool {
+    [
+        Format::D16_UNORM_PACK16, Format::D16_UNORM_S8_UINT_PACK32,
+        Format::D24_UNORM_PACK32, Format::D24_UNORM_S8_UINT_PACK32,
+        Format::D32_SFLOAT_PACK32, Format::D32_SFLOAT_S8_UINT_PACK64,
+    ].contains(format)
+}
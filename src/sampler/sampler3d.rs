@@ -1,8 +1,12 @@
 
 use crate::ffi::root::gli;
 use crate::ffi::root::bindings::FSampler3D as bindings;
+use crate::ffi::root::bindings::TexelType4F;
+use crate::ffi::root::bindings::Texture as texture_bindings;
 
-use crate::sampler::{Wrap, Filter};
+use crate::sampler::{Wrap, Filter, ReadOnly, ReadWrite, SamplerTexture};
+use crate::sampler::filtering;
+use crate::sampler::batch;
 use crate::texture::Texture3D;
 use crate::extent::{Extent3d, NormalizeCoord3d};
 
@@ -11,60 +15,160 @@ use std::marker::PhantomData;
 /// 3d texture sampler.
 ///
 /// It interprets the texture data as float.
-#[repr(transparent)]
-pub struct FSampler3D<'a> {
+/// It only writes to its texture when it is created from a mutable reference, see `ReadWrite`.
+pub struct FSampler3D<'a, A = ReadOnly> {
 
     ffi: gli::fsampler3D,
-    phantom_type: PhantomData<&'a ()>,
+    wraps: [Wrap; 3],
+    mip: Filter,
+    min: Filter,
+    border_color: [f32; 4],
+    phantom_type: PhantomData<&'a *const ()>,
+    access: PhantomData<A>,
 }
 
-impl<'a, 'b: 'a> FSampler3D<'a> {
+impl<'a, A> FSampler3D<'a, A> {
 
     /// Constructor of `FSampler3D`.
     #[cfg(not(target_os = "windows"))]
-    pub fn new(texture: &'b Texture3D, wrap: Wrap, mip: Filter, min: Filter) -> FSampler3D {
+    pub fn new(texture: impl SamplerTexture<'a, Texture3D, Access = A>, wrap: Wrap, mip: Filter, min: Filter) -> FSampler3D<'a, A> {
+        FSampler3D::with_wraps(texture, [wrap.clone(), wrap.clone(), wrap], mip, min)
+    }
+
+    /// Constructor of `FSampler3D` with a wrap mode for each axis(s, t, r).
+    ///
+    /// The sampling and the mipmaps generation are done on the Rust side, where each texel of a filtering footprint
+    /// is wrapped independently like GPU samplers do, so the wrap modes may differ.
+    ///
+    /// Panic if the texture is empty or its format is compressed.
+    #[cfg(not(target_os = "windows"))]
+    pub fn with_wraps(texture: impl SamplerTexture<'a, Texture3D, Access = A>, wraps: [Wrap; 3], mip: Filter, min: Filter) -> FSampler3D<'a, A> {
         FSampler3D {
-            ffi: unsafe { bindings::fsampler3d_new(texture.raw_ffi(), wrap.0, mip.0, min.0) },
+            ffi: crate::sampler::new_native(|| unsafe { bindings::fsampler3d_new(texture.into_texture().raw_ffi(), wraps[0].0, mip.native(), min.native()) }),
+            wraps, mip, min,
+            border_color: [0.0, 0.0, 0.0, 1.0],
             phantom_type: PhantomData,
+            access: PhantomData,
         }
     }
 
     /// Set the border color used by sampler. Default is `[0.0, 0.0, 0.0, 1.0]`.
     pub fn set_border_color(&mut self, color: [f32; 4]) {
         unsafe { bindings::fsampler3d_set_border_color(&mut self.ffi, color.into()); }
+        self.border_color = color;
+    }
+
+    /// Return the wrap mode of each axis(s, t, r).
+    pub fn wraps(&self) -> &[Wrap; 3] {
+        &self.wraps
+    }
+
+    /// Fetch a texel from the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
+    pub fn texel_fetch(&self, texel_coord: Extent3d, level: usize) -> [f32; 4] {
+        let raw = crate::sampler::call_native(|| unsafe { bindings::fsampler3d_texel_fetch(&self.ffi, &texel_coord.into(), level) });
+        raw.content
+    }
+
+    /// Sample the sampler texture at a specific level.
+    pub fn texel_lod(&self, sample_coord: NormalizeCoord3d, level: f32) -> [f32; 4] {
+        let coord: [f32; 3] = sample_coord.into();
+
+        let texture = self.target_texture();
+        filtering::sample_lod(&coord, level, unsafe { texture_bindings::texture_levels(texture) },
+            self.state(),
+            |level| *unsafe { texture_bindings::texture_extent(texture, level) },
+            |texel_coord, level| self.texel_fetch(Extent3d { width: texel_coord[0], height: texel_coord[1], depth: texel_coord[2] }, level))
+    }
+
+    /// Fetch the texels at many texel coordinates from the sampler texture.
+    ///
+    /// `texels` receives one texel for each element of `texel_coords`, and must have the same length.
+    /// The loop runs in the native library, and large batches are split over several threads.
+    /// Panic if one of the texels is outside the texture.
+    pub fn texel_fetch_many(&self, texel_coords: &[Extent3d], level: usize, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(texel_coords.len(), texels.len(), "The number of texels must match the number of texel coordinates.");
+
+        batch::for_each_chunk(self, texel_coords, texels, |sampler, texel_coords, texels| {
+            let coords: Vec<[u32; 3]> = texel_coords.iter().map(|&texel_coord| texel_coord.into()).collect();
+            crate::sampler::call_native(|| unsafe { bindings::fsampler3d_texel_fetch_many(&sampler.ffi, coords.as_ptr(), coords.len(), level, texels.as_mut_ptr() as *mut TexelType4F) });
+        });
+    }
+
+    /// Sample the sampler texture at many coordinates at a specific level.
+    ///
+    /// `texels` receives one texel for each element of `sample_coords`, and must have the same length.
+    /// The texels are sampled on the Rust side like `texel_lod`, and large batches are split over several threads.
+    pub fn texel_lod_many(&self, sample_coords: &[NormalizeCoord3d], level: f32, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(sample_coords.len(), texels.len(), "The number of texels must match the number of sample coordinates.");
+
+        batch::for_each_chunk(self, sample_coords, texels, |sampler, sample_coords, texels| {
+            for (sample_coord, texel) in sample_coords.iter().zip(texels.iter_mut()) {
+                *texel = sampler.texel_lod(*sample_coord, level);
+            }
+        });
+    }
+
+    /// Return the state used by the sampling done on the Rust side.
+    fn state(&self) -> filtering::SamplerState<'_> {
+        filtering::SamplerState { wraps: &self.wraps, mip: &self.mip, min: &self.min, border_color: self.border_color }
     }
 
+    /// Return the texture which the sampler reads from.
+    fn target_texture(&self) -> &gli::texture {
+        unsafe { &(*bindings::fsampler3d_target_texture(&self.ffi))._base }
+    }
+}
+
+impl<'a> FSampler3D<'a, ReadWrite> {
+
     /// Clear the sampler texture with a uniform texel.
     pub fn clear(&mut self, texel: [f32; 4]) {
-        unsafe { bindings::fsampler3d_clear(&mut self.ffi, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler3d_clear(&mut self.ffi, texel.into()) });
     }
 
     /// Generate all the mipmaps of the sampler texture from the texture base level.
     pub fn generate_mipmaps(&mut self, minification: Filter) {
-        unsafe { bindings::fsampler3d_generate_mipmaps1(&mut self.ffi, minification.0); }
+        let texture = self.target_texture();
+        let (base_level, max_level) = unsafe { (texture_bindings::texture_base_level(texture), texture_bindings::texture_max_level(texture)) };
+        self.downsample_levels(base_level, max_level, &minification);
     }
 
     /// Generate the mipmaps of the sampler texture from the texture base level to the texture max level included.
     pub fn generate_mipmaps_detail(&mut self, base_level: usize, max_level: usize, minification: Filter) {
-        unsafe { bindings::fsampler3d_generate_mipmaps3(&mut self.ffi, base_level, max_level, minification.0); }
-    }
-
-    /// Fetch a texel from the sampler texture.
-    pub fn texel_fetch(&self, texel_coord: Extent3d, level: usize) -> [f32; 4] {
-        let raw = unsafe { bindings::fsampler3d_texel_fetch(&self.ffi, &texel_coord.into(), level) };
-        raw.content
+        self.downsample_levels(base_level, max_level, &minification);
     }
 
     /// Write a texel in the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
     pub fn texel_write(&mut self, texel_coord: Extent3d, level: usize, texel: [f32; 4]) {
-        unsafe { bindings::fsampler3d_texel_write(&mut self.ffi, &texel_coord.into(), level, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler3d_texel_write(&mut self.ffi, &texel_coord.into(), level, texel.into()) });
     }
 
-    /// Sample the sampler texture at a specific level.
-    pub fn texel_lod(&self, sample_coord: NormalizeCoord3d, level: f32) -> [f32; 4] {
-        let coord: [f32; 3] = sample_coord.into();
-        let raw = unsafe { bindings::fsampler3d_texel_lod(&self.ffi, &coord, level) };
-        raw.content
+    /// Generate the levels after `base_level` up to `max_level` by filtering the previous level with the wrap mode of each axis.
+    fn downsample_levels(&mut self, base_level: usize, max_level: usize, minification: &Filter) {
+
+        for level in base_level..max_level {
+
+            let texture = self.target_texture();
+            let src_extent = *unsafe { texture_bindings::texture_extent(texture, level) };
+            let dst_extent = *unsafe { texture_bindings::texture_extent(texture, level + 1) };
+
+            let texels = filtering::downsample(&src_extent, &dst_extent, &self.wraps, minification, self.border_color,
+                |texel_coord| self.texel_fetch(Extent3d { width: texel_coord[0], height: texel_coord[1], depth: texel_coord[2] }, level));
+
+            let width = dst_extent[0].max(1);
+            let height = dst_extent[1].max(1);
+            for (i, texel) in texels.into_iter().enumerate() {
+                let i = i as u32;
+                let texel_coord = Extent3d { width: i % width, height: i / width % height, depth: i / (width * height) };
+                self.texel_write(texel_coord, level + 1, texel);
+            }
+        }
     }
 }
 
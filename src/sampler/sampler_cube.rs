@@ -1,70 +1,201 @@
 
 use crate::ffi::root::gli;
 use crate::ffi::root::bindings::FSamplerCube as bindings;
+use crate::ffi::root::bindings::TexelType4F;
+use crate::ffi::root::bindings::Texture as texture_bindings;
 
-use crate::sampler::{Wrap, Filter};
+use crate::sampler::{Wrap, Filter, ReadOnly, ReadWrite, SamplerTexture};
+use crate::sampler::cube;
+use crate::sampler::filtering;
+use crate::sampler::batch;
 use crate::texture::TextureCube;
 use crate::extent::{Extent2d, NormalizeCoord2d};
 
 use std::marker::PhantomData;
+use std::ops::RangeInclusive;
 
 /// Cube map texture sampler.
 ///
 /// It interprets the texture data as float.
-#[repr(transparent)]
-pub struct FSamplerCube<'a> {
+/// It only writes to its texture when it is created from a mutable reference, see `ReadWrite`.
+pub struct FSamplerCube<'a, A = ReadOnly> {
 
     ffi: gli::fsamplerCube,
-    phantom_type: PhantomData<&'a ()>,
+    wraps: [Wrap; 2],
+    mip: Filter,
+    min: Filter,
+    border_color: [f32; 4],
+    phantom_type: PhantomData<&'a *const ()>,
+    access: PhantomData<A>,
 }
 
-impl<'a, 'b: 'a> FSamplerCube<'a> {
+impl<'a, A> FSamplerCube<'a, A> {
 
     /// Constructor of `FSamplerCube`.
     #[cfg(not(target_os = "windows"))]
-    pub fn new(texture: &'b TextureCube, wrap: Wrap, mip: Filter, min: Filter) -> FSamplerCube {
+    pub fn new(texture: impl SamplerTexture<'a, TextureCube, Access = A>, wrap: Wrap, mip: Filter, min: Filter) -> FSamplerCube<'a, A> {
+        FSamplerCube::with_wraps(texture, [wrap.clone(), wrap], mip, min)
+    }
+
+    /// Constructor of `FSamplerCube` with a wrap mode for each axis(s, t) of the faces.
+    ///
+    /// The sampling and the mipmaps generation are done on the Rust side, where each texel of a filtering footprint
+    /// is wrapped independently like GPU samplers do, so the wrap modes may differ.
+    ///
+    /// Panic if the texture is empty or its format is compressed.
+    #[cfg(not(target_os = "windows"))]
+    pub fn with_wraps(texture: impl SamplerTexture<'a, TextureCube, Access = A>, wraps: [Wrap; 2], mip: Filter, min: Filter) -> FSamplerCube<'a, A> {
         FSamplerCube {
-            ffi: unsafe { bindings::fsampler_cube_new(texture.raw_ffi(), wrap.0, mip.0, min.0) },
+            ffi: crate::sampler::new_native(|| unsafe { bindings::fsampler_cube_new(texture.into_texture().raw_ffi(), wraps[0].0, mip.native(), min.native()) }),
+            wraps, mip, min,
+            border_color: [0.0, 0.0, 0.0, 1.0],
             phantom_type: PhantomData,
+            access: PhantomData,
         }
     }
 
     /// Set the border color used by sampler. Default is `[0.0, 0.0, 0.0, 1.0]`.
     pub fn set_border_color(&mut self, color: [f32; 4]) {
         unsafe { bindings::fsampler_cube_set_border_color(&mut self.ffi, color.into()); }
+        self.border_color = color;
+    }
+
+    /// Return the wrap mode of each axis(s, t) of the faces.
+    pub fn wraps(&self) -> &[Wrap; 2] {
+        &self.wraps
+    }
+
+    /// Fetch a texel from the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
+    pub fn texel_fetch(&self, texel_coord: Extent2d, face: usize, level: usize) -> [f32; 4] {
+        let raw = crate::sampler::call_native(|| unsafe { bindings::fsampler_cube_texel_fetch(&self.ffi, &texel_coord.into(), face, level) });
+        raw.content
+    }
+
+    /// Sample the sampler texture at a specific level.
+    pub fn texel_lod(&self, sample_coord: NormalizeCoord2d, face: usize, level: f32) -> [f32; 4] {
+        let coord: [f32; 2] = sample_coord.into();
+
+        let texture = self.target_texture();
+        filtering::sample_lod(&coord, level, unsafe { texture_bindings::texture_levels(texture) },
+            self.state(),
+            |level| *unsafe { texture_bindings::texture_extent(texture, level) },
+            |texel_coord, level| self.texel_fetch(Extent2d { width: texel_coord[0], height: texel_coord[1] }, face, level))
+    }
+
+    /// Fetch the texels of a face at many texel coordinates from the sampler texture.
+    ///
+    /// `texels` receives one texel for each element of `texel_coords`, and must have the same length.
+    /// The loop runs in the native library, and large batches are split over several threads.
+    /// Panic if one of the texels is outside the texture.
+    pub fn texel_fetch_many(&self, texel_coords: &[Extent2d], face: usize, level: usize, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(texel_coords.len(), texels.len(), "The number of texels must match the number of texel coordinates.");
+
+        batch::for_each_chunk(self, texel_coords, texels, |sampler, texel_coords, texels| {
+            let coords: Vec<[u32; 2]> = texel_coords.iter().map(|&texel_coord| texel_coord.into()).collect();
+            crate::sampler::call_native(|| unsafe { bindings::fsampler_cube_texel_fetch_many(&sampler.ffi, coords.as_ptr(), coords.len(), face, level, texels.as_mut_ptr() as *mut TexelType4F) });
+        });
+    }
+
+    /// Sample the sampler texture of a face at many coordinates at a specific level.
+    ///
+    /// `texels` receives one texel for each element of `sample_coords`, and must have the same length.
+    /// The texels are sampled on the Rust side like `texel_lod`, and large batches are split over several threads.
+    pub fn texel_lod_many(&self, sample_coords: &[NormalizeCoord2d], face: usize, level: f32, texels: &mut [[f32; 4]]) {
+
+        assert_eq!(sample_coords.len(), texels.len(), "The number of texels must match the number of sample coordinates.");
+
+        batch::for_each_chunk(self, sample_coords, texels, |sampler, sample_coords, texels| {
+            for (sample_coord, texel) in sample_coords.iter().zip(texels.iter_mut()) {
+                *texel = sampler.texel_lod(*sample_coord, face, level);
+            }
+        });
+    }
+
+    /// Sample the sampler texture along a direction at a specific level.
+    ///
+    /// The face is selected by the major axis of `direction`, and the texel is filtered within that face only.
+    pub fn sample_dir(&self, direction: [f32; 3], level: f32) -> [f32; 4] {
+        let (face, coord) = cube::select_face(direction);
+        self.texel_lod(coord.into(), face, level)
+    }
+
+    /// Sample the sampler texture along a direction at a specific level, filtering across the face edges.
+    ///
+    /// Texels are always filtered bilinearly within a level and linearly between the two nearest levels,
+    /// taking the texels from the adjacent faces near the edges of a face(seamless cube map filtering).
+    pub fn sample_dir_seamless(&self, direction: [f32; 3], level: f32) -> [f32; 4] {
+
+        let texture = self.target_texture();
+        let levels = unsafe { texture_bindings::texture_levels(texture) };
+
+        cube::sample_seamless(direction, level, levels,
+            |level| unsafe { texture_bindings::texture_extent(texture, level)[0] },
+            |texel_coord, face, level| self.texel_fetch(texel_coord, face, level))
+    }
+
+    /// Return the state used by the sampling done on the Rust side.
+    fn state(&self) -> filtering::SamplerState<'_> {
+        filtering::SamplerState { wraps: &self.wraps, mip: &self.mip, min: &self.min, border_color: self.border_color }
     }
 
+    /// Return the texture which the sampler reads from.
+    fn target_texture(&self) -> &gli::texture {
+        unsafe { &(*bindings::fsampler_cube_target_texture(&self.ffi))._base }
+    }
+}
+
+impl<'a> FSamplerCube<'a, ReadWrite> {
+
     /// Clear the sampler texture with a uniform texel.
     pub fn clear(&mut self, texel: [f32; 4]) {
-        unsafe { bindings::fsampler_cube_clear(&mut self.ffi, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler_cube_clear(&mut self.ffi, texel.into()) });
     }
 
     /// Generate all the mipmaps of the sampler texture from the texture base level.
     pub fn generate_mipmaps(&mut self, minification: Filter) {
-        unsafe { bindings::fsampler_cube_generate_mipmaps1(&mut self.ffi, minification.0); }
+        let texture = self.target_texture();
+        let (base_face, max_face, base_level, max_level) = unsafe {(
+            texture_bindings::texture_base_face(texture), texture_bindings::texture_max_face(texture),
+            texture_bindings::texture_base_level(texture), texture_bindings::texture_max_level(texture),
+        )};
+        self.downsample_levels(base_face..=max_face, base_level, max_level, &minification);
     }
 
     /// Generate the mipmaps of the sampler texture from the texture base level to the texture max level included.
     pub fn generate_mipmaps_detail(&mut self, base_face: usize, max_face: usize, base_level: usize, max_level: usize, minification: Filter) {
-        unsafe { bindings::fsampler_cube_generate_mipmaps2(&mut self.ffi, base_face, max_face, base_level, max_level, minification.0); }
-    }
-
-    /// Fetch a texel from the sampler texture.
-    pub fn texel_fetch(&self, texel_coord: Extent2d, face: usize, level: usize) -> [f32; 4] {
-        let raw = unsafe { bindings::fsampler_cube_texel_fetch(&self.ffi, &texel_coord.into(), face, level) };
-        raw.content
+        self.downsample_levels(base_face..=max_face, base_level, max_level, &minification);
     }
 
     /// Write a texel in the sampler texture.
+    ///
+    /// Panic if the texel is outside the texture.
     pub fn texel_write(&mut self, texel_coord: Extent2d, face: usize, level: usize, texel: [f32; 4]) {
-        unsafe { bindings::fsampler_cube_texel_write(&mut self.ffi, &texel_coord.into(), face, level, texel.into()); }
+        crate::sampler::call_native(|| unsafe { bindings::fsampler_cube_texel_write(&mut self.ffi, &texel_coord.into(), face, level, texel.into()) });
     }
 
-    /// Sample the sampler texture at a specific level.
-    pub fn texel_lod(&self, sample_coord: NormalizeCoord2d, face: usize, level: f32) -> [f32; 4] {
-        let coord: [f32; 2] = sample_coord.into();
-        let raw = unsafe { bindings::fsampler_cube_texel_lod(&self.ffi, &coord, face, level) };
-        raw.content
+    /// Generate the levels after `base_level` up to `max_level` of each face by filtering the previous level with the wrap mode of each axis.
+    fn downsample_levels(&mut self, faces: RangeInclusive<usize>, base_level: usize, max_level: usize, minification: &Filter) {
+
+        for face in faces {
+            for level in base_level..max_level {
+
+                let texture = self.target_texture();
+                let src_extent = *unsafe { texture_bindings::texture_extent(texture, level) };
+                let dst_extent = *unsafe { texture_bindings::texture_extent(texture, level + 1) };
+
+                let texels = filtering::downsample(&src_extent[..2], &dst_extent[..2], &self.wraps, minification, self.border_color,
+                    |texel_coord| self.texel_fetch(Extent2d { width: texel_coord[0], height: texel_coord[1] }, face, level));
+
+                let width = dst_extent[0].max(1);
+                for (i, texel) in texels.into_iter().enumerate() {
+                    let i = i as u32;
+                    self.texel_write(Extent2d { width: i % width, height: i / width }, face, level + 1, texel);
+                }
+            }
+        }
     }
 }
 
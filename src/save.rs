@@ -1,11 +1,11 @@
-
 use std::path::Path;
 use std::ffi::OsStr;
+use std::os::raw::c_char;
 
 use crate::ffi::root::bindings;
+use crate::format::TexFormatType;
 use crate::texture::GliTexture;
-use crate::error::{Result, Error};
-use std::ffi::CString;
+use crate::error::{Result, Error, catch_ffi};
 
 // TODO: Some other save methods are not yet implemented.
 
@@ -18,15 +18,17 @@ use std::ffi::CString;
 /// The function use the filename extension included in the path to figure out the file container to use.
 pub fn save(texture: &impl GliTexture, path: impl AsRef<Path>) -> Result<()> {
 
-    if let Some(dst_extension) = path.as_ref().extension().and_then(OsStr::to_str) {
+    let path = path.as_ref();
+
+    if let Some(dst_extension) = path.extension().and_then(OsStr::to_str) {
         match dst_extension {
-            | "dds" => save_dds(texture, path),
-            | "ktx" => save_ktx(texture, path),
-            | "kmg" => save_kmg(texture, path),
-            | _ => Err(Error::save_texture(format!("Saving {} format is not support.", dst_extension)))
+            | "dds" => save_file(texture, path, TexFormatType::DDS),
+            | "ktx" => save_file(texture, path, TexFormatType::KTX),
+            | "kmg" => save_file(texture, path, TexFormatType::KMG),
+            | _ => Err(Error::save_texture(format!("Saving {} format is not support.", dst_extension)).with_path(path))
         }
     } else {
-        Err(Error::save_texture("Invalid path to save texture."))
+        Err(Error::save_texture("Invalid path to save texture.").with_path(path))
     }
 }
 
@@ -38,16 +40,7 @@ pub fn save(texture: &impl GliTexture, path: impl AsRef<Path>) -> Result<()> {
 ///
 /// This function ignores the filename extension in the path and save to DDS anyway but keep the requested filename extension.
 pub fn save_dds(texture: &impl GliTexture, path: impl AsRef<Path>) -> Result<()> {
-
-    let dst_path = path.as_ref().to_str()
-        .and_then(|p| CString::new(p).ok())
-        .ok_or(Error::save_texture("Invalid file path."))?;
-
-    if unsafe { bindings::Save::save_save_dds(texture.raw_texture(), dst_path.as_ptr()) } {
-        Ok(())
-    } else {
-        Err(Error::save_texture("Failed to save dds texture."))
-    }
+    save_file(texture, path.as_ref(), TexFormatType::DDS)
 }
 
 /// Save a texture storage_linear to a KTX file.
@@ -58,16 +51,7 @@ pub fn save_dds(texture: &impl GliTexture, path: impl AsRef<Path>) -> Result<()>
 ///
 /// This function ignores the filename extension in the path and save to KTX anyway but keep the requested filename extension.
 pub fn save_ktx(texture: &impl GliTexture, path: impl AsRef<Path>) -> Result<()> {
-
-    let dst_path = path.as_ref().to_str()
-        .and_then(|p| CString::new(p).ok())
-        .ok_or(Error::save_texture("Invalid file path."))?;
-
-    if unsafe { bindings::Save::save_save_ktx(texture.raw_texture(), dst_path.as_ptr()) } {
-        Ok(())
-    } else {
-        Err(Error::save_texture("Failed to save ktx texture."))
-    }
+    save_file(texture, path.as_ref(), TexFormatType::KTX)
 }
 
 /// Save a texture storage_linear to a KMG (Khronos Image) file.
@@ -78,14 +62,53 @@ pub fn save_ktx(texture: &impl GliTexture, path: impl AsRef<Path>) -> Result<()>
 ///
 /// This function ignores the filename extension in the path and save to KMG anyway but keep the requested filename extension.
 pub fn save_kmg(texture: &impl GliTexture, path: impl AsRef<Path>) -> Result<()> {
+    save_file(texture, path.as_ref(), TexFormatType::KMG)
+}
+
+/// Save a texture storage_linear to the content of a texture file in memory.
+///
+/// `format` is the container of the file, see `load_from_memory` to load it back.
+pub fn save_to_memory(texture: &impl GliTexture, format: TexFormatType) -> Result<Vec<u8>> {
+
+    let (mut data, mut size): (*mut c_char, usize) = (std::ptr::null_mut(), 0);
+
+    let saved = catch_ffi(|| unsafe {
+        match format {
+            | TexFormatType::DDS => bindings::Save::save_dds_memory(texture.raw_texture(), &mut data, &mut size),
+            | TexFormatType::KTX => bindings::Save::save_ktx_memory(texture.raw_texture(), &mut data, &mut size),
+            | TexFormatType::KMG => bindings::Save::save_kmg_memory(texture.raw_texture(), &mut data, &mut size),
+        }
+    });
 
-    let dst_path = path.as_ref().to_str()
-        .and_then(|p| CString::new(p).ok())
-        .ok_or(Error::save_texture("Invalid file path."))?;
+    let bytes = if data.is_null() {
+        Vec::new()
+    } else {
+        let bytes = unsafe { std::slice::from_raw_parts(data as *const u8, size) }.to_vec();
+        unsafe { bindings::Save::save_free_memory(data); }
+        bytes
+    };
 
-    if unsafe { bindings::Save::save_save_kmg(texture.raw_texture(), dst_path.as_ptr()) } {
-        Ok(())
+    if saved? {
+        Ok(bytes)
     } else {
-        Err(Error::save_texture("Failed to save kmg texture."))
+        Err(Error::save_texture(format!("Failed to save {} texture.", container_name(&format))))
+    }
+}
+
+/// Write the file at `path` on the Rust side, so any path supported by the platform can be saved.
+///
+/// Errors are of kind `ErrorKind::Path`, caused by the save or the I/O error.
+fn save_file(texture: &impl GliTexture, path: &Path, format: TexFormatType) -> Result<()> {
+
+    save_to_memory(texture, format)
+        .and_then(|bytes| std::fs::write(path, bytes).map_err(Error::from))
+        .map_err(|error| error.with_path(path))
+}
+
+fn container_name(format: &TexFormatType) -> &'static str {
+    match format {
+        | TexFormatType::DDS => "dds",
+        | TexFormatType::KTX => "ktx",
+        | TexFormatType::KMG => "kmg",
     }
 }
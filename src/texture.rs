@@ -6,6 +6,17 @@ pub use self::t1d_array::Texture1DArray;
 pub use self::t2d_array::Texture2DArray;
 pub use self::tcube::TextureCube;
 pub use self::tcube_array::TextureCubeArray;
+pub use self::any::AnyTexture;
+pub use self::builder::TextureBuilder;
+pub use self::cube_layout::CubeLayout;
+pub use self::unique::Unique;
+pub use self::resize::{ResizeOptions, PowerOfTwo};
+
+pub(crate) use self::validate::{check_view, check_view_format, check_image, check_index, check_storage, mipmap_levels};
+#[cfg(not(target_os = "windows"))]
+pub use self::compare::{compare, ComparisonReport, SubresourceComparison, ChannelMetrics};
+#[cfg(not(target_os = "windows"))]
+pub use self::ibl::SphericalHarmonics;
 
 mod t1d;
 mod t2d;
@@ -14,6 +25,19 @@ mod t1d_array;
 mod t2d_array;
 mod tcube;
 mod tcube_array;
+mod any;
+mod builder;
+mod cube_layout;
+mod unique;
+mod validate;
+mod texel;
+mod swizzle;
+mod transform;
+mod resize;
+#[cfg(not(target_os = "windows"))]
+mod compare;
+#[cfg(not(target_os = "windows"))]
+mod ibl;
 
 use std::os::raw::c_void;
 
@@ -21,6 +45,7 @@ use crate::ffi::root::glm;
 use crate::ffi::root::bindings::Texture as bindings;
 use crate::format::{Format, Swizzles};
 use crate::target::Target;
+use crate::sampler::Filter;
 use crate::Extent3d;
 
 #[cfg(not(feature = "rc_debug"))]
@@ -47,7 +72,7 @@ pub mod inner {
 
 pub trait GliTexture: inner::TextureAccessible + Sized + PartialEq + Eq {
     const TARGET_TYPE: Target;
-    type ExtentType: From<[u32; 3]>;
+    type ExtentType: From<[u32; 3]> + Into<[u32; 3]>;
 
     /// Return the corresponding extent type of the texture instance,
     /// which represents the size of a specific mip-level of this texture(width, height and depth).
@@ -80,30 +105,62 @@ pub trait GliTexture: inner::TextureAccessible + Sized + PartialEq + Eq {
     }
 
     /// Clear the entire texture storage_linear with zeros.
+    ///
+    /// If the storage is shared, the texture first gets its own copy, see `make_unique`.
     fn clear(&mut self) {
+        self.make_unique();
         unsafe { bindings::texture_clear(self.raw_texture_mut()) }
     }
 
     // TODO: Other 3 clear methods is missing.
 
     /// Copy a specific image of a texture.
-    fn copy(&mut self, src_texture: &Self, src_layer: usize, src_face: usize, src_level: usize, dst_layer: usize, dst_face: usize, dst_level: usize) {
-        unsafe {
+    ///
+    /// The images must exist in both textures, have the same extent, and their formats must have the same blocks.
+    /// If the storage is shared, the texture first gets its own copy, see `make_unique`.
+    fn copy(&mut self, src_texture: &Self, src_layer: usize, src_face: usize, src_level: usize, dst_layer: usize, dst_face: usize, dst_level: usize) -> crate::Result<()> {
+
+        validate::check_image(src_texture, src_layer, src_face, src_level)?;
+        validate::check_image(self, dst_layer, dst_face, dst_level)?;
+        validate::check_formats(&src_texture.format(), &self.format())?;
+
+        let src_extent = *unsafe { bindings::texture_extent(src_texture.raw_texture(), src_level) };
+        let dst_extent = *unsafe { bindings::texture_extent(self.raw_texture(), dst_level) };
+        if src_extent != dst_extent {
+            return Err(crate::Error::invalid_argument(format!("The source image of {:?} texels differs from the destination image of {:?} texels.", src_extent, dst_extent)))
+        }
+
+        self.make_unique();
+        crate::error::catch_ffi(|| unsafe {
             bindings::texture_copy(
                 self.raw_texture_mut(), src_texture.raw_texture(), src_layer, src_face, src_level, dst_layer, dst_face, dst_level)
-        }
+        })
     }
 
     /// Copy a subset of a specific image of a texture.
-    fn copy_subset(&mut self, src_texture: &Self, src_layer: usize, src_face: usize, src_level: usize, src_offset: Extent3d, dst_layer: usize, dst_face: usize, dst_level: usize, dst_offset: Extent3d, extent: Extent3d) {
+    ///
+    /// The images must exist in both textures, the regions must lie in their images and be aligned to the blocks of the format,
+    /// and the formats must have the same blocks. If the storage is shared, the texture first gets its own copy, see `make_unique`.
+    fn copy_subset(&mut self, src_texture: &Self, src_layer: usize, src_face: usize, src_level: usize, src_offset: Extent3d, dst_layer: usize, dst_face: usize, dst_level: usize, dst_offset: Extent3d, extent: Extent3d) -> crate::Result<()> {
+
+        validate::check_image(src_texture, src_layer, src_face, src_level)?;
+        validate::check_image(self, dst_layer, dst_face, dst_level)?;
+        validate::check_formats(&src_texture.format(), &self.format())?;
+
+        let src_extent = *unsafe { bindings::texture_extent(src_texture.raw_texture(), src_level) };
+        let dst_extent = *unsafe { bindings::texture_extent(self.raw_texture(), dst_level) };
+        validate::check_region("source", src_offset, extent, src_extent, &src_texture.format())?;
+        validate::check_region("destination", dst_offset, extent, dst_extent, &self.format())?;
+
+        self.make_unique();
 
         let src_offset = glm::ivec3(src_offset.into());
         let dst_offset = glm::ivec3(dst_offset.into());
         let extent = glm::ivec3(extent.into());
-        unsafe {
+        crate::error::catch_ffi(|| unsafe {
             bindings::texture_copy_subset(
                 self.raw_texture_mut(), src_texture.raw_texture(), src_layer, src_face, src_level, &src_offset, dst_layer, dst_face, dst_level, &dst_offset, &extent)
-        }
+        })
     }
 
     /// Return a pointer to the beginning of the texture instance data.
@@ -111,11 +168,41 @@ pub trait GliTexture: inner::TextureAccessible + Sized + PartialEq + Eq {
         unsafe { bindings::texture_data(self.raw_texture()) }
     }
 
+    /// Return a mutable pointer to the beginning of the texture instance data.
+    ///
+    /// If the storage is shared, the texture first gets its own copy, see `make_unique`.
+    ///
+    /// # Safety
+    ///
+    /// The pointer is valid for `size()` bytes, until the texture is dropped or its storage is replaced.
+    /// Since this call may replace the storage, the pointers obtained earlier from `data` or `data_detail`
+    /// may point to the previous storage, which is no longer the storage of this texture, or has been freed.
     unsafe fn data_mut(&mut self) -> *mut c_void {
+        self.make_unique();
         bindings::texture_data_mut(self.raw_texture_mut())
     }
 
-    // TODO: Other 6 data methods is missing.
+    /// Return a pointer to the beginning of the data of a specific layer, face and level of the texture instance.
+    fn data_detail(&self, layer: usize, face: usize, level: usize) -> *const c_void {
+        unsafe { bindings::texture_data_detail(self.raw_texture(), layer, face, level) }
+    }
+
+    /// Return a mutable pointer to the beginning of the data of a specific layer, face and level of the texture instance.
+    ///
+    /// If the storage is shared, the texture first gets its own copy, see `make_unique`.
+    ///
+    /// # Safety
+    ///
+    /// The layer, face and level must exist, and the pointer is valid for `size_at_level(level)` bytes, until the
+    /// texture is dropped or its storage is replaced. Since this call may replace the storage, the pointers obtained
+    /// earlier from `data` or `data_detail` may point to the previous storage, which is no longer the storage of this
+    /// texture, or has been freed.
+    unsafe fn data_detail_mut(&mut self, layer: usize, face: usize, level: usize) -> *mut c_void {
+        self.make_unique();
+        bindings::texture_data_detail_mut(self.raw_texture_mut(), layer, face, level)
+    }
+
+    // TODO: Other 4 data methods is missing.
 
     /// Return whether the texture instance is empty, no storage_type or description have been assigned to the instance.
     fn empty(&self) -> bool {
@@ -180,12 +267,148 @@ pub trait GliTexture: inner::TextureAccessible + Sized + PartialEq + Eq {
 
     // TODO: store(..) methods is missing, due to template specialization.
 
-    // TODO: swizzle(&self) methods is missing, due to template specialization.
+    /// Return the swizzles of the texture instance, the source of the red, green, blue and alpha channels.
+    fn swizzles(&self) -> Swizzles {
+        *unsafe { bindings::texture_swizzles(self.raw_texture()) }
+    }
+
+    /// Rewrite the texels so that the swizzles of the texture become identity, like uploading it with them would.
+    ///
+    /// Use it before saving to a container or uploading to an API which ignores the swizzles. `Swizzle::ZERO` and
+    /// `Swizzle::ONE` write the zero and one of each channel. The channels not stored by the format can still be
+    /// selected, and read as a sampler reads them. Return an error if the format is compressed, or if the swizzle
+    /// of a channel not stored by the format changes what it reads, like `[R, R, R, ONE]` on `R8_UNORM_PACK8`,
+    /// which needs a format with more channels.
+    /// If the storage is shared, the texture first gets its own copy, see `make_unique`.
+    fn bake_swizzles(&mut self) -> crate::Result<()> {
+        swizzle::bake_swizzles(self)
+    }
+
+    /// Decode the texels of an sRGB texture to linear, and change its format to the `UNORM` variant, see `Format::to_linear`.
+    ///
+    /// Every layer, face and level is converted. The alpha channel is not changed.
+    /// Return an error if the format is compressed or is not an sRGB format.
+    fn convert_srgb_to_linear(&mut self) -> crate::Result<()> {
+        transform::convert_srgb_to_linear(self)
+    }
+
+    /// Encode the texels of a linear texture to sRGB, and change its format to the sRGB variant, see `Format::to_srgb`.
+    ///
+    /// Every layer, face and level is converted. The alpha channel is not changed.
+    /// Return an error if the format is compressed or has no sRGB variant.
+    fn convert_linear_to_srgb(&mut self) -> crate::Result<()> {
+        transform::convert_linear_to_srgb(self)
+    }
+
+    /// Multiply the red, green and blue channels of every texel by its alpha channel.
+    ///
+    /// The channels of sRGB formats are multiplied in linear space. A format without alpha channel is left unchanged.
+    /// Return an error if the format is compressed or stores integers.
+    fn premultiply_alpha(&mut self) -> crate::Result<()> {
+        transform::premultiply_alpha(self)
+    }
+
+    /// Divide the red, green and blue channels of every texel by its alpha channel, the inverse of `premultiply_alpha`.
+    ///
+    /// The texels whose alpha is zero are left unchanged. Return an error if the format is compressed or stores integers.
+    fn unpremultiply_alpha(&mut self) -> crate::Result<()> {
+        transform::unpremultiply_alpha(self)
+    }
+
+    /// Raise the red, green and blue channels of every texel to the power `gamma`, as they are stored.
+    ///
+    /// Return an error if `gamma` is not a positive number, or if the format is compressed or stores integers.
+    fn adjust_gamma(&mut self, gamma: f32) -> crate::Result<()> {
+        transform::adjust_gamma(self, gamma)
+    }
+
+    /// Resize every layer and face into a new texture of `extent` texels in the same format, filtering with `filter`.
+    ///
+    /// When enlarged, the texels are filtered like the samplers filter them. When shrunk, the filter is stretched
+    /// over all the texels covered by each resized texel, so that no texel is skipped. The edges are clamped.
+    /// The resized texture keeps the number of levels, up to a complete mipmap chain, and its mipmaps are generated
+    /// from its base level.
+    /// See `resize_with` for the other options. Return an error if the format is compressed or the extent is not supported.
+    fn resize(&self, extent: Self::ExtentType, filter: Filter) -> crate::Result<Self> {
+        resize::resize(self, extent.into(), &ResizeOptions::new(filter))
+    }
+
+    /// Resize every layer and face into a new texture of `extent` texels in the same format, as set by `options`.
+    ///
+    /// The axes are scaled independently. The faces of a cube map are resized separately, with the wrap modes of `options`.
+    fn resize_with(&self, extent: Self::ExtentType, options: &ResizeOptions) -> crate::Result<Self> {
+        resize::resize(self, extent.into(), options)
+    }
 
     /// Return the target of a texture instance.
     fn target(&self) -> Target {
         Self::TARGET_TYPE
     }
+
+    /// Create a view of the texture, reinterpreting its texels with another format.
+    ///
+    /// The view shares the storage and references the same layers, faces and levels. Return an error if
+    /// `format` is not view compatible with the format of the texture, see `Format::is_view_compatible`.
+    fn view_as(&self, format: Format) -> crate::Result<Self> {
+
+        if self.empty() {
+            return Err(crate::Error::invalid_argument("Can not create a view of an empty texture."))
+        }
+        validate::check_view_format(&self.format(), &format)?;
+
+        crate::error::catch_ffi(|| unsafe { bindings::texture_view_as(self.raw_texture(), format.0) }).map(Self::from)
+    }
+
+    /// Return the number of textures, images and samplers sharing the storage of this texture, itself included.
+    ///
+    /// Views created by the `share_from*` methods and `get_level` share the storage of their source. Return 0 for an empty texture.
+    fn shared_count(&self) -> usize {
+        unsafe { bindings::get_texture_shared_storage_count(self.raw_texture()) as usize }
+    }
+
+    /// Return whether the storage of this texture is shared with another texture, image or sampler.
+    fn is_shared(&self) -> bool {
+        self.shared_count() > 1
+    }
+
+    /// Give the texture its own storage if it shares it, by copying its layers, faces and levels.
+    ///
+    /// A view becomes a standalone texture holding the subset it referenced, and the other textures sharing
+    /// the storage are not affected by later writes. `clear`, `copy`, `data_mut` and the other mutating methods
+    /// call it before writing, and so do the samplers created from a mutable reference, which are the only ones able to write.
+    fn make_unique(&mut self) {
+        if self.is_shared() {
+            *self = duplicate(self);
+        }
+    }
+
+    /// Convert a layer, face and level of the texture to an image of the `image` crate.
+    ///
+    /// See `GliImage::to_dynamic_image` for the supported formats.
+    #[cfg(feature = "image")]
+    fn to_dynamic_image(&self, layer: usize, face: usize, level: usize) -> crate::Result<::image::DynamicImage> {
+        crate::image::texture_level_to_dynamic_image(self, layer, face, level)
+    }
+
+    /// Return whether the texels of two textures of the same shape differ by at most `tolerance` in every channel.
+    ///
+    /// The texels are decoded to float, so the textures may be stored in different formats.
+    /// Textures of different shapes, or in compressed formats `compare` can not decode, are never approximately equal.
+    /// See `compare` for the detailed differences.
+    #[cfg(not(target_os = "windows"))]
+    fn approx_eq(&self, other: &Self, tolerance: f32) -> bool {
+        compare(self, other).map(|report| report.max_abs_error() <= tolerance).unwrap_or(false)
+    }
+}
+
+/// Copy the layers, faces and levels of a texture into a new storage.
+///
+/// Panic if the new storage can not be allocated, like the standard collections do.
+pub(crate) fn duplicate<T: GliTexture>(texture: &T) -> T {
+    match crate::error::catch_ffi(|| unsafe { bindings::texture_duplicate(texture.raw_texture()) }) {
+        | Ok(raw_texture) => T::from(raw_texture),
+        | Err(error) => panic!("Failed to copy the storage of the texture: {}", error),
+    }
 }
 
 impl Drop for crate::ffi::root::gli::texture {
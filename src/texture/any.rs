@@ -0,0 +1,76 @@
+
+use crate::ffi::root::gli;
+
+use crate::target::Target;
+use crate::texture::GliTexture;
+use crate::texture::{Texture1D, Texture1DArray, Texture2D, Texture2DArray, Texture3D, TextureCube, TextureCubeArray};
+
+/// A texture whose target is only known at runtime, such as a texture loaded from a file by `load_any`.
+#[derive(Clone)]
+pub enum AnyTexture {
+    Texture1D(Texture1D),
+    Texture1DArray(Texture1DArray),
+    Texture2D(Texture2D),
+    Texture2DArray(Texture2DArray),
+    Texture3D(Texture3D),
+    TextureCube(TextureCube),
+    TextureCubeArray(TextureCubeArray),
+}
+
+/// Evaluate an expression with the concrete texture held by an `AnyTexture`.
+///
+/// `with_texture!(any, texture => body)` binds `texture` to the texture of each variant in turn, so `body` is
+/// compiled once per texture type. `any` may be an `AnyTexture`, a reference or a mutable reference to it.
+#[macro_export]
+macro_rules! with_texture {
+    ($any:expr, $texture:ident => $body:expr) => {
+        match $any {
+            | $crate::AnyTexture::Texture1D($texture)        => $body,
+            | $crate::AnyTexture::Texture1DArray($texture)   => $body,
+            | $crate::AnyTexture::Texture2D($texture)        => $body,
+            | $crate::AnyTexture::Texture2DArray($texture)   => $body,
+            | $crate::AnyTexture::Texture3D($texture)        => $body,
+            | $crate::AnyTexture::TextureCube($texture)      => $body,
+            | $crate::AnyTexture::TextureCubeArray($texture) => $body,
+        }
+    };
+}
+
+impl AnyTexture {
+
+    /// Return the target of the texture.
+    pub fn target(&self) -> Target {
+        with_texture!(self, texture => texture.target())
+    }
+
+    /// Return whether the texture is empty.
+    pub fn empty(&self) -> bool {
+        with_texture!(self, texture => texture.empty())
+    }
+
+    /// Give the texture its own storage if it shares it, see `GliTexture::make_unique`.
+    pub fn make_unique(&mut self) {
+        with_texture!(self, texture => texture.make_unique())
+    }
+
+    /// Wrap a raw texture in the texture type matching its target.
+    ///
+    /// Rectangle textures are wrapped as 2d textures. Return `None` for an unknown target.
+    pub(crate) fn from_raw(raw: gli::texture) -> Option<AnyTexture> {
+
+        let texture = match Target(raw.Target) {
+            | Target::TARGET_1D         => AnyTexture::Texture1D(Texture1D::from(raw)),
+            | Target::TARGET_1D_ARRAY   => AnyTexture::Texture1DArray(Texture1DArray::from(raw)),
+            | Target::TARGET_2D
+            | Target::TARGET_RECT       => AnyTexture::Texture2D(Texture2D::from(raw)),
+            | Target::TARGET_2D_ARRAY
+            | Target::TARGET_RECT_ARRAY => AnyTexture::Texture2DArray(Texture2DArray::from(raw)),
+            | Target::TARGET_3D         => AnyTexture::Texture3D(Texture3D::from(raw)),
+            | Target::TARGET_CUBE       => AnyTexture::TextureCube(TextureCube::from(raw)),
+            | Target::TARGET_CUBE_ARRAY => AnyTexture::TextureCubeArray(TextureCubeArray::from(raw)),
+            | _ => return None,
+        };
+
+        Some(texture)
+    }
+}
@@ -0,0 +1,359 @@
+
+use crate::format::Format;
+use crate::image::GliImage;
+use crate::texture::{GliTexture, Texture2D, Texture2DArray, TextureCube, TextureCubeArray};
+use crate::extent::Extent2d;
+use crate::error::{Result, Error};
+
+#[cfg(not(target_os = "windows"))]
+use crate::sampler::Filter;
+
+use std::os::raw::c_void;
+use std::slice;
+
+/// Assemble a `Texture2DArray`, a `TextureCube` or a `TextureCubeArray` from individual 2d textures and images.
+///
+/// Each source fills one or several levels of a layer and a face. All the sources must share the same format,
+/// and the extent of each level must match the extent implied by the base level.
+///
+/// ```ignore
+/// let cube: TextureCube = TextureBuilder::new()
+///     .texture(0, 0, &positive_x)
+///     .texture(0, 1, &negative_x)
+///     // ...the other four faces
+///     .generate_mipmaps(Filter::LINEAR)
+///     .build_cube()?;
+/// ```
+pub struct TextureBuilder<'a> {
+    sources: Vec<Source<'a>>,
+    levels: Option<usize>,
+    #[cfg(not(target_os = "windows"))]
+    mipmap_filter: Option<Filter>,
+}
+
+struct Source<'a> {
+    layer: usize,
+    face: usize,
+    data: SourceData<'a>,
+}
+
+enum SourceData<'a> {
+    /// All the levels of a 2d texture, starting at level 0.
+    Texture(&'a Texture2D),
+    /// A single level.
+    Image(usize, &'a GliImage),
+}
+
+/// The shape of the texture to build, deduced from the sources.
+struct Layout {
+    format: Format,
+    extent: Extent2d,
+    layers: usize,
+    levels: usize,
+    /// Whether a level is provided, indexed by `(layer * faces + face) * levels + level`.
+    provided: Vec<bool>,
+}
+
+/// A level of a layer and face to copy into the built texture.
+struct Level {
+    layer: usize,
+    face: usize,
+    level: usize,
+    format: Format,
+    extent: Extent2d,
+    data: *const c_void,
+    size: usize,
+}
+
+impl<'a> TextureBuilder<'a> {
+
+    /// Create a builder without any source.
+    pub fn new() -> TextureBuilder<'a> {
+        TextureBuilder {
+            sources: Vec::new(),
+            levels: None,
+            #[cfg(not(target_os = "windows"))]
+            mipmap_filter: None,
+        }
+    }
+
+    /// Fill the levels of the `layer` and `face` with all the levels of `texture`.
+    pub fn texture(mut self, layer: usize, face: usize, texture: &'a Texture2D) -> TextureBuilder<'a> {
+        self.sources.push(Source { layer, face, data: SourceData::Texture(texture) });
+        self
+    }
+
+    /// Fill a single `level` of the `layer` and `face` with `image`.
+    pub fn image(mut self, layer: usize, face: usize, level: usize, image: &'a GliImage) -> TextureBuilder<'a> {
+        self.sources.push(Source { layer, face, data: SourceData::Image(level, image) });
+        self
+    }
+
+    /// Set the number of levels of the built texture.
+    ///
+    /// By default, the built texture has a complete mipmap chain if the mipmaps are generated,
+    /// and the number of levels of the sources otherwise.
+    pub fn levels(mut self, levels: usize) -> TextureBuilder<'a> {
+        self.levels = Some(levels);
+        self
+    }
+
+    /// Generate the levels missing from the sources with the `minification` filter.
+    ///
+    /// Each missing level is filtered from the level above it, which requires an uncompressed format.
+    #[cfg(not(target_os = "windows"))]
+    pub fn generate_mipmaps(mut self, minification: Filter) -> TextureBuilder<'a> {
+        self.mipmap_filter = Some(minification);
+        self
+    }
+
+    /// Build a 2d array texture. All the sources must be in face 0.
+    pub fn build_2d_array(&self) -> Result<Texture2DArray> {
+
+        let layout = self.layout(1)?;
+        let mut texture = Texture2DArray::new(layout.format.clone(), layout.extent, layout.layers, layout.levels)?;
+        self.fill(&mut texture, &layout)?;
+        Ok(texture)
+    }
+
+    /// Build a cube map texture from the six faces of layer 0.
+    pub fn build_cube(&self) -> Result<TextureCube> {
+
+        let layout = self.layout(6)?;
+        if layout.layers != 1 {
+            return Err(Error::invalid_argument(format!("A cube map has a single layer, but the sources fill {} layers.", layout.layers)))
+        }
+
+        let mut texture = TextureCube::new(layout.format.clone(), layout.extent, layout.levels)?;
+        self.fill(&mut texture, &layout)?;
+        Ok(texture)
+    }
+
+    /// Build a cube map array texture from the six faces of each layer.
+    pub fn build_cube_array(&self) -> Result<TextureCubeArray> {
+
+        let layout = self.layout(6)?;
+        let mut texture = TextureCubeArray::new(layout.format.clone(), layout.extent, layout.layers, layout.levels)?;
+        self.fill(&mut texture, &layout)?;
+        Ok(texture)
+    }
+
+    fn is_generating_mipmaps(&self) -> bool {
+
+        #[cfg(not(target_os = "windows"))]
+        { self.mipmap_filter.is_some() }
+        #[cfg(target_os = "windows")]
+        { false }
+    }
+
+    /// Expand the sources into the levels they provide.
+    fn source_levels(&self) -> Vec<Level> {
+
+        let mut levels = Vec::new();
+
+        for source in self.sources.iter() {
+            match source.data {
+                | SourceData::Texture(texture) => {
+                    for level in 0..texture.levels() {
+                        levels.push(Level {
+                            layer: source.layer,
+                            face: source.face,
+                            level,
+                            format: texture.format(),
+                            extent: texture.extent(level),
+                            data: texture.data_detail(0, 0, level),
+                            size: texture.size_at_level(level),
+                        });
+                    }
+                },
+                | SourceData::Image(level, image) => {
+                    let extent = image.extent();
+                    levels.push(Level {
+                        layer: source.layer,
+                        face: source.face,
+                        level,
+                        format: image.format(),
+                        extent: Extent2d { width: extent.width, height: extent.height },
+                        data: image.data(),
+                        size: image.size(),
+                    });
+                },
+            }
+        }
+
+        levels
+    }
+
+    /// Validate the sources and deduce the shape of the texture to build.
+    fn layout(&self, faces: usize) -> Result<Layout> {
+
+        let sources = self.source_levels();
+
+        let first = sources.first()
+            .ok_or_else(|| Error::invalid_argument("The texture builder has no source."))?;
+        let format = first.format.clone();
+
+        for source in sources.iter() {
+            if source.format != format {
+                return Err(Error::invalid_argument(format!("The format {} of layer {}, face {}, level {} differs from the format {} of the other sources.",
+                    source.format, source.layer, source.face, source.level, format)))
+            }
+            if source.face >= faces {
+                return Err(Error::invalid_argument(format!("The face {} of a source is out of range, the texture has {} faces.", source.face, faces)))
+            }
+        }
+
+        let base = sources.iter().find(|source| source.level == 0)
+            .ok_or_else(|| Error::invalid_argument("No source provides the base level."))?;
+        let extent = base.extent;
+        let chain_levels = mipmap_chain_levels(extent);
+
+        let layers = sources.iter().map(|source| source.layer).max().unwrap_or(0) + 1;
+        let levels = match self.levels {
+            | Some(levels) => levels,
+            | None if self.is_generating_mipmaps() => chain_levels,
+            | None => sources.iter().map(|source| source.level).max().unwrap_or(0) + 1,
+        };
+
+        if levels == 0 || levels > chain_levels {
+            return Err(Error::invalid_argument(format!("A texture of {}x{} texels has between 1 and {} levels, not {}.", extent.width, extent.height, chain_levels, levels)))
+        }
+
+        let mut provided = vec![false; layers * faces * levels];
+
+        for source in sources.iter() {
+
+            if source.level >= levels {
+                continue
+            }
+
+            let expected = level_extent(extent, source.level);
+            if source.extent.width != expected.width || source.extent.height != expected.height {
+                return Err(Error::invalid_argument(format!("The level {} of layer {}, face {} is {}x{} texels, but {}x{} texels are expected from the base level.",
+                    source.level, source.layer, source.face, source.extent.width, source.extent.height, expected.width, expected.height)))
+            }
+
+            let index = (source.layer * faces + source.face) * levels + source.level;
+            if provided[index] {
+                return Err(Error::invalid_argument(format!("The level {} of layer {}, face {} is provided twice.", source.level, source.layer, source.face)))
+            }
+            provided[index] = true;
+        }
+
+        for layer in 0..layers {
+            for face in 0..faces {
+                for level in 0..levels {
+                    if !provided[(layer * faces + face) * levels + level] && (level == 0 || !self.is_generating_mipmaps()) {
+                        return Err(Error::invalid_argument(format!("No source provides the level {} of layer {}, face {}.", level, layer, face)))
+                    }
+                }
+            }
+        }
+
+        if self.is_generating_mipmaps() && format.is_compressed() && provided.iter().any(|provided| !provided) {
+            return Err(Error::unsupported_format(format!("The mipmaps of the compressed format {} can not be generated.", format)))
+        }
+
+        Ok(Layout { format, extent, layers, levels, provided })
+    }
+
+    /// Copy the sources into `texture`, then generate the missing levels.
+    fn fill<T: GliTexture>(&self, texture: &mut T, layout: &Layout) -> Result<()> {
+
+        for source in self.source_levels() {
+
+            if source.level >= layout.levels {
+                continue
+            }
+
+            let dst_size = texture.size_at_level(source.level);
+            if dst_size != source.size {
+                return Err(Error::bug(format!("The level {} of a source is {} bytes, but the texture level is {} bytes.", source.level, source.size, dst_size)))
+            }
+
+            unsafe {
+                let src = slice::from_raw_parts(source.data as *const u8, source.size);
+                let dst = slice::from_raw_parts_mut(texture.data_detail_mut(source.layer, source.face, source.level) as *mut u8, dst_size);
+                dst.copy_from_slice(src);
+            }
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            if let Some(ref filter) = self.mipmap_filter {
+                generate_missing_levels(texture, layout, filter)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Default for TextureBuilder<'a> {
+
+    fn default() -> TextureBuilder<'a> {
+        TextureBuilder::new()
+    }
+}
+
+/// Filter each run of missing levels of every layer and face from the level above the run.
+#[cfg(not(target_os = "windows"))]
+fn generate_missing_levels<T: GliTexture>(texture: &mut T, layout: &Layout, filter: &Filter) -> Result<()> {
+
+    use crate::sampler::{FSampler2D, Wrap};
+
+    let faces = texture.faces();
+
+    for layer in 0..layout.layers {
+        for face in 0..faces {
+
+            let provided = &layout.provided[((layer * faces + face) * layout.levels)..((layer * faces + face + 1) * layout.levels)];
+            if provided.iter().all(|provided| *provided) {
+                continue
+            }
+
+            // Filter the levels of the layer and face in a 2d texture of its own, then copy the generated levels back.
+            let mut image = Texture2D::share_from_detail(texture, layout.format.clone(), layer, layer, face, face, 0, layout.levels - 1)?;
+            let mut sampler = FSampler2D::new(&mut image, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR);
+
+            let mut level = 1;
+            while level < layout.levels {
+                if provided[level] {
+                    level += 1;
+                    continue
+                }
+
+                let mut last_missing = level;
+                while last_missing + 1 < layout.levels && !provided[last_missing + 1] {
+                    last_missing += 1;
+                }
+
+                sampler.generate_mipmaps_detail(level - 1, last_missing, filter.clone());
+                level = last_missing + 1;
+            }
+
+            drop(sampler);
+            for level in (0..layout.levels).filter(|&level| !provided[level]) {
+                unsafe {
+                    let src = slice::from_raw_parts(image.data_detail(0, 0, level) as *const u8, image.size_at_level(level));
+                    let dst = slice::from_raw_parts_mut(texture.data_detail_mut(layer, face, level) as *mut u8, texture.size_at_level(level));
+                    dst.copy_from_slice(src);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn mipmap_chain_levels(extent: Extent2d) -> usize {
+    let max_size = extent.width.max(extent.height).max(1);
+    (32 - max_size.leading_zeros()) as usize
+}
+
+fn level_extent(extent: Extent2d, level: usize) -> Extent2d {
+    Extent2d {
+        width : (extent.width  >> level).max(1),
+        height: (extent.height >> level).max(1),
+    }
+}
@@ -0,0 +1,247 @@
+use crate::ffi::root::bindings::FSampler2D as sampler2d;
+use crate::ffi::root::bindings::FSampler3D as sampler3d;
+use crate::ffi::root::bindings::TexelType4F;
+
+use crate::format::Format;
+use crate::texture::{GliTexture, Texture2D, Texture3D};
+use crate::texture::transform::srgb_to_linear;
+use crate::target::Target;
+use crate::error::{Result, Error, catch_ffi};
+
+/// The side of the square windows over which the SSIM is computed.
+const SSIM_WINDOW: u32 = 8;
+/// The distance between two SSIM windows.
+const SSIM_STRIDE: u32 = 4;
+
+/// The differences between two textures, measured for each layer, face and level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComparisonReport {
+    pub subresources: Vec<SubresourceComparison>,
+}
+
+/// The differences between a layer, face and level of two textures, measured for each channel(R, G, B, A).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubresourceComparison {
+    pub layer: usize,
+    pub face: usize,
+    pub level: usize,
+    pub channels: [ChannelMetrics; 4],
+}
+
+/// The differences between a channel of two images.
+///
+/// The texels are decoded to float, and the metrics assume a peak value of 1.0, which is the range of normalized formats.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelMetrics {
+    /// The largest absolute difference between two texels.
+    pub max_abs_error: f32,
+    /// The root mean square of the differences.
+    pub rmse: f32,
+    /// The peak signal to noise ratio in decibels, infinite if the channels are equal.
+    pub psnr: f32,
+    /// The mean structural similarity over 8x8 windows, 1.0 if the channels are equal.
+    pub ssim: f32,
+}
+
+impl ComparisonReport {
+
+    /// Return the largest absolute difference between two texels of any subresource and channel.
+    pub fn max_abs_error(&self) -> f32 {
+        self.channels().map(|channel| channel.max_abs_error).fold(0.0, f32::max)
+    }
+
+    /// Return the lowest PSNR of any subresource and channel.
+    pub fn min_psnr(&self) -> f32 {
+        self.channels().map(|channel| channel.psnr).fold(f32::INFINITY, f32::min)
+    }
+
+    /// Return the lowest SSIM of any subresource and channel.
+    pub fn min_ssim(&self) -> f32 {
+        self.channels().map(|channel| channel.ssim).fold(1.0, f32::min)
+    }
+
+    fn channels(&self) -> impl Iterator<Item = &ChannelMetrics> {
+        self.subresources.iter().flat_map(|subresource| subresource.channels.iter())
+    }
+}
+
+/// Compare the texels of two textures of the same shape, which may be stored in different formats.
+///
+/// The textures must have the same number of layers, faces and levels, and the same extent.
+/// The texels are decoded to float like the samplers do. Besides the uncompressed formats, the DXT1, DXT3, DXT5,
+/// BC4 and BC5 compressed formats are decoded, and the other compressed formats return an error.
+pub fn compare<T: GliTexture>(a: &T, b: &T) -> Result<ComparisonReport> {
+
+    if a.layers() != b.layers() || a.faces() != b.faces() || a.levels() != b.levels() {
+        return Err(Error::invalid_argument(format!("The textures have {}, {} and {} layers, faces and levels, and {}, {} and {} layers, faces and levels.",
+            a.layers(), a.faces(), a.levels(), b.layers(), b.faces(), b.levels())))
+    }
+
+    let mut subresources = Vec::with_capacity(a.layers() * a.faces() * a.levels());
+
+    for layer in 0..a.layers() {
+        for face in 0..a.faces() {
+            for level in 0..a.levels() {
+
+                let (extent_a, texels_a) = decode(a, layer, face, level)?;
+                let (extent_b, texels_b) = decode(b, layer, face, level)?;
+
+                if extent_a != extent_b {
+                    return Err(Error::invalid_argument(format!("The level {} of the textures is {:?} and {:?} texels.", level, extent_a, extent_b)))
+                }
+
+                let mut channels = [ChannelMetrics { max_abs_error: 0.0, rmse: 0.0, psnr: f32::INFINITY, ssim: 1.0 }; 4];
+                for (channel, metrics) in channels.iter_mut().enumerate() {
+                    *metrics = channel_metrics(&texels_a, &texels_b, channel, extent_a);
+                }
+
+                subresources.push(SubresourceComparison { layer, face, level, channels });
+            }
+        }
+    }
+
+    Ok(ComparisonReport { subresources })
+}
+
+/// Decode the texels of a layer, face and level to float, row by row and slice by slice.
+fn decode<T: GliTexture>(texture: &T, layer: usize, face: usize, level: usize) -> Result<([u32; 3], Vec<[f32; 4]>)> {
+
+    let format = texture.format();
+    if format.is_compressed() && !is_decodable(&format) {
+        return Err(Error::unsupported_format(format!("The texels of {} can not be decoded, only the DXT1, DXT3, DXT5, BC4 and BC5 compressed formats are supported.", format)))
+    }
+
+    let (layer, face, level) = (texture.base_layer() + layer, texture.base_face() + face, texture.base_level() + level);
+
+    let (extent, mut texels) = if texture.target() == Target::TARGET_3D {
+
+        let view = Texture3D::share_from_detail(texture, format.clone(), layer, layer, face, face, level, level)?;
+        let extent = view.extent(0);
+
+        let mut texels = vec![[0.0; 4]; (extent.width * extent.height * extent.depth) as usize];
+        catch_ffi(|| unsafe { sampler3d::texture3d_decode_texels(view.raw_ffi(), 0, texels.as_mut_ptr() as *mut TexelType4F) })?;
+
+        ([extent.width, extent.height, extent.depth], texels)
+    } else {
+
+        let view = Texture2D::share_from_detail(texture, format.clone(), layer, layer, face, face, level, level)?;
+        let extent = view.extent(0);
+
+        let mut texels = vec![[0.0; 4]; (extent.width * extent.height) as usize];
+        catch_ffi(|| unsafe { sampler2d::texture2d_decode_texels(view.raw_ffi(), 0, texels.as_mut_ptr() as *mut TexelType4F) })?;
+
+        ([extent.width, extent.height, 1], texels)
+    };
+
+    // The compressed sRGB texels are decoded without conversion, unlike the uncompressed ones.
+    if format.is_compressed() && format.is_srgb() {
+        for texel in texels.iter_mut() {
+            texel[..3].iter_mut().for_each(|channel| *channel = srgb_to_linear(f64::from(*channel)) as f32);
+        }
+    }
+
+    Ok((extent, texels))
+}
+
+/// Return whether the fetch functions of gli can decode the compressed `format`.
+fn is_decodable(format: &Format) -> bool {
+    [
+        Format::RGB_DXT1_UNORM_BLOCK8, Format::RGB_DXT1_SRGB_BLOCK8,
+        Format::RGBA_DXT1_UNORM_BLOCK8, Format::RGBA_DXT1_SRGB_BLOCK8,
+        Format::RGBA_DXT3_UNORM_BLOCK16, Format::RGBA_DXT3_SRGB_BLOCK16,
+        Format::RGBA_DXT5_UNORM_BLOCK16, Format::RGBA_DXT5_SRGB_BLOCK16,
+        Format::R_ATI1N_UNORM_BLOCK8, Format::R_ATI1N_SNORM_BLOCK8,
+        Format::RG_ATI2N_UNORM_BLOCK16, Format::RG_ATI2N_SNORM_BLOCK16,
+    ].contains(format)
+}
+
+fn channel_metrics(a: &[[f32; 4]], b: &[[f32; 4]], channel: usize, extent: [u32; 3]) -> ChannelMetrics {
+
+    let mut max_abs_error = 0.0_f32;
+    let mut squared_error = 0.0_f64;
+
+    for (texel_a, texel_b) in a.iter().zip(b.iter()) {
+        let difference = texel_a[channel] - texel_b[channel];
+        max_abs_error = max_abs_error.max(difference.abs());
+        squared_error += f64::from(difference) * f64::from(difference);
+    }
+
+    let mse = squared_error / a.len().max(1) as f64;
+    let psnr = if mse > 0.0 { (10.0 * (1.0 / mse).log10()) as f32 } else { f32::INFINITY };
+
+    let slice_size = (extent[0] * extent[1]) as usize;
+    let mut ssim = 0.0;
+    for z in 0..(extent[2] as usize) {
+        let slice = (z * slice_size)..((z + 1) * slice_size);
+        ssim += slice_ssim(&a[slice.clone()], &b[slice], channel, extent[0], extent[1]);
+    }
+
+    ChannelMetrics {
+        max_abs_error,
+        rmse: mse.sqrt() as f32,
+        psnr,
+        ssim: (ssim / f64::from(extent[2].max(1))) as f32,
+    }
+}
+
+/// Return the mean SSIM of a channel over the windows of a slice.
+fn slice_ssim(a: &[[f32; 4]], b: &[[f32; 4]], channel: usize, width: u32, height: u32) -> f64 {
+
+    // The stabilizing constants for a peak value of 1.0.
+    const C1: f64 = 0.01 * 0.01;
+    const C2: f64 = 0.03 * 0.03;
+
+    let window_width = SSIM_WINDOW.min(width);
+    let window_height = SSIM_WINDOW.min(height);
+
+    let mut sum = 0.0;
+    let mut windows = 0;
+
+    for y0 in window_starts(height, window_height) {
+        for x0 in window_starts(width, window_width) {
+
+            let (mut mean_a, mut mean_b) = (0.0, 0.0);
+            let (mut square_a, mut square_b, mut product) = (0.0, 0.0, 0.0);
+
+            for y in y0..(y0 + window_height) {
+                for x in x0..(x0 + window_width) {
+                    let index = (y * width + x) as usize;
+                    let (va, vb) = (f64::from(a[index][channel]), f64::from(b[index][channel]));
+                    mean_a += va;
+                    mean_b += vb;
+                    square_a += va * va;
+                    square_b += vb * vb;
+                    product += va * vb;
+                }
+            }
+
+            let count = f64::from(window_width * window_height);
+            mean_a /= count;
+            mean_b /= count;
+            let variance_a = square_a / count - mean_a * mean_a;
+            let variance_b = square_b / count - mean_b * mean_b;
+            let covariance = product / count - mean_a * mean_b;
+
+            sum += ((2.0 * mean_a * mean_b + C1) * (2.0 * covariance + C2))
+                / ((mean_a * mean_a + mean_b * mean_b + C1) * (variance_a + variance_b + C2));
+            windows += 1;
+        }
+    }
+
+    if windows == 0 { 1.0 } else { sum / f64::from(windows) }
+}
+
+/// Return the first coordinate of each window along an axis, the last window being aligned with the end of the axis.
+fn window_starts(size: u32, window: u32) -> Vec<u32> {
+
+    if size == 0 {
+        return Vec::new()
+    }
+
+    let last = size - window;
+    let mut starts: Vec<u32> = (0..=last).step_by(SSIM_STRIDE as usize).collect();
+    if starts.last() != Some(&last) {
+        starts.push(last);
+    }
+    starts
+}
@@ -0,0 +1,243 @@
+
+use crate::format::Format;
+use crate::texture::{GliTexture, Texture2D, TextureCube};
+use crate::extent::Extent2d;
+use crate::error::{Result, Error};
+
+use std::slice;
+
+/// The arrangement of the six faces of a cube map in a single 2d image.
+///
+/// The faces keep the orientation of the faces of `TextureCube`, except the -Z face of the vertical cross,
+/// which is rotated by 180 degrees so that it joins the -Y face above it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CubeLayout {
+    /// Four faces wide and three faces high:
+    ///
+    /// ```text
+    ///      +Y
+    /// -X   +Z   +X   -Z
+    ///      -Y
+    /// ```
+    HorizontalCross,
+    /// Three faces wide and four faces high:
+    ///
+    /// ```text
+    ///      +Y
+    /// -X   +Z   +X
+    ///      -Y
+    ///      -Z
+    /// ```
+    VerticalCross,
+    /// Six faces wide, from left to right in the face order of `TextureCube::get_face`: +X, -X, +Y, -Y, +Z, -Z.
+    HorizontalStrip,
+    /// Six faces high, from top to bottom in the face order of `TextureCube::get_face`: +X, -X, +Y, -Y, +Z, -Z.
+    VerticalStrip,
+}
+
+impl CubeLayout {
+
+    /// Return the size of the layout in faces, as columns and rows.
+    fn grid(&self) -> (u32, u32) {
+        match self {
+            | CubeLayout::HorizontalCross => (4, 3),
+            | CubeLayout::VerticalCross   => (3, 4),
+            | CubeLayout::HorizontalStrip => (6, 1),
+            | CubeLayout::VerticalStrip   => (1, 6),
+        }
+    }
+
+    /// Return the column and the row of a face, and whether it is rotated by 180 degrees.
+    fn placement(&self, face: usize) -> (u32, u32, bool) {
+        match self {
+            | CubeLayout::HorizontalCross => [(2, 1, false), (0, 1, false), (1, 0, false), (1, 2, false), (1, 1, false), (3, 1, false)][face],
+            | CubeLayout::VerticalCross   => [(2, 1, false), (0, 1, false), (1, 0, false), (1, 2, false), (1, 1, false), (1, 3, true)][face],
+            | CubeLayout::HorizontalStrip => (face as u32, 0, false),
+            | CubeLayout::VerticalStrip   => (0, face as u32, false),
+        }
+    }
+}
+
+impl TextureCube {
+
+    /// Create a cube map with a single level from an image holding the six faces in `layout`.
+    ///
+    /// Only the base level of `image` is used. The format must be uncompressed.
+    pub fn from_layout(image: &Texture2D, layout: CubeLayout) -> Result<TextureCube> {
+
+        let format = image.format();
+        let texel_size = uncompressed_texel_size(&format)?;
+
+        let extent = image.extent(0);
+        let (columns, rows) = layout.grid();
+        let face_size = extent.width / columns;
+
+        if face_size == 0 || extent.width != face_size * columns || extent.height != face_size * rows {
+            return Err(Error::invalid_argument(format!("A {:?} of square faces is {}x{} faces, which does not fit an image of {}x{} texels.",
+                layout, columns, rows, extent.width, extent.height)))
+        }
+
+        let mut cube = TextureCube::new(format, Extent2d { width: face_size, height: face_size }, 1)?;
+        let src = unsafe { slice::from_raw_parts(image.data_detail(0, 0, 0) as *const u8, image.size_at_level(0)) };
+
+        for face in 0..6 {
+            let (column, row, rotated) = layout.placement(face);
+            let dst = unsafe { slice::from_raw_parts_mut(cube.data_detail_mut(0, face, 0) as *mut u8, cube.size_at_level(0)) };
+
+            for y in 0..face_size {
+                for x in 0..face_size {
+                    let (fx, fy) = if rotated { (face_size - 1 - x, face_size - 1 - y) } else { (x, y) };
+                    let src_offset = (((row * face_size + y) * extent.width + column * face_size + x) as usize) * texel_size;
+                    let dst_offset = ((fy * face_size + fx) as usize) * texel_size;
+                    dst[dst_offset..(dst_offset + texel_size)].copy_from_slice(&src[src_offset..(src_offset + texel_size)]);
+                }
+            }
+        }
+
+        Ok(cube)
+    }
+
+    /// Arrange the six faces of a level of the cube map in a single image with a single level.
+    ///
+    /// The texels of the cross layouts which are not covered by a face are set to zero. The format must be uncompressed.
+    pub fn to_layout(&self, layout: CubeLayout, level: usize) -> Result<Texture2D> {
+
+        let format = self.format();
+        let texel_size = uncompressed_texel_size(&format)?;
+        if level >= self.levels() {
+            return Err(Error::invalid_argument(format!("The cube map has {} levels, the level {} does not exist.", self.levels(), level)))
+        }
+
+        let face_size = self.extent(level).width;
+        let (columns, rows) = layout.grid();
+        let width = face_size * columns;
+
+        let mut image = Texture2D::new(format, Extent2d { width, height: face_size * rows }, 1)?;
+        image.clear();
+        let dst = unsafe { slice::from_raw_parts_mut(image.data_detail_mut(0, 0, 0) as *mut u8, image.size_at_level(0)) };
+
+        for face in 0..6 {
+            let (column, row, rotated) = layout.placement(face);
+            let src = unsafe { slice::from_raw_parts(self.data_detail(0, face, level) as *const u8, self.size_at_level(level)) };
+
+            for y in 0..face_size {
+                for x in 0..face_size {
+                    let (fx, fy) = if rotated { (face_size - 1 - x, face_size - 1 - y) } else { (x, y) };
+                    let src_offset = ((fy * face_size + fx) as usize) * texel_size;
+                    let dst_offset = (((row * face_size + y) * width + column * face_size + x) as usize) * texel_size;
+                    dst[dst_offset..(dst_offset + texel_size)].copy_from_slice(&src[src_offset..(src_offset + texel_size)]);
+                }
+            }
+        }
+
+        Ok(image)
+    }
+
+    /// Create a cube map with a single level of `face_size` texels by resampling an equirectangular panorama.
+    ///
+    /// The horizontal axis of the panorama is the longitude, with -Z at its center and +X at three quarters of its width.
+    /// The vertical axis is the latitude, from +Y at the top to -Y at the bottom.
+    /// The panorama is sampled bilinearly from its base level, repeating horizontally and clamping vertically.
+    #[cfg(not(target_os = "windows"))]
+    pub fn from_equirectangular(image: &Texture2D, face_size: u32) -> Result<TextureCube> {
+
+        use crate::sampler::{FSampler2D, FSamplerCube, Wrap, Filter};
+        use crate::sampler::cube::face_direction;
+        use crate::extent::NormalizeCoord2d;
+
+        let format = image.format();
+        uncompressed_texel_size(&format)?;
+        if face_size == 0 {
+            return Err(Error::invalid_argument("The faces of a cube map must not be empty."))
+        }
+
+        let mut cube = TextureCube::new(format, Extent2d { width: face_size, height: face_size }, 1)?;
+        let panorama = FSampler2D::with_wraps(image, [Wrap::REPEAT, Wrap::CLAMP_TO_EDGE], Filter::NEAREST, Filter::LINEAR);
+        let mut faces = FSamplerCube::new(&mut cube, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::LINEAR);
+
+        for face in 0..6 {
+            for y in 0..face_size {
+                for x in 0..face_size {
+                    let coord = [(x as f32 + 0.5) / face_size as f32, (y as f32 + 0.5) / face_size as f32];
+                    let [u, v] = equirectangular_coord(face_direction(face, coord));
+                    let texel = panorama.texel_lod(NormalizeCoord2d { x: u, y: v }, 0.0);
+                    faces.texel_write(Extent2d { width: x, height: y }, face, 0, texel);
+                }
+            }
+        }
+
+        drop(faces);
+        Ok(cube)
+    }
+
+    /// Resample a level of the cube map into an equirectangular panorama with a single level.
+    ///
+    /// The panorama follows the conventions of `from_equirectangular`, and the faces are filtered across their edges.
+    #[cfg(not(target_os = "windows"))]
+    pub fn to_equirectangular(&self, extent: Extent2d, level: usize) -> Result<Texture2D> {
+
+        use crate::sampler::{FSampler2D, FSamplerCube, Wrap, Filter};
+
+        let format = self.format();
+        uncompressed_texel_size(&format)?;
+        if level >= self.levels() {
+            return Err(Error::invalid_argument(format!("The cube map has {} levels, the level {} does not exist.", self.levels(), level)))
+        }
+        if extent.width == 0 || extent.height == 0 {
+            return Err(Error::invalid_argument("The panorama must not be empty."))
+        }
+
+        let mut image = Texture2D::new(format, extent, 1)?;
+        let faces = FSamplerCube::new(self, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::LINEAR);
+        let mut panorama = FSampler2D::new(&mut image, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+
+        for y in 0..extent.height {
+            for x in 0..extent.width {
+                let u = (x as f32 + 0.5) / extent.width as f32;
+                let v = (y as f32 + 0.5) / extent.height as f32;
+                let texel = faces.sample_dir_seamless(equirectangular_direction([u, v]), level as f32);
+                panorama.texel_write(Extent2d { width: x, height: y }, 0, texel);
+            }
+        }
+
+        drop(panorama);
+        Ok(image)
+    }
+}
+
+/// Return the size in bytes of a texel, or an error for compressed formats.
+fn uncompressed_texel_size(format: &Format) -> Result<usize> {
+
+    if format.is_compressed() {
+        Err(Error::unsupported_format(format!("The cube map layouts do not support the compressed format {}.", format)))
+    } else {
+        Ok(format.block_size())
+    }
+}
+
+/// Return the normalized coordinate of a direction in an equirectangular panorama.
+#[cfg(not(target_os = "windows"))]
+fn equirectangular_coord(direction: [f32; 3]) -> [f32; 2] {
+
+    use std::f32::consts::PI;
+
+    let [x, y, z] = direction;
+    let length = (x * x + y * y + z * z).sqrt().max(f32::MIN_POSITIVE);
+
+    let longitude = x.atan2(-z);
+    let latitude = (y / length).clamp(-1.0, 1.0).acos();
+
+    [0.5 + longitude / (2.0 * PI), latitude / PI]
+}
+
+/// Return the direction pointing at a normalized coordinate of an equirectangular panorama.
+#[cfg(not(target_os = "windows"))]
+fn equirectangular_direction(coord: [f32; 2]) -> [f32; 3] {
+
+    use std::f32::consts::PI;
+
+    let longitude = (coord[0] - 0.5) * 2.0 * PI;
+    let latitude = coord[1] * PI;
+
+    [latitude.sin() * longitude.sin(), latitude.cos(), -latitude.sin() * longitude.cos()]
+}
@@ -0,0 +1,342 @@
+
+use crate::format::Format;
+use crate::sampler::{FSamplerCube, Wrap, Filter};
+use crate::sampler::cube::face_direction;
+use crate::texture::{GliTexture, TextureCube};
+use crate::extent::Extent2d;
+use crate::error::{Result, Error};
+
+use std::f32::consts::PI;
+
+/// The spherical harmonics projection of the radiance of a cube map, up to the second band.
+///
+/// The coefficients are ordered by band: `L00`, `L1-1`, `L10`, `L11`, `L2-2`, `L2-1`, `L20`, `L21`, `L22`,
+/// and each coefficient holds the red, green and blue channels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SphericalHarmonics {
+    pub coefficients: [[f32; 3]; 9],
+}
+
+impl SphericalHarmonics {
+
+    /// Evaluate the diffuse irradiance for a surface `normal`.
+    ///
+    /// As for `TextureCube::irradiance`, the irradiance is divided by PI,
+    /// so that the radiance reflected by a Lambertian surface is `albedo * irradiance`.
+    pub fn irradiance(&self, normal: [f32; 3]) -> [f32; 3] {
+
+        // The cosine lobe convolution of each band(Ramamoorthi and Hanrahan), divided by PI.
+        const BAND_WEIGHTS: [f32; 9] = [1.0, 2.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, 0.25, 0.25, 0.25, 0.25, 0.25];
+
+        let basis = sh_basis(normalize(normal));
+        let mut irradiance = [0.0; 3];
+
+        for ((coefficient, basis), weight) in self.coefficients.iter().zip(basis.iter()).zip(BAND_WEIGHTS.iter()) {
+            for channel in 0..3 {
+                irradiance[channel] += coefficient[channel] * basis * weight;
+            }
+        }
+
+        [irradiance[0].max(0.0), irradiance[1].max(0.0), irradiance[2].max(0.0)]
+    }
+}
+
+impl TextureCube {
+
+    /// Project the base level of the cube map onto the spherical harmonics up to the second band.
+    ///
+    /// Every texel of the base level is weighted by the solid angle it covers. The format must be uncompressed.
+    pub fn spherical_harmonics(&self) -> Result<SphericalHarmonics> {
+
+        check_uncompressed(&self.format())?;
+
+        let size = self.extent(0).width;
+        let sampler = FSamplerCube::new(self, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        let mut coefficients = [[0.0; 3]; 9];
+
+        for face in 0..6 {
+            for y in 0..size {
+                for x in 0..size {
+
+                    let coord = texel_center(x, y, size);
+                    let direction = face_direction(face, coord);
+                    let weight = texel_solid_angle(coord, size);
+
+                    let texel = sampler.texel_fetch(Extent2d { width: x, height: y }, face, 0);
+                    let basis = sh_basis(normalize(direction));
+
+                    for (coefficient, basis) in coefficients.iter_mut().zip(basis.iter()) {
+                        for channel in 0..3 {
+                            coefficient[channel] += texel[channel] * basis * weight;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(SphericalHarmonics { coefficients })
+    }
+
+    /// Convolve the cube map with a cosine lobe into a diffuse irradiance cube map with a single level of `face_size` texels.
+    ///
+    /// The irradiance is divided by PI, so that the radiance reflected by a Lambertian surface is `albedo * irradiance`.
+    /// Each texel integrates `samples` directions distributed by importance over the cosine lobe, and the radiance
+    /// is read from a mipmap level matching the solid angle of each sample to avoid aliasing.
+    ///
+    /// The texture keeps the format of the cube map, which should be a float format such as `RGBA16_SFLOAT` to hold HDR values.
+    pub fn irradiance(&self, face_size: u32, samples: u32) -> Result<TextureCube> {
+
+        check_arguments(&self.format(), face_size, samples)?;
+
+        let source = self.with_mipmap_chain()?;
+        let radiance = FSamplerCube::new(&source, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR);
+        let source_size = source.extent(0).width;
+
+        let mut irradiance = TextureCube::new(self.format(), Extent2d { width: face_size, height: face_size }, 1)?;
+        let mut dst = FSamplerCube::new(&mut irradiance, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+
+        for face in 0..6 {
+            for y in 0..face_size {
+                for x in 0..face_size {
+
+                    let normal = normalize(face_direction(face, texel_center(x, y, face_size)));
+                    let (tangent, bitangent) = tangent_frame(normal);
+
+                    let mut sum = [0.0; 3];
+                    for i in 0..samples {
+
+                        let [u, v] = hammersley(i, samples);
+                        let cos_theta = (1.0 - v).sqrt();
+                        let sin_theta = v.sqrt();
+                        let phi = 2.0 * PI * u;
+
+                        let direction = to_world([sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta], tangent, bitangent, normal);
+                        let pdf = cos_theta / PI;
+                        let level = sample_level(pdf, samples, source_size);
+
+                        let texel = radiance.sample_dir_seamless(direction, level);
+                        sum = [sum[0] + texel[0], sum[1] + texel[1], sum[2] + texel[2]];
+                    }
+
+                    let scale = 1.0 / samples as f32;
+                    dst.texel_write(Extent2d { width: x, height: y }, face, 0, [sum[0] * scale, sum[1] * scale, sum[2] * scale, 1.0]);
+                }
+            }
+        }
+
+        drop(dst);
+        Ok(irradiance)
+    }
+
+    /// Prefilter the cube map for the specular reflection of the GGX microfacet distribution.
+    ///
+    /// The returned cube map has `levels` levels from `face_size` texels, and level `i` is convolved with
+    /// the roughness `i / (levels - 1)`, the roughness being squared into the alpha of GGX. Each texel integrates
+    /// `samples` directions distributed by importance over the GGX lobe, assuming the view direction equals the normal.
+    ///
+    /// The texture keeps the format of the cube map, which should be a float format such as `RGBA16_SFLOAT` to hold HDR values.
+    pub fn prefilter_specular(&self, face_size: u32, levels: usize, samples: u32) -> Result<TextureCube> {
+
+        check_arguments(&self.format(), face_size, samples)?;
+
+        let max_levels = (32 - face_size.leading_zeros()) as usize;
+        if levels == 0 || levels > max_levels {
+            return Err(Error::invalid_argument(format!("A cube map of {} texels has between 1 and {} levels, not {}.", face_size, max_levels, levels)))
+        }
+
+        let source = self.with_mipmap_chain()?;
+        let radiance = FSamplerCube::new(&source, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR);
+        let source_size = source.extent(0).width;
+
+        let mut specular = TextureCube::new(self.format(), Extent2d { width: face_size, height: face_size }, levels)?;
+        let mut dst = FSamplerCube::new(&mut specular, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+
+        for level in 0..levels {
+
+            let size = (face_size >> level).max(1);
+            let roughness = if levels > 1 { level as f32 / (levels - 1) as f32 } else { 0.0 };
+            let alpha = roughness * roughness;
+
+            for face in 0..6 {
+                for y in 0..size {
+                    for x in 0..size {
+
+                        let normal = normalize(face_direction(face, texel_center(x, y, size)));
+
+                        let texel = if alpha == 0.0 {
+                            // A perfect mirror only reflects the radiance along the normal.
+                            radiance.sample_dir_seamless(normal, 0.0)
+                        } else {
+                            prefilter_ggx(&radiance, source_size, normal, alpha, samples)
+                        };
+
+                        dst.texel_write(Extent2d { width: x, height: y }, face, level, texel);
+                    }
+                }
+            }
+        }
+
+        drop(dst);
+        Ok(specular)
+    }
+
+    /// Copy the base level of the cube map into a new cube map, and generate its complete mipmap chain.
+    fn with_mipmap_chain(&self) -> Result<TextureCube> {
+
+        let extent = self.extent(0);
+        let mut texture = TextureCube::new_with_mipmap_chain(self.format(), Extent2d { width: extent.width, height: extent.height })?;
+        for face in 0..6 {
+            texture.copy(self, 0, face, 0, 0, face, 0)?;
+        }
+
+        FSamplerCube::new(&mut texture, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR).generate_mipmaps(Filter::LINEAR);
+        Ok(texture)
+    }
+}
+
+/// Integrate the radiance around `normal` weighted by the GGX lobe of `alpha`.
+fn prefilter_ggx(radiance: &FSamplerCube, source_size: u32, normal: [f32; 3], alpha: f32, samples: u32) -> [f32; 4] {
+
+    let (tangent, bitangent) = tangent_frame(normal);
+    let alpha2 = alpha * alpha;
+
+    let mut sum = [0.0; 3];
+    let mut total_weight = 0.0;
+
+    for i in 0..samples {
+
+        let [u, v] = hammersley(i, samples);
+        let cos_theta = ((1.0 - v) / (1.0 + (alpha2 - 1.0) * v)).sqrt();
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+        let phi = 2.0 * PI * u;
+
+        let half = to_world([sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta], tangent, bitangent, normal);
+        let v_dot_h = dot(normal, half);
+        let light = [
+            2.0 * v_dot_h * half[0] - normal[0],
+            2.0 * v_dot_h * half[1] - normal[1],
+            2.0 * v_dot_h * half[2] - normal[2],
+        ];
+
+        let n_dot_l = dot(normal, light);
+        if n_dot_l <= 0.0 {
+            continue
+        }
+
+        // With the view direction along the normal, the pdf of the reflected direction is D(h) / 4.
+        let d = alpha2 / (PI * (cos_theta * cos_theta * (alpha2 - 1.0) + 1.0).powi(2));
+        let level = sample_level(d / 4.0, samples, source_size);
+
+        let texel = radiance.sample_dir_seamless(light, level);
+        for channel in 0..3 {
+            sum[channel] += texel[channel] * n_dot_l;
+        }
+        total_weight += n_dot_l;
+    }
+
+    if total_weight > 0.0 {
+        [sum[0] / total_weight, sum[1] / total_weight, sum[2] / total_weight, 1.0]
+    } else {
+        radiance.sample_dir_seamless(normal, 0.0)
+    }
+}
+
+fn check_uncompressed(format: &Format) -> Result<()> {
+
+    if format.is_compressed() {
+        Err(Error::unsupported_format(format!("The image based lighting filters do not support the compressed format {}.", format)))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_arguments(format: &Format, face_size: u32, samples: u32) -> Result<()> {
+
+    check_uncompressed(format)?;
+    if face_size == 0 {
+        return Err(Error::invalid_argument("The faces of a cube map must not be empty."))
+    }
+    if samples == 0 {
+        return Err(Error::invalid_argument("The number of samples must not be zero."))
+    }
+    Ok(())
+}
+
+/// Return the mipmap level whose texels cover the solid angle of a sample of probability density `pdf`(filtered importance sampling).
+fn sample_level(pdf: f32, samples: u32, source_size: u32) -> f32 {
+
+    let sample_solid_angle = 1.0 / (samples as f32 * pdf).max(f32::MIN_POSITIVE);
+    let texel_solid_angle = 4.0 * PI / (6.0 * (source_size * source_size) as f32);
+
+    (0.5 * (sample_solid_angle / texel_solid_angle).log2() + 1.0).max(0.0)
+}
+
+/// The real spherical harmonics basis up to the second band, evaluated along a normalized direction.
+fn sh_basis(direction: [f32; 3]) -> [f32; 9] {
+
+    let [x, y, z] = direction;
+    [
+        0.282_095,
+        0.488_603 * y,
+        0.488_603 * z,
+        0.488_603 * x,
+        1.092_548 * x * y,
+        1.092_548 * y * z,
+        0.315_392 * (3.0 * z * z - 1.0),
+        1.092_548 * x * z,
+        0.546_274 * (x * x - y * y),
+    ]
+}
+
+/// Return the solid angle covered by the texel of a face centered at the normalized coordinate `coord`.
+fn texel_solid_angle(coord: [f32; 2], size: u32) -> f32 {
+
+    // The solid angle subtended by the rectangle from the face center to a point of the face.
+    fn area_element(x: f32, y: f32) -> f32 {
+        (x * y).atan2((x * x + y * y + 1.0).sqrt())
+    }
+
+    let half_texel = 1.0 / size as f32;
+    let (u, v) = (coord[0] * 2.0 - 1.0, coord[1] * 2.0 - 1.0);
+    let (x0, x1, y0, y1) = (u - half_texel, u + half_texel, v - half_texel, v + half_texel);
+
+    area_element(x0, y0) - area_element(x0, y1) - area_element(x1, y0) + area_element(x1, y1)
+}
+
+fn texel_center(x: u32, y: u32, size: u32) -> [f32; 2] {
+    [(x as f32 + 0.5) / size as f32, (y as f32 + 0.5) / size as f32]
+}
+
+/// The `i`-th point of a Hammersley set of `count` points in the unit square.
+fn hammersley(i: u32, count: u32) -> [f32; 2] {
+    [i as f32 / count as f32, i.reverse_bits() as f32 * 2.328_306_4e-10]
+}
+
+/// Return a tangent and a bitangent orthogonal to `normal`.
+fn tangent_frame(normal: [f32; 3]) -> ([f32; 3], [f32; 3]) {
+
+    let up = if normal[2].abs() < 0.999 { [0.0, 0.0, 1.0] } else { [1.0, 0.0, 0.0] };
+    let tangent = normalize(cross(up, normal));
+    (tangent, cross(normal, tangent))
+}
+
+fn to_world(v: [f32; 3], tangent: [f32; 3], bitangent: [f32; 3], normal: [f32; 3]) -> [f32; 3] {
+    [
+        tangent[0] * v[0] + bitangent[0] * v[1] + normal[0] * v[2],
+        tangent[1] * v[0] + bitangent[1] * v[1] + normal[1] * v[2],
+        tangent[2] * v[0] + bitangent[2] * v[1] + normal[2] * v[2],
+    ]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt().max(f32::MIN_POSITIVE);
+    [v[0] / length, v[1] / length, v[2] / length]
+}
@@ -0,0 +1,204 @@
+use crate::ffi::root::glm;
+use crate::ffi::root::bindings::Texture as bindings;
+
+use crate::sampler::{Wrap, Filter};
+use crate::sampler::filtering;
+use crate::target::Target;
+use crate::texture::{GliTexture, check_storage, mipmap_levels};
+use crate::texture::texel::TexelLayout;
+use crate::texture::transform::{srgb_to_linear, linear_to_srgb};
+use crate::error::{Result, Error, catch_ffi};
+
+use std::slice;
+
+/// How the requested extent of `ResizeOptions` is rounded to a power of two.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PowerOfTwo {
+    /// Round each axis up to the next power of two.
+    Up,
+    /// Round each axis down to the previous power of two.
+    Down,
+    /// Round each axis to the nearest power of two, up when both are as near.
+    Nearest,
+}
+
+/// The options of `GliTexture::resize_with`.
+///
+/// ```ignore
+/// let tile = texture.resize_with(Extent2d { width: 200, height: 120 }, &ResizeOptions::new(Filter::CATMULL_ROM)
+///     .wrap(Wrap::REPEAT)
+///     .power_of_two(PowerOfTwo::Nearest))?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResizeOptions {
+    filter: Filter,
+    wraps: [Wrap; 3],
+    border_color: [f32; 4],
+    levels: Option<usize>,
+    generate_mipmaps: bool,
+    power_of_two: Option<PowerOfTwo>,
+}
+
+impl ResizeOptions {
+
+    /// Resize with `filter`, clamping to the edges, and regenerate the mipmaps.
+    pub fn new(filter: Filter) -> ResizeOptions {
+        ResizeOptions {
+            filter,
+            wraps: [Wrap::CLAMP_TO_EDGE, Wrap::CLAMP_TO_EDGE, Wrap::CLAMP_TO_EDGE],
+            border_color: [0.0, 0.0, 0.0, 1.0],
+            levels: None,
+            generate_mipmaps: true,
+            power_of_two: None,
+        }
+    }
+
+    /// Set the wrap mode of every axis. Use `Wrap::REPEAT` for tiling textures.
+    pub fn wrap(self, wrap: Wrap) -> ResizeOptions {
+        self.wraps([wrap.clone(), wrap.clone(), wrap])
+    }
+
+    /// Set the wrap mode of each axis(s, t, r). The axes the texture does not have are ignored.
+    pub fn wraps(mut self, wraps: [Wrap; 3]) -> ResizeOptions {
+        self.wraps = wraps;
+        self
+    }
+
+    /// Set the border color used with `Wrap::CLAMP_TO_BORDER`. Default is `[0.0, 0.0, 0.0, 1.0]`.
+    pub fn border_color(mut self, color: [f32; 4]) -> ResizeOptions {
+        self.border_color = color;
+        self
+    }
+
+    /// Set the number of levels of the resized texture.
+    ///
+    /// By default, the resized texture has as many levels as the source, up to a complete mipmap chain.
+    pub fn levels(mut self, levels: usize) -> ResizeOptions {
+        self.levels = Some(levels);
+        self
+    }
+
+    /// Set whether the levels after the base level are generated by filtering the level above, which is the default,
+    /// or resized from the same level of the source.
+    pub fn generate_mipmaps(mut self, generate: bool) -> ResizeOptions {
+        self.generate_mipmaps = generate;
+        self
+    }
+
+    /// Round the requested extent to a power of two.
+    pub fn power_of_two(mut self, rounding: PowerOfTwo) -> ResizeOptions {
+        self.power_of_two = Some(rounding);
+        self
+    }
+}
+
+/// Resize every layer and face of `texture` into a new texture of `extent` texels.
+///
+/// When an axis is shrunk, the filter covers all the source texels of each destination texel, see `filtering::resample`.
+pub(crate) fn resize<T: GliTexture>(texture: &T, extent: [u32; 3], options: &ResizeOptions) -> Result<T> {
+
+    if texture.empty() {
+        return Err(Error::invalid_argument("Can not resize an empty texture."))
+    }
+
+    let format = texture.format();
+    let layout = TexelLayout::new(&format)?;
+
+    let axes = match T::TARGET_TYPE {
+        | Target::TARGET_1D | Target::TARGET_1D_ARRAY => 1,
+        | Target::TARGET_3D => 3,
+        | _ => 2,
+    };
+
+    let mut extent = extent;
+    for (axis, size) in extent.iter_mut().enumerate() {
+        *size = if axis >= axes { 1 } else { round_power_of_two(*size, options.power_of_two) };
+    }
+
+    let levels = options.levels.unwrap_or_else(|| texture.levels().min(mipmap_levels(extent)));
+    if !options.generate_mipmaps && levels > texture.levels() {
+        return Err(Error::invalid_argument(format!("The source has {} levels to resize, but {} levels are requested.", texture.levels(), levels)))
+    }
+    check_storage(&format, extent, texture.layers(), texture.faces(), levels)?;
+
+    let raw_extent = glm::ivec3(extent);
+    let mut resized = T::from(catch_ffi(|| unsafe { bindings::texture_new_like(texture.raw_texture(), &raw_extent, levels) })?);
+
+    // The sRGB texels are filtered in linear space, like the samplers do.
+    let is_srgb = format.is_srgb();
+
+    for layer in 0..texture.layers() {
+        for face in 0..texture.faces() {
+            for level in 0..levels {
+
+                let (src_extent, src_texels) = if level == 0 || !options.generate_mipmaps {
+                    read_level(texture, &layout, is_srgb, layer, face, level)
+                } else {
+                    read_level(&resized, &layout, is_srgb, layer, face, level - 1)
+                };
+                let dst_extent = level_extent(&resized, level);
+
+                let texels = filtering::resample(
+                    &src_extent[..axes], &dst_extent[..axes], &options.wraps[..axes], &options.filter, options.border_color, src_texels);
+
+                write_level(&mut resized, &layout, is_srgb, layer, face, level, &texels);
+            }
+        }
+    }
+
+    Ok(resized)
+}
+
+fn round_power_of_two(size: u32, rounding: Option<PowerOfTwo>) -> u32 {
+
+    let down = |size: u32| if size == 0 { 0 } else { 1 << (31 - size.leading_zeros()) };
+    let up = |size: u32| size.checked_next_power_of_two().unwrap_or(1 << 31);
+
+    match rounding {
+        | None => size,
+        | Some(PowerOfTwo::Up) => up(size),
+        | Some(PowerOfTwo::Down) => down(size),
+        | Some(PowerOfTwo::Nearest) => {
+            let (down, up) = (down(size), up(size));
+            if size - down < up - size { down } else { up }
+        },
+    }
+}
+
+fn level_extent<T: GliTexture>(texture: &T, level: usize) -> [u32; 3] {
+    *unsafe { bindings::texture_extent(texture.raw_texture(), level) }
+}
+
+/// Decode the texels of a layer, face and level to float, with the first axis varying the fastest.
+fn read_level<T: GliTexture>(texture: &T, layout: &TexelLayout, is_srgb: bool, layer: usize, face: usize, level: usize) -> ([u32; 3], Vec<[f32; 4]>) {
+
+    let data = unsafe {
+        slice::from_raw_parts(texture.data_detail(layer, face, level) as *const u8, texture.size_at_level(level))
+    };
+
+    let texels = data.chunks_exact(layout.size()).map(|texel| {
+        let mut value = layout.decode(texel);
+        if is_srgb {
+            value[..3].iter_mut().for_each(|channel| *channel = srgb_to_linear(*channel));
+        }
+        value.map(|channel| channel as f32)
+    }).collect();
+
+    (level_extent(texture, level), texels)
+}
+
+fn write_level<T: GliTexture>(texture: &mut T, layout: &TexelLayout, is_srgb: bool, layer: usize, face: usize, level: usize, texels: &[[f32; 4]]) {
+
+    let size = texture.size_at_level(level);
+    let data = unsafe {
+        slice::from_raw_parts_mut(texture.data_detail_mut(layer, face, level) as *mut u8, size)
+    };
+
+    for (texel, value) in data.chunks_exact_mut(layout.size()).zip(texels.iter()) {
+        let mut value = value.map(f64::from);
+        if is_srgb {
+            value[..3].iter_mut().for_each(|channel| *channel = linear_to_srgb(*channel));
+        }
+        layout.encode(value, texel);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::ffi::root::gli;
+
+use crate::format::Swizzles;
+use crate::texture::GliTexture;
+use crate::texture::texel::{TexelLayout, for_each_texel};
+use crate::error::{Result, Error};
+
+/// The swizzles which read every channel from itself.
+pub(crate) const IDENTITY_SWIZZLES: Swizzles = [
+    gli::swizzle_SWIZZLE_RED,
+    gli::swizzle_SWIZZLE_GREEN,
+    gli::swizzle_SWIZZLE_BLUE,
+    gli::swizzle_SWIZZLE_ALPHA,
+];
+
+/// Rewrite the texels of every layer, face and level of `texture` with its swizzles applied, then reset its swizzles.
+pub(crate) fn bake_swizzles<T: GliTexture>(texture: &mut T) -> Result<()> {
+
+    let swizzles = texture.swizzles();
+
+    if texture.empty() || swizzles == IDENTITY_SWIZZLES {
+        return Ok(())
+    }
+    if let Some(swizzle) = swizzles.iter().find(|&&swizzle| swizzle > gli::swizzle_SWIZZLE_LAST) {
+        return Err(Error::invalid_argument(format!("The swizzle {} is not valid.", swizzle)))
+    }
+
+    let format = texture.format();
+    let layout = TexelLayout::new(&format)?;
+    layout.check_swizzles(&format, &swizzles)?;
+
+    for_each_texel(texture, &layout, |texel| layout.swizzle(texel, &swizzles));
+
+    texture.set_swizzles(IDENTITY_SWIZZLES);
+    Ok(())
+}
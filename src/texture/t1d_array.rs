@@ -6,12 +6,17 @@ use crate::format::Format;
 use crate::target::Target;
 use crate::texture::{GliTexture, Texture1D};
 use crate::texture::inner::TextureAccessible;
+use crate::texture::{check_image, check_index, check_view, check_storage, mipmap_levels};
 use crate::Extent1d;
+use crate::error::{Result, catch_ffi};
+
+use std::marker::PhantomData;
 
 /// 1d array texture
 #[repr(transparent)]
 pub struct Texture1DArray {
     ffi: gli::texture1d_array,
+    phantom_type: PhantomData<*const ()>,
 }
 
 impl Texture1DArray {
@@ -19,60 +24,89 @@ impl Texture1DArray {
     /// Create an empty texture 1D array.
     #[inline]
     pub fn new_empty() -> Texture1DArray {
-        Texture1DArray { ffi: unsafe { bindings::tex1darray_new_empty() } }
+        Texture1DArray { ffi: unsafe { bindings::tex1darray_new_empty() }, phantom_type: PhantomData }
     }
 
     /// Create a texture1d_array and allocate a new storage_linear.
+    ///
+    /// Return an error if the extent or the number of levels is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new(format: Format, extent: Extent1d, layers: usize, levels: usize) -> Texture1DArray {
-        Texture1DArray { ffi: unsafe { bindings::tex1darray_new_(format.0, extent.into(), layers, levels) } }
+    pub fn new(format: Format, extent: Extent1d, layers: usize, levels: usize) -> Result<Texture1DArray> {
+
+        check_storage(&format, [extent.width, 1, 1], layers, 1, levels)?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::tex1darray_new_(format.0, extent.into(), layers, levels) })?;
+        Ok(Texture1DArray { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture1d_array and allocate a new storage_linear with a complete mipmap chain.
+    ///
+    /// Return an error if the extent is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new_with_mipmap_chain(format: Format, extent: Extent1d, layers: usize) -> Texture1DArray {
-        Texture1DArray { ffi: unsafe { bindings::tex1darray_new_with_mipmap_chain(format.0, extent.into(), layers) } }
+    pub fn new_with_mipmap_chain(format: Format, extent: Extent1d, layers: usize) -> Result<Texture1DArray> {
+
+        let texels = [extent.width, 1, 1];
+        check_storage(&format, texels, layers, 1, mipmap_levels(texels))?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::tex1darray_new_with_mipmap_chain(format.0, extent.into(), layers) })?;
+        Ok(Texture1DArray { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture1d_array view with an existing storage_linear.
     #[inline]
     pub fn share_from(texture: &impl GliTexture) -> Texture1DArray {
-        Texture1DArray { ffi: unsafe { bindings::tex1darray_share_from(texture.raw_texture()) } }
+        Texture1DArray { ffi: unsafe { bindings::tex1darray_share_from(texture.raw_texture()) }, phantom_type: PhantomData }
     }
 
     /// Create a texture1d_array view with an existing storage_linear.
+    ///
+    /// The layer, face and level ranges are inclusive and absolute in the storage of `texture`.
+    /// Return an error if a range exceeds the storage or does not fit the target of the view,
+    /// or if `format` is not view compatible with the format of `texture`, see `Format::is_view_compatible`.
     #[inline]
-    pub fn share_from_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Texture1DArray {
+    pub fn share_from_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Result<Texture1DArray> {
 
-        Texture1DArray {
-            ffi: unsafe { bindings::tex1darray_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) }
-        }
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &format, [base_layer, max_layer], [base_face, max_face], [base_level, max_level])?;
+
+        Ok(Texture1DArray {
+            ffi: catch_ffi(|| unsafe { bindings::tex1darray_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a texture1d_array view, reference a subset of an existing texture1d_array instance.
+    ///
+    /// The layers and levels ranges are inclusive and relative to `texture`. Return an error if a range exceeds `texture`.
     #[inline]
-    pub fn share_from_subset(texture: &Texture1DArray, base_layer: usize, max_layer: usize, base_level: usize, max_level: usize) -> Texture1DArray {
-
-        Texture1DArray {
-            ffi: unsafe { bindings::tex1darray_share_from_subset(&texture.ffi, base_layer, max_layer, base_level, max_level) }
-        }
+    pub fn share_from_subset(texture: &Texture1DArray, base_layer: usize, max_layer: usize, base_level: usize, max_level: usize) -> Result<Texture1DArray> {
+
+        check_index("layer", max_layer, texture.layers())?;
+        check_index("level", max_level, texture.levels())?;
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &texture.format(),
+            [texture.base_layer() + base_layer, texture.base_layer() + max_layer],
+            [texture.base_face(), texture.max_face()],
+            [texture.base_level() + base_level, texture.base_level() + max_level])?;
+
+        Ok(Texture1DArray {
+            ffi: catch_ffi(|| unsafe { bindings::tex1darray_share_from_subset(&texture.ffi, base_layer, max_layer, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
-   /// Create a view of the texture identified by Layer in the texture array.
-   ///
-   /// This method is equivalent to `[]` operator in C++ version.
+    /// Create a view of the texture identified by Layer in the texture array.
+    ///
+    /// This method is equivalent to `[]` operator in C++ version.
     #[inline]
-    pub fn get_layer(&self, layer: usize) -> Texture1D {
+    pub fn get_layer(&self, layer: usize) -> Result<Texture1D> {
 
-       debug_assert!(self.empty());
-       debug_assert!(layer < self.layers());
+        check_image(self, layer, 0, 0)?;
 
-       Texture1D::share_from_detail(
-           self, self.format(),
-           self.base_layer() + layer, self.base_layer() + layer,
-           self.base_face(), self.max_face(),
-           self.base_level(), self.max_level())
-   }
+        Texture1D::share_from_detail(
+            self, self.format(),
+            self.base_layer() + layer, self.base_layer() + layer,
+            self.base_face(), self.max_face(),
+            self.base_level(), self.max_level())
+    }
 
     #[doc(hidden)]
     #[inline]
@@ -100,7 +134,7 @@ impl TextureAccessible for Texture1DArray {
 impl From<gli::texture> for Texture1DArray {
 
     fn from(ffi: gli::texture) -> Texture1DArray {
-        Texture1DArray { ffi: gli::texture1d_array { _base: ffi } }
+        Texture1DArray { ffi: gli::texture1d_array { _base: ffi }, phantom_type: PhantomData }
     }
 }
 
@@ -128,3 +162,11 @@ impl std::cmp::PartialEq for Texture1DArray {
 }
 
 impl std::cmp::Eq for Texture1DArray {}
+
+impl Clone for Texture1DArray {
+
+    /// Copy the texture into a new storage. A view is copied into a standalone texture holding the subset it references.
+    fn clone(&self) -> Texture1DArray {
+        crate::texture::duplicate(self)
+    }
+}
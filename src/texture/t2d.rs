@@ -8,12 +8,17 @@ use crate::target::Target;
 use crate::image::GliImage;
 use crate::texture::GliTexture;
 use crate::texture::inner::TextureAccessible;
+use crate::texture::{check_image, check_index, check_view, check_storage, mipmap_levels};
 use crate::Extent2d;
+use crate::error::{Result, catch_ffi};
+
+use std::marker::PhantomData;
 
 /// 2d texture
 #[repr(transparent)]
 pub struct Texture2D {
     ffi: gli::texture2d,
+    phantom_type: PhantomData<*const ()>,
 }
 
 impl Texture2D {
@@ -21,51 +26,82 @@ impl Texture2D {
     /// Create an empty texture 2D.
     #[inline]
     pub fn new_empty() -> Texture2D {
-        Texture2D { ffi: unsafe { bindings::tex2d_new_empty() } }
+        Texture2D { ffi: unsafe { bindings::tex2d_new_empty() }, phantom_type: PhantomData }
     }
 
     /// Create a texture2d and allocate a new storage_linear.
+    ///
+    /// Return an error if the extent or the number of levels is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new(format: Format, extent: Extent2d, levels: usize) -> Texture2D {
-        Texture2D { ffi: unsafe { bindings::tex2d_new_(format.0, glm::ivec2(extent.into()), levels) } }
+    pub fn new(format: Format, extent: Extent2d, levels: usize) -> Result<Texture2D> {
+
+        check_storage(&format, [extent.width, extent.height, 1], 1, 1, levels)?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::tex2d_new_(format.0, glm::ivec2(extent.into()), levels) })?;
+        Ok(Texture2D { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture2d and allocate a new storage_linear with a complete mipmap chain.
+    ///
+    /// Return an error if the extent is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new_with_mipmap_chain(format: Format, extent: Extent2d) -> Texture2D {
-        Texture2D { ffi: unsafe { bindings::tex2d_new_with_mipmap_chain(format.0, glm::ivec2(extent.into())) } }
+    pub fn new_with_mipmap_chain(format: Format, extent: Extent2d) -> Result<Texture2D> {
+
+        let texels = [extent.width, extent.height, 1];
+        check_storage(&format, texels, 1, 1, mipmap_levels(texels))?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::tex2d_new_with_mipmap_chain(format.0, glm::ivec2(extent.into())) })?;
+        Ok(Texture2D { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture2d view with an existing storage_linear.
     #[inline]
     pub fn share_from(texture: &impl GliTexture) -> Texture2D {
-        Texture2D { ffi: unsafe { bindings::tex2d_share_from(texture.raw_texture()) } }
+        Texture2D { ffi: unsafe { bindings::tex2d_share_from(texture.raw_texture()) }, phantom_type: PhantomData }
     }
 
     /// Create a texture2d view with an existing storage_linear.
+    ///
+    /// The layer, face and level ranges are inclusive and absolute in the storage of `texture`.
+    /// Return an error if a range exceeds the storage or does not fit the target of the view,
+    /// or if `format` is not view compatible with the format of `texture`, see `Format::is_view_compatible`.
     #[inline]
-    pub fn share_from_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Texture2D {
+    pub fn share_from_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Result<Texture2D> {
 
-        Texture2D {
-            ffi: unsafe { bindings::tex2d_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) }
-        }
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &format, [base_layer, max_layer], [base_face, max_face], [base_level, max_level])?;
+
+        Ok(Texture2D {
+            ffi: catch_ffi(|| unsafe { bindings::tex2d_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a texture2d view, reference a subset of an existing texture2d instance.
+    ///
+    /// The levels ranges are inclusive and relative to `texture`. Return an error if a range exceeds `texture`.
     #[inline]
-    pub fn share_from_subset(texture: &Texture2D, base_level: usize, max_level: usize) -> Texture2D {
-
-        Texture2D { ffi: unsafe { bindings::tex2d_share_from_subset(&texture.ffi, base_level, max_level) } }
+    pub fn share_from_subset(texture: &Texture2D, base_level: usize, max_level: usize) -> Result<Texture2D> {
+
+        check_index("level", max_level, texture.levels())?;
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &texture.format(),
+            [texture.base_layer(), texture.max_layer()],
+            [texture.base_face(), texture.max_face()],
+            [texture.base_level() + base_level, texture.base_level() + max_level])?;
+
+        Ok(Texture2D {
+            ffi: catch_ffi(|| unsafe { bindings::tex2d_share_from_subset(&texture.ffi, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a view of the image identified by Level in the mipmap chain of the texture.
     ///
     /// This method is equivalent to `[]` operator in C++ version.
     #[inline]
-    pub fn get_level(&self, level: usize) -> GliImage {
+    pub fn get_level(&self, level: usize) -> Result<GliImage> {
 
-        debug_assert!(level < self.levels());
-        GliImage::shared_from_texture(self, self.format(), self.base_layer(), self.base_face(), self.base_level() + level)
+        check_image(self, 0, 0, level)?;
+        Ok(GliImage::shared_from_texture(self, self.format(), self.base_layer(), self.base_face(), self.base_level() + level))
     }
 
     #[doc(hidden)]
@@ -94,7 +130,7 @@ impl TextureAccessible for Texture2D {
 impl From<gli::texture> for Texture2D {
 
     fn from(ffi: gli::texture) -> Texture2D {
-        Texture2D { ffi: gli::texture2d { _base: ffi } }
+        Texture2D { ffi: gli::texture2d { _base: ffi }, phantom_type: PhantomData }
     }
 }
 
@@ -122,3 +158,11 @@ impl std::cmp::PartialEq for Texture2D {
 }
 
 impl std::cmp::Eq for Texture2D {}
+
+impl Clone for Texture2D {
+
+    /// Copy the texture into a new storage. A view is copied into a standalone texture holding the subset it references.
+    fn clone(&self) -> Texture2D {
+        crate::texture::duplicate(self)
+    }
+}
@@ -8,12 +8,17 @@ use crate::target::Target;
 use crate::image::GliImage;
 use crate::texture::GliTexture;
 use crate::texture::inner::TextureAccessible;
+use crate::texture::{check_image, check_index, check_view, check_storage, mipmap_levels};
 use crate::Extent3d;
+use crate::error::{Result, catch_ffi};
+
+use std::marker::PhantomData;
 
 /// 3d texture
 #[repr(transparent)]
 pub struct Texture3D {
     ffi: gli::texture3d,
+    phantom_type: PhantomData<*const ()>,
 }
 
 impl Texture3D {
@@ -21,50 +26,82 @@ impl Texture3D {
     /// Create an empty texture 3D.
     #[inline]
     pub fn new_empty() -> Texture3D {
-        Texture3D { ffi: unsafe { bindings::tex3d_new_empty() } }
+        Texture3D { ffi: unsafe { bindings::tex3d_new_empty() }, phantom_type: PhantomData }
     }
 
     /// Create a texture3d and allocate a new storage_linear.
+    ///
+    /// Return an error if the extent or the number of levels is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new(format: Format, extent: Extent3d, levels: usize) -> Texture3D {
-        Texture3D { ffi: unsafe { bindings::tex3d_new_(format.0, glm::ivec3(extent.into()), levels) } }
+    pub fn new(format: Format, extent: Extent3d, levels: usize) -> Result<Texture3D> {
+
+        check_storage(&format, [extent.width, extent.height, extent.depth], 1, 1, levels)?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::tex3d_new_(format.0, glm::ivec3(extent.into()), levels) })?;
+        Ok(Texture3D { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture3d and allocate a new storage_linear with a complete mipmap chain.
+    ///
+    /// Return an error if the extent is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new_with_mipmap_chain(format: Format, extent: Extent3d) -> Texture3D {
-        Texture3D { ffi: unsafe { bindings::tex3d_new_with_mipmap_chain(format.0, glm::ivec3(extent.into())) } }
+    pub fn new_with_mipmap_chain(format: Format, extent: Extent3d) -> Result<Texture3D> {
+
+        let texels = [extent.width, extent.height, extent.depth];
+        check_storage(&format, texels, 1, 1, mipmap_levels(texels))?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::tex3d_new_with_mipmap_chain(format.0, glm::ivec3(extent.into())) })?;
+        Ok(Texture3D { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture3d view with an existing storage_linear.
     #[inline]
     pub fn share_from(texture: &impl GliTexture) -> Texture3D {
-        Texture3D { ffi: unsafe { bindings::tex3d_share_from(texture.raw_texture()) } }
+        Texture3D { ffi: unsafe { bindings::tex3d_share_from(texture.raw_texture()) }, phantom_type: PhantomData }
     }
 
     /// Create a texture3d view with an existing storage_linear.
+    ///
+    /// The layer, face and level ranges are inclusive and absolute in the storage of `texture`.
+    /// Return an error if a range exceeds the storage or does not fit the target of the view,
+    /// or if `format` is not view compatible with the format of `texture`, see `Format::is_view_compatible`.
     #[inline]
-    pub fn share_from_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Texture3D {
+    pub fn share_from_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Result<Texture3D> {
 
-        Texture3D {
-            ffi: unsafe { bindings::tex3d_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) }
-        }
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &format, [base_layer, max_layer], [base_face, max_face], [base_level, max_level])?;
+
+        Ok(Texture3D {
+            ffi: catch_ffi(|| unsafe { bindings::tex3d_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a texture3d view, reference a subset of an existing texture3d instance.
+    ///
+    /// The levels ranges are inclusive and relative to `texture`. Return an error if a range exceeds `texture`.
     #[inline]
-    pub fn share_from_subset(texture: &Texture3D, base_level: usize, max_level: usize) -> Texture3D {
-        Texture3D { ffi: unsafe { bindings::tex3d_share_from_subset(&texture.ffi, base_level, max_level) } }
+    pub fn share_from_subset(texture: &Texture3D, base_level: usize, max_level: usize) -> Result<Texture3D> {
+
+        check_index("level", max_level, texture.levels())?;
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &texture.format(),
+            [texture.base_layer(), texture.max_layer()],
+            [texture.base_face(), texture.max_face()],
+            [texture.base_level() + base_level, texture.base_level() + max_level])?;
+
+        Ok(Texture3D {
+            ffi: catch_ffi(|| unsafe { bindings::tex3d_share_from_subset(&texture.ffi, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a view of the image identified by Level in the mipmap chain of the texture.
     ///
     /// This method is equivalent to `[]` operator in C++ version.
     #[inline]
-    pub fn get_level(&self, level: usize) -> GliImage {
+    pub fn get_level(&self, level: usize) -> Result<GliImage> {
 
-        debug_assert!(level < self.levels());
-        GliImage::shared_from_texture(self, self.format(), self.base_layer(), self.base_face(), self.base_level() + level)
+        check_image(self, 0, 0, level)?;
+        Ok(GliImage::shared_from_texture(self, self.format(), self.base_layer(), self.base_face(), self.base_level() + level))
     }
 
     #[doc(hidden)]
@@ -93,7 +130,7 @@ impl TextureAccessible for Texture3D {
 impl From<gli::texture> for Texture3D {
 
     fn from(ffi: gli::texture) -> Texture3D {
-        Texture3D { ffi: gli::texture3d { _base: ffi } }
+        Texture3D { ffi: gli::texture3d { _base: ffi }, phantom_type: PhantomData }
     }
 }
 
@@ -121,3 +158,11 @@ impl std::cmp::PartialEq for Texture3D {
 }
 
 impl std::cmp::Eq for Texture3D {}
+
+impl Clone for Texture3D {
+
+    /// Copy the texture into a new storage. A view is copied into a standalone texture holding the subset it references.
+    fn clone(&self) -> Texture3D {
+        crate::texture::duplicate(self)
+    }
+}
@@ -7,12 +7,17 @@ use crate::format::Format;
 use crate::target::Target;
 use crate::texture::{GliTexture, Texture2D};
 use crate::texture::inner::TextureAccessible;
+use crate::texture::{check_image, check_index, check_view, check_storage, mipmap_levels};
 use crate::Extent2d;
+use crate::error::{Result, catch_ffi};
+
+use std::marker::PhantomData;
 
 /// Cube map texture
 #[repr(transparent)]
 pub struct TextureCube {
     ffi: gli::texture_cube,
+    phantom_type: PhantomData<*const ()>,
 }
 
 impl TextureCube {
@@ -20,52 +25,82 @@ impl TextureCube {
     /// Create an empty texture cube.
     #[inline]
     pub fn new_empty() -> TextureCube {
-        TextureCube { ffi: unsafe { bindings::texcube_new_empty() } }
+        TextureCube { ffi: unsafe { bindings::texcube_new_empty() }, phantom_type: PhantomData }
     }
 
     /// Create a texture_cube.hpp and allocate a new storage_linear.
+    ///
+    /// Return an error if the extent or the number of levels is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new(format: Format, extent: Extent2d, levels: usize) -> TextureCube {
-        TextureCube { ffi: unsafe { bindings::texcube_new_(format.0, glm::ivec2(extent.into()), levels) } }
+    pub fn new(format: Format, extent: Extent2d, levels: usize) -> Result<TextureCube> {
+
+        check_storage(&format, [extent.width, extent.height, 1], 1, 6, levels)?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::texcube_new_(format.0, glm::ivec2(extent.into()), levels) })?;
+        Ok(TextureCube { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture_cube.hpp and allocate a new storage_linear with a complete mipmap chain.
+    ///
+    /// Return an error if the extent is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new_with_mipmap_chain(format: Format, extent: Extent2d) -> TextureCube {
-        TextureCube { ffi: unsafe { bindings::texcube_new_with_mipmap_chain(format.0, glm::ivec2(extent.into())) } }
+    pub fn new_with_mipmap_chain(format: Format, extent: Extent2d) -> Result<TextureCube> {
+
+        let texels = [extent.width, extent.height, 1];
+        check_storage(&format, texels, 1, 6, mipmap_levels(texels))?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::texcube_new_with_mipmap_chain(format.0, glm::ivec2(extent.into())) })?;
+        Ok(TextureCube { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture_cube.hpp view with an existing storage_linear.
     #[inline]
     pub fn new_from(texture: &impl GliTexture) -> TextureCube {
-        TextureCube { ffi: unsafe { bindings::texcube_share_from(texture.raw_texture()) } }
+        TextureCube { ffi: unsafe { bindings::texcube_share_from(texture.raw_texture()) }, phantom_type: PhantomData }
     }
 
     /// Create a texture_cube.hpp view with an existing storage_linear.
+    ///
+    /// The layer, face and level ranges are inclusive and absolute in the storage of `texture`.
+    /// Return an error if a range exceeds the storage or does not fit the target of the view,
+    /// or if `format` is not view compatible with the format of `texture`, see `Format::is_view_compatible`.
     #[inline]
-    pub fn new_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> TextureCube {
+    pub fn new_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Result<TextureCube> {
 
-        TextureCube {
-            ffi: unsafe { bindings::texcube_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) }
-        }
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &format, [base_layer, max_layer], [base_face, max_face], [base_level, max_level])?;
+
+        Ok(TextureCube {
+            ffi: catch_ffi(|| unsafe { bindings::texcube_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a texture_cube.hpp view, reference a subset of an existing texture_cube.hpp instance.
+    ///
+    /// The faces and levels ranges are inclusive and relative to `texture`. Return an error if a range exceeds `texture`.
     #[inline]
-    pub fn new_from_subset(texture: &TextureCube, base_layer: usize, max_layer: usize, base_level: usize, max_level: usize) -> TextureCube {
-
-        TextureCube {
-            ffi: unsafe { bindings::texcube_share_from_subset(&texture.ffi, base_layer, max_layer, base_level, max_level) }
-        }
+    pub fn new_from_subset(texture: &TextureCube, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Result<TextureCube> {
+
+        check_index("face", max_face, texture.faces())?;
+        check_index("level", max_level, texture.levels())?;
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &texture.format(),
+            [texture.base_layer(), texture.max_layer()],
+            [texture.base_face() + base_face, texture.base_face() + max_face],
+            [texture.base_level() + base_level, texture.base_level() + max_level])?;
+
+        Ok(TextureCube {
+            ffi: catch_ffi(|| unsafe { bindings::texcube_share_from_subset(&texture.ffi, base_face, max_face, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a view of the texture identified by Face in the texture cube.
     ///
     /// This method is equivalent to `[]` operator in C++ version.
     #[inline]
-    pub fn get_face(&self, face: usize) -> Texture2D {
+    pub fn get_face(&self, face: usize) -> Result<Texture2D> {
 
-        debug_assert!(face < self.faces());
+        check_image(self, 0, face, 0)?;
 
         Texture2D::share_from_detail(
             self, self.format(),
@@ -100,7 +135,7 @@ impl TextureAccessible for TextureCube {
 impl From<gli::texture> for TextureCube {
 
     fn from(ffi: gli::texture) -> TextureCube {
-        TextureCube { ffi: gli::texture_cube { _base: ffi } }
+        TextureCube { ffi: gli::texture_cube { _base: ffi }, phantom_type: PhantomData }
     }
 }
 
@@ -128,3 +163,11 @@ impl std::cmp::PartialEq for TextureCube {
 }
 
 impl std::cmp::Eq for TextureCube {}
+
+impl Clone for TextureCube {
+
+    /// Copy the texture into a new storage. A view is copied into a standalone texture holding the subset it references.
+    fn clone(&self) -> TextureCube {
+        crate::texture::duplicate(self)
+    }
+}
@@ -7,12 +7,17 @@ use crate::format::Format;
 use crate::target::Target;
 use crate::texture::{GliTexture, TextureCube};
 use crate::texture::inner::TextureAccessible;
+use crate::texture::{check_image, check_index, check_view, check_storage, mipmap_levels};
 use crate::Extent2d;
+use crate::error::{Result, catch_ffi};
+
+use std::marker::PhantomData;
 
 /// Cube map array texture
 #[repr(transparent)]
 pub struct TextureCubeArray {
     ffi: gli::texture_cube_array,
+    phantom_type: PhantomData<*const ()>,
 }
 
 impl TextureCubeArray {
@@ -20,58 +25,87 @@ impl TextureCubeArray {
     /// Create an empty texture cube array.
     #[inline]
     pub fn new_empty() -> TextureCubeArray {
-        TextureCubeArray { ffi: unsafe { bindings::texcubearray_new_empty() } }
+        TextureCubeArray { ffi: unsafe { bindings::texcubearray_new_empty() }, phantom_type: PhantomData }
     }
 
     /// Create a texture_cube_array and allocate a new storage_linear.
+    ///
+    /// Return an error if the extent or the number of levels is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new(format: Format, extent: Extent2d, layers: usize, levels: usize) -> TextureCubeArray {
+    pub fn new(format: Format, extent: Extent2d, layers: usize, levels: usize) -> Result<TextureCubeArray> {
 
-        TextureCubeArray { ffi: unsafe { bindings::texcubearray_new_(format.0, glm::ivec2(extent.into()), layers, levels) } }
+        check_storage(&format, [extent.width, extent.height, 1], layers, 6, levels)?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::texcubearray_new_(format.0, glm::ivec2(extent.into()), layers, levels) })?;
+        Ok(TextureCubeArray { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture_cube_array and allocate a new storage_linear with a complete mipmap chain.
+    ///
+    /// Return an error if the extent is not supported, or if the storage can not be allocated.
     #[inline]
-    pub fn new_with_mipmap_chain(format: Format, extent: Extent2d, layers: usize) -> TextureCubeArray {
+    pub fn new_with_mipmap_chain(format: Format, extent: Extent2d, layers: usize) -> Result<TextureCubeArray> {
 
-        TextureCubeArray { ffi: unsafe { bindings::texcubearray_new_with_mipmap_chain(format.0, glm::ivec2(extent.into()), layers) } }
+        let texels = [extent.width, extent.height, 1];
+        check_storage(&format, texels, layers, 6, mipmap_levels(texels))?;
+
+        let ffi = catch_ffi(|| unsafe { bindings::texcubearray_new_with_mipmap_chain(format.0, glm::ivec2(extent.into()), layers) })?;
+        Ok(TextureCubeArray { ffi, phantom_type: PhantomData })
     }
 
     /// Create a texture_cube_array view with an existing storage_linear.
     #[inline]
     pub fn new_from(texture: &impl GliTexture) -> TextureCubeArray {
-        TextureCubeArray { ffi: unsafe { bindings::texcubearray_share_from(texture.raw_texture()) } }
+        TextureCubeArray { ffi: unsafe { bindings::texcubearray_share_from(texture.raw_texture()) }, phantom_type: PhantomData }
     }
 
     /// Create a texture_cube_array view with an existing storage_linear.
+    ///
+    /// The layer, face and level ranges are inclusive and absolute in the storage of `texture`.
+    /// Return an error if a range exceeds the storage or does not fit the target of the view,
+    /// or if `format` is not view compatible with the format of `texture`, see `Format::is_view_compatible`.
     #[inline]
-    pub fn new_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> TextureCubeArray {
+    pub fn new_detail(texture: &impl GliTexture, format: Format, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Result<TextureCubeArray> {
 
-        TextureCubeArray {
-            ffi: unsafe { bindings::texcubearray_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) }
-        }
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &format, [base_layer, max_layer], [base_face, max_face], [base_level, max_level])?;
+
+        Ok(TextureCubeArray {
+            ffi: catch_ffi(|| unsafe { bindings::texcubearray_share_from_detail(texture.raw_texture(), format.0, base_layer, max_layer, base_face, max_face, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a texture_cube_array view, reference a subset of an existing texture_cube_array instance.
+    ///
+    /// The layers, faces and levels ranges are inclusive and relative to `texture`. Return an error if a range exceeds `texture`.
     #[inline]
-    pub fn new_from_subset(texture: &TextureCubeArray, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> TextureCubeArray {
-
-        TextureCubeArray {
-            ffi: unsafe { bindings::texcubearray_share_from_subset(&texture.ffi, base_layer, max_layer, base_face, max_face, base_level, max_level) }
-        }
+    pub fn new_from_subset(texture: &TextureCubeArray, base_layer: usize, max_layer: usize, base_face: usize, max_face: usize, base_level: usize, max_level: usize) -> Result<TextureCubeArray> {
+
+        check_index("layer", max_layer, texture.layers())?;
+        check_index("face", max_face, texture.faces())?;
+        check_index("level", max_level, texture.levels())?;
+        check_view(texture.raw_texture(), Self::TARGET_TYPE, &texture.format(),
+            [texture.base_layer() + base_layer, texture.base_layer() + max_layer],
+            [texture.base_face() + base_face, texture.base_face() + max_face],
+            [texture.base_level() + base_level, texture.base_level() + max_level])?;
+
+        Ok(TextureCubeArray {
+            ffi: catch_ffi(|| unsafe { bindings::texcubearray_share_from_subset(&texture.ffi, base_layer, max_layer, base_face, max_face, base_level, max_level) })?,
+            phantom_type: PhantomData,
+        })
     }
 
     /// Create a view of the texture identified by Layer in the texture array.
     ///
     /// This method is equivalent to `[]` operator in C++ version.
     #[inline]
-    pub fn get_layer(&self, layer: usize) -> TextureCube {
+    pub fn get_layer(&self, layer: usize) -> Result<TextureCube> {
 
-        debug_assert!(layer < self.layers());
+        check_image(self, layer, 0, 0)?;
 
         TextureCube::new_detail(
             self, self.format(),
-            self.base_layer() + layer, self.base_level() + layer,
+            self.base_layer() + layer, self.base_layer() + layer,
             self.base_face(), self.max_face(),
             self.base_level(), self.max_level())
     }
@@ -102,7 +136,7 @@ impl TextureAccessible for TextureCubeArray {
 impl From<gli::texture> for TextureCubeArray {
 
     fn from(ffi: gli::texture) -> TextureCubeArray {
-        TextureCubeArray { ffi: gli::texture_cube_array { _base: ffi } }
+        TextureCubeArray { ffi: gli::texture_cube_array { _base: ffi }, phantom_type: PhantomData }
     }
 }
 
@@ -130,3 +164,11 @@ impl std::cmp::PartialEq for TextureCubeArray {
 }
 
 impl std::cmp::Eq for TextureCubeArray {}
+
+impl Clone for TextureCubeArray {
+
+    /// Copy the texture into a new storage. A view is copied into a standalone texture holding the subset it references.
+    fn clone(&self) -> TextureCubeArray {
+        crate::texture::duplicate(self)
+    }
+}
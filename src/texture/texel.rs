@@ -0,0 +1,486 @@
+// Layout of the texels of the uncompressed formats, to read and write their channels on the Rust side.
+//
+// The layouts are derived from the names of the formats, which list the stored channels with their number
+// of bits, followed by their numeric type, like `RGB10A2_UNORM_PACK32` or `D24_UNORM_S8_UINT_PACK32`.
+
+use crate::format::{Format, Swizzle, Swizzles};
+use crate::texture::GliTexture;
+use crate::error::{Result, Error};
+
+use std::slice;
+
+/// The largest texel of an uncompressed format, in bytes.
+const MAX_TEXEL_SIZE: usize = 32;
+
+/// The numeric type of a stored channel.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Encoding {
+    Unorm,
+    Snorm,
+    Uint,
+    Sint,
+    Float,
+    UFloat,
+}
+
+/// A channel stored in a bit field of the texel.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Field {
+    offset: usize,
+    bits: usize,
+    encoding: Encoding,
+}
+
+/// The source of a red, green, blue or alpha channel.
+#[derive(Debug, Clone, Copy)]
+enum Channel {
+    Stored(Field),
+    Zero,
+    One,
+    /// A channel not stored by the format, which repeats another channel, like the luminance of `L8_UNORM_PACK8`.
+    Copy(usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Storage {
+    Fields([Channel; 4]),
+    /// `RGB9E5_UFLOAT_PACK32`, whose channels share an exponent.
+    SharedExponent,
+}
+
+/// The layout of the texels of an uncompressed format.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TexelLayout {
+    size: usize,
+    storage: Storage,
+}
+
+impl TexelLayout {
+
+    /// Return the layout of `format`, or an error if the format is compressed or not valid.
+    pub(crate) fn new(format: &Format) -> Result<TexelLayout> {
+
+        if !format.is_valid() || format.is_compressed() {
+            return Err(Error::unsupported_format(format!("The texels of {} can not be accessed, only the uncompressed formats are supported.", format)))
+        }
+
+        let size = format.block_size();
+        if format == &Format::RGB9E5_UFLOAT_PACK32 {
+            return Ok(TexelLayout { size, storage: Storage::SharedExponent })
+        }
+
+        let name = format.to_string();
+        let tokens: Vec<&str> = name.split('_').collect();
+        let (groups, pack) = tokens.split_at(tokens.len() - 1);
+
+        // The channels of each group, like `D24` and `UNORM`, with their number of bits.
+        let mut stored: Vec<(char, usize, Encoding)> = Vec::new();
+        for group in groups.chunks(2) {
+            let encoding = match group.get(1) {
+                | Some(&"UNORM") | Some(&"SRGB")    => Encoding::Unorm,
+                | Some(&"SNORM")                    => Encoding::Snorm,
+                | Some(&"UINT")  | Some(&"USCALED") => Encoding::Uint,
+                | Some(&"SINT")  | Some(&"SSCALED") => Encoding::Sint,
+                | Some(&"SFLOAT")                   => Encoding::Float,
+                | Some(&"UFLOAT")                   => Encoding::UFloat,
+                | _ => return Err(Error::bug(format!("The layout of the format {} is not known.", format))),
+            };
+            stored.extend(parse_channels(group[0]).into_iter().map(|(letter, bits)| (letter, bits, encoding)));
+        }
+
+        // The components of the packed 8 and 16 bit formats are listed from the most significant bits,
+        // the others from the first byte, like Vulkan does.
+        let msb_first = (pack[0] == "PACK8" || pack[0] == "PACK16") && stored.iter().any(|&(_, bits, _)| bits % 8 != 0);
+        let total_bits: usize = stored.iter().map(|&(_, bits, _)| bits).sum();
+        if total_bits > size * 8 {
+            return Err(Error::bug(format!("The channels of the format {} exceed its texels.", format)))
+        }
+
+        let is_luminance = stored.iter().any(|&(letter, _, _)| letter == 'L');
+        let mut channels = [
+            Channel::Zero,
+            if is_luminance { Channel::Copy(0) } else { Channel::Zero },
+            if is_luminance { Channel::Copy(0) } else { Channel::Zero },
+            Channel::One,
+        ];
+
+        let mut offset = 0;
+        let mut depth_stencil = 0;
+        for (letter, bits, encoding) in stored {
+
+            let index = match letter {
+                | 'R' | 'L' => 0,
+                | 'G' => 1,
+                | 'B' => 2,
+                | 'A' => 3,
+                // The depth and the stencil are read as red and green, or red alone.
+                | _ => { depth_stencil += 1; depth_stencil - 1 },
+            };
+
+            let field_offset = if msb_first { size * 8 - offset - bits } else { offset };
+            channels[index] = Channel::Stored(Field { offset: field_offset, bits, encoding });
+            offset += bits;
+        }
+
+        Ok(TexelLayout { size, storage: Storage::Fields(channels) })
+    }
+
+    /// Return the size of a texel in bytes.
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Return whether a channel of the texels stores an integer, which is neither normalized nor a float.
+    pub(crate) fn is_integer(&self) -> bool {
+        match self.storage {
+            | Storage::Fields(ref channels) => channels.iter().any(|channel| match *channel {
+                | Channel::Stored(field) => field.encoding == Encoding::Uint || field.encoding == Encoding::Sint,
+                | _ => false,
+            }),
+            | Storage::SharedExponent => false,
+        }
+    }
+
+    /// Decode the red, green, blue and alpha channels of a texel.
+    ///
+    /// The normalized channels are decoded in 0 to 1 (or -1 to 1), and the integer channels to their value.
+    pub(crate) fn decode(&self, texel: &[u8]) -> [f64; 4] {
+
+        match self.storage {
+            | Storage::Fields(ref channels) => {
+                let mut value = [0.0; 4];
+                for (index, channel) in value.iter_mut().enumerate() {
+                    *channel = decode_channel(channels, index, texel);
+                }
+                value
+            },
+            | Storage::SharedExponent => decode_rgb9e5(read_bits(texel, 0, 32)),
+        }
+    }
+
+    /// Encode the red, green, blue and alpha channels of a texel. The channels not stored by the format are discarded.
+    pub(crate) fn encode(&self, value: [f64; 4], texel: &mut [u8]) {
+
+        match self.storage {
+            | Storage::Fields(ref channels) => {
+                for (channel, &channel_value) in channels.iter().zip(value.iter()) {
+                    if let Channel::Stored(field) = *channel {
+                        write_bits(texel, field.offset, field.bits, encode_field(&field, channel_value));
+                    }
+                }
+            },
+            | Storage::SharedExponent => write_bits(texel, 0, 32, encode_rgb9e5(value)),
+        }
+    }
+
+    /// Return an error if `swizzles` can not be baked into the texels, because a channel not stored by the format
+    /// does not read what the format already reads there, like the green channel of `R8_UNORM_PACK8` swizzled to red.
+    pub(crate) fn check_swizzles(&self, format: &Format, swizzles: &Swizzles) -> Result<()> {
+
+        for (index, &swizzle) in swizzles.iter().enumerate() {
+
+            if self.is_stored(index) {
+                continue
+            }
+
+            let source = swizzle_index(swizzle).and_then(|source| self.resolve(source));
+            if source != self.resolve(index) {
+                return Err(Error::unsupported_format(format!(
+                    "The swizzle {} of the channel {} can not be baked, because the format {} does not store this channel.",
+                    swizzle, index, format)))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return whether the channel `index` is stored in the texels.
+    fn is_stored(&self, index: usize) -> bool {
+        match self.storage {
+            | Storage::Fields(ref channels) => match channels[index] {
+                | Channel::Stored(_) => true,
+                | _ => false,
+            },
+            | Storage::SharedExponent => index < 3,
+        }
+    }
+
+    /// Return the stored channel read by the channel `index`, or the constant it reads.
+    fn resolve(&self, index: usize) -> std::result::Result<usize, f64> {
+        match self.storage {
+            | Storage::Fields(ref channels) => match channels[index] {
+                | Channel::Stored(_)    => Ok(index),
+                | Channel::Zero         => Err(0.0),
+                | Channel::One          => Err(1.0),
+                | Channel::Copy(source) => self.resolve(source),
+            },
+            | Storage::SharedExponent => if index < 3 { Ok(index) } else { Err(1.0) },
+        }
+    }
+
+    /// Rearrange the channels of a texel, so that reading it gives the channels selected by `swizzles`.
+    ///
+    /// The channels with the same numeric type are moved without conversion. The swizzles must pass `check_swizzles`.
+    pub(crate) fn swizzle(&self, texel: &mut [u8], swizzles: &Swizzles) {
+
+        let mut source = [0; MAX_TEXEL_SIZE];
+        source[..texel.len()].copy_from_slice(texel);
+        let source = &source[..texel.len()];
+
+        match self.storage {
+            | Storage::Fields(ref channels) => {
+                for (channel, &swizzle) in channels.iter().zip(swizzles.iter()) {
+                    if let Channel::Stored(field) = *channel {
+                        let bits = match swizzle_index(swizzle) {
+                            | Ok(index) => read_channel_as(channels, index, source, &field),
+                            | Err(constant) => encode_field(&field, constant),
+                        };
+                        write_bits(texel, field.offset, field.bits, bits);
+                    }
+                }
+            },
+            | Storage::SharedExponent => {
+                let value = decode_rgb9e5(read_bits(source, 0, 32));
+                let mut swizzled = [0.0; 4];
+                for (channel, &swizzle) in swizzled.iter_mut().zip(swizzles.iter()) {
+                    *channel = swizzle_index(swizzle).map(|index| value[index]).unwrap_or_else(|constant| constant);
+                }
+                write_bits(texel, 0, 32, encode_rgb9e5(swizzled));
+            },
+        }
+    }
+}
+
+/// Call `process` with every texel of every layer, face and level of `texture`.
+///
+/// If the storage is shared, the texture first gets its own copy, see `make_unique`.
+pub(crate) fn for_each_texel<T, F>(texture: &mut T, layout: &TexelLayout, mut process: F)
+    where
+        T: GliTexture,
+        F: FnMut(&mut [u8]) {
+
+    for layer in 0..texture.layers() {
+        for face in 0..texture.faces() {
+            for level in 0..texture.levels() {
+
+                let size = texture.size_at_level(level);
+                let texels = unsafe {
+                    slice::from_raw_parts_mut(texture.data_detail_mut(layer, face, level) as *mut u8, size)
+                };
+
+                texels.chunks_exact_mut(layout.size()).for_each(&mut process);
+            }
+        }
+    }
+}
+
+/// Return the index of the channel selected by `swizzle`, or the constant it selects. Invalid values select one.
+fn swizzle_index(swizzle: u32) -> std::result::Result<usize, f64> {
+    match Swizzle(swizzle) {
+        | Swizzle::RED   => Ok(0),
+        | Swizzle::GREEN => Ok(1),
+        | Swizzle::BLUE  => Ok(2),
+        | Swizzle::ALPHA => Ok(3),
+        | Swizzle::ZERO  => Err(0.0),
+        | _              => Err(1.0),
+    }
+}
+
+/// Split the channels of a format name, like `A1RGB5`, into the channels and their number of bits.
+fn parse_channels(group: &str) -> Vec<(char, usize)> {
+
+    let mut channels = Vec::new();
+    let (mut letters, mut bits) = (String::new(), 0);
+
+    for character in group.chars() {
+        if let Some(digit) = character.to_digit(10) {
+            bits = bits * 10 + digit as usize;
+        } else {
+            if bits != 0 {
+                channels.extend(letters.drain(..).map(|letter| (letter, bits)));
+                bits = 0;
+            }
+            letters.push(character);
+        }
+    }
+    channels.extend(letters.drain(..).map(|letter| (letter, bits)));
+    channels
+}
+
+fn decode_channel(channels: &[Channel; 4], index: usize, texel: &[u8]) -> f64 {
+    match channels[index] {
+        | Channel::Stored(field) => decode_field(&field, read_bits(texel, field.offset, field.bits)),
+        | Channel::Zero          => 0.0,
+        | Channel::One           => 1.0,
+        | Channel::Copy(source)  => decode_channel(channels, source, texel),
+    }
+}
+
+/// Read the channel `index` of a texel, encoded as `field`.
+fn read_channel_as(channels: &[Channel; 4], index: usize, texel: &[u8], field: &Field) -> u64 {
+    match channels[index] {
+        | Channel::Stored(source) if source.encoding == field.encoding && source.bits == field.bits => {
+            read_bits(texel, source.offset, source.bits)
+        },
+        | Channel::Stored(source) => encode_field(field, decode_field(&source, read_bits(texel, source.offset, source.bits))),
+        | Channel::Zero           => encode_field(field, 0.0),
+        | Channel::One            => encode_field(field, 1.0),
+        | Channel::Copy(source)   => read_channel_as(channels, source, texel, field),
+    }
+}
+
+fn read_bits(texel: &[u8], offset: usize, bits: usize) -> u64 {
+
+    let bytes = &texel[offset / 8..(offset + bits).div_ceil(8)];
+    let word = bytes.iter().rev().fold(0_u128, |word, &byte| (word << 8) | u128::from(byte));
+    ((word >> (offset % 8)) & mask(bits)) as u64
+}
+
+fn write_bits(texel: &mut [u8], offset: usize, bits: usize, value: u64) {
+
+    let shift = offset % 8;
+    let bytes = &mut texel[offset / 8..(offset + bits).div_ceil(8)];
+    let word = bytes.iter().rev().fold(0_u128, |word, &byte| (word << 8) | u128::from(byte));
+    let word = (word & !(mask(bits) << shift)) | ((u128::from(value) & mask(bits)) << shift);
+
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = (word >> (index * 8)) as u8;
+    }
+}
+
+#[inline]
+fn mask(bits: usize) -> u128 {
+    (1_u128 << bits) - 1
+}
+
+fn decode_field(field: &Field, value: u64) -> f64 {
+
+    let bits = field.bits as u32;
+    let signed = || ((value << (64 - bits)) as i64) >> (64 - bits);
+
+    match field.encoding {
+        | Encoding::Unorm  => value as f64 / mask(field.bits) as f64,
+        | Encoding::Snorm  => (signed() as f64 / mask(field.bits - 1) as f64).max(-1.0),
+        | Encoding::Uint   => value as f64,
+        | Encoding::Sint   => signed() as f64,
+        | Encoding::Float  => match bits {
+            | 64 => f64::from_bits(value),
+            | 32 => f64::from(f32::from_bits(value as u32)),
+            | _  => decode_minifloat(value, 5, bits - 6, true),
+        },
+        | Encoding::UFloat => decode_minifloat(value, 5, bits - 5, false),
+    }
+}
+
+fn encode_field(field: &Field, value: f64) -> u64 {
+
+    let bits = field.bits as u32;
+    let max = mask(field.bits) as f64;
+
+    match field.encoding {
+        | Encoding::Unorm  => (value.clamp(0.0, 1.0) * max).round() as u64,
+        | Encoding::Snorm  => {
+            let max = mask(field.bits - 1) as f64;
+            ((value.clamp(-1.0, 1.0) * max).round() as i64 as u64) & mask(field.bits) as u64
+        },
+        | Encoding::Uint   => value.clamp(0.0, max).round() as u64,
+        | Encoding::Sint   => {
+            let max = mask(field.bits - 1) as f64;
+            ((value.clamp(-max - 1.0, max).round() as i64) as u64) & mask(field.bits) as u64
+        },
+        | Encoding::Float  => match bits {
+            | 64 => value.to_bits(),
+            | 32 => u64::from((value as f32).to_bits()),
+            | _  => encode_minifloat(value, 5, bits - 6, true),
+        },
+        | Encoding::UFloat => encode_minifloat(value, 5, bits - 5, false),
+    }
+}
+
+/// Decode a floating point number with `exponent` bits of exponent and `mantissa` bits of mantissa, like a half float.
+fn decode_minifloat(value: u64, exponent: u32, mantissa: u32, signed: bool) -> f64 {
+
+    let max_exponent = (1 << exponent) - 1;
+    let bias = (1 << (exponent - 1)) - 1;
+    let biased = ((value >> mantissa) & max_exponent) as i32;
+    let fraction = (value & ((1 << mantissa) - 1)) as f64 / (1_u64 << mantissa) as f64;
+    let sign = if signed && (value >> (exponent + mantissa)) & 1 == 1 { -1.0 } else { 1.0 };
+
+    let magnitude = if biased == 0 {
+        fraction * 2_f64.powi(1 - bias)
+    } else if biased == max_exponent as i32 {
+        if fraction == 0.0 { f64::INFINITY } else { f64::NAN }
+    } else {
+        (1.0 + fraction) * 2_f64.powi(biased - bias)
+    };
+
+    sign * magnitude
+}
+
+/// Encode a floating point number with `exponent` bits of exponent and `mantissa` bits of mantissa, rounding to the nearest.
+///
+/// The numbers too large for the format become infinite, and the negative numbers become zero if the format is unsigned.
+fn encode_minifloat(value: f64, exponent: u32, mantissa: u32, signed: bool) -> u64 {
+
+    let max_exponent = (1_u64 << exponent) - 1;
+    let bias = (1 << (exponent - 1)) - 1;
+
+    if value.is_nan() {
+        return (max_exponent << mantissa) | (1 << (mantissa - 1))
+    }
+
+    let sign = if signed && value.is_sign_negative() { 1 << (exponent + mantissa) } else { 0 };
+    let value = if signed { value.abs() } else { value.max(0.0) };
+
+    if value < 2_f64.powi(1 - bias) {
+        // A subnormal number. Rounding up to the smallest normal number carries into the exponent.
+        return sign | (value * 2_f64.powi(bias - 1 + mantissa as i32)).round() as u64
+    }
+    if value.is_infinite() {
+        return sign | (max_exponent << mantissa)
+    }
+
+    let unbiased = ((value.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+    let mut fraction = ((value / 2_f64.powi(unbiased) - 1.0) * (1_u64 << mantissa) as f64).round() as u64;
+    let mut biased = (unbiased + bias) as u64;
+    if fraction == 1 << mantissa {
+        fraction = 0;
+        biased += 1;
+    }
+
+    if biased >= max_exponent {
+        sign | (max_exponent << mantissa)
+    } else {
+        sign | (biased << mantissa) | fraction
+    }
+}
+
+/// Decode a texel of `RGB9E5_UFLOAT_PACK32`, whose alpha is one.
+fn decode_rgb9e5(value: u64) -> [f64; 4] {
+
+    let scale = 2_f64.powi(((value >> 27) & 0x1f) as i32 - 15 - 9);
+    [
+        (value & 0x1ff) as f64 * scale,
+        ((value >> 9) & 0x1ff) as f64 * scale,
+        ((value >> 18) & 0x1ff) as f64 * scale,
+        1.0,
+    ]
+}
+
+/// Encode a texel of `RGB9E5_UFLOAT_PACK32`, as specified by `EXT_texture_shared_exponent`.
+fn encode_rgb9e5(value: [f64; 4]) -> u64 {
+
+    let max_value = 511.0 / 512.0 * 65536.0;
+    let rgb = [value[0], value[1], value[2]].map(|channel| if channel.is_nan() { 0.0 } else { channel.clamp(0.0, max_value) });
+    let max_channel = rgb.iter().cloned().fold(0.0, f64::max);
+
+    let mut exponent = (max_channel.log2().floor().max(-16.0) as i32) + 1 + 15;
+    if (max_channel / 2_f64.powi(exponent - 24) + 0.5).floor() == 512.0 {
+        exponent += 1;
+    }
+
+    let scale = 2_f64.powi(exponent - 24);
+    rgb.iter().enumerate().fold((exponent as u64) << 27, |texel, (index, &channel)| {
+        texel | (((channel / scale + 0.5).floor() as u64) << (index * 9))
+    })
+}
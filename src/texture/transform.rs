@@ -0,0 +1,122 @@
+use crate::format::Format;
+use crate::texture::GliTexture;
+use crate::texture::texel::{TexelLayout, for_each_texel};
+use crate::error::{Result, Error};
+
+/// Decode an sRGB encoded channel to linear.
+pub(crate) fn srgb_to_linear(value: f64) -> f64 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear channel to sRGB.
+pub(crate) fn linear_to_srgb(value: f64) -> f64 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Decode the sRGB texels of `texture`, and reinterpret them with the `UNORM` variant of its format.
+pub(crate) fn convert_srgb_to_linear<T: GliTexture>(texture: &mut T) -> Result<()> {
+
+    let format = texture.format();
+    let linear_format = format.to_linear()
+        .filter(|_| !format.is_compressed())
+        .ok_or_else(|| Error::unsupported_format(format!("The format {} is not an uncompressed sRGB format.", format)))?;
+
+    map_colors(texture, &format, |color, _| color.map(srgb_to_linear))?;
+    retype(texture, linear_format)
+}
+
+/// Encode the texels of `texture` to sRGB, and reinterpret them with the sRGB variant of its format.
+pub(crate) fn convert_linear_to_srgb<T: GliTexture>(texture: &mut T) -> Result<()> {
+
+    let format = texture.format();
+    let srgb_format = format.to_srgb()
+        .filter(|_| !format.is_compressed())
+        .ok_or_else(|| Error::unsupported_format(format!("The format {} has no uncompressed sRGB variant.", format)))?;
+
+    map_colors(texture, &format, |color, _| color.map(linear_to_srgb))?;
+    retype(texture, srgb_format)
+}
+
+/// Multiply the color channels of `texture` by its alpha channel.
+pub(crate) fn premultiply_alpha<T: GliTexture>(texture: &mut T) -> Result<()> {
+
+    let format = texture.format();
+    map_linear_colors(texture, &format, |color, alpha| color.map(|channel| channel * alpha))
+}
+
+/// Divide the color channels of `texture` by its alpha channel. The texels whose alpha is zero are not changed.
+pub(crate) fn unpremultiply_alpha<T: GliTexture>(texture: &mut T) -> Result<()> {
+
+    let format = texture.format();
+    map_linear_colors(texture, &format, |color, alpha| {
+        if alpha == 0.0 { color } else { color.map(|channel| channel / alpha) }
+    })
+}
+
+/// Raise the color channels of `texture` to the power `gamma`.
+pub(crate) fn adjust_gamma<T: GliTexture>(texture: &mut T, gamma: f32) -> Result<()> {
+
+    if !(gamma.is_finite() && gamma > 0.0) {
+        return Err(Error::invalid_argument(format!("The gamma {} is not a positive number.", gamma)))
+    }
+
+    let format = texture.format();
+    let gamma = f64::from(gamma);
+    map_colors(texture, &format, |color, _| color.map(|channel| channel.signum() * channel.abs().powf(gamma)))
+}
+
+/// Call `transform` with the color channels in linear space and the alpha channel of every texel of `texture`.
+fn map_linear_colors<T, F>(texture: &mut T, format: &Format, transform: F) -> Result<()>
+    where
+        T: GliTexture,
+        F: Fn([f64; 3], f64) -> [f64; 3] {
+
+    if format.is_srgb() {
+        map_colors(texture, format, |color, alpha| transform(color.map(srgb_to_linear), alpha).map(linear_to_srgb))
+    } else {
+        map_colors(texture, format, transform)
+    }
+}
+
+/// Call `transform` with the color channels and the alpha channel of every texel of `texture`, and store the color it returns.
+///
+/// Return an error if the format is compressed or stores integers.
+fn map_colors<T, F>(texture: &mut T, format: &Format, transform: F) -> Result<()>
+    where
+        T: GliTexture,
+        F: Fn([f64; 3], f64) -> [f64; 3] {
+
+    let layout = TexelLayout::new(format)?;
+    if layout.is_integer() {
+        return Err(Error::unsupported_format(format!("The format {} stores integers, not colors.", format)))
+    }
+
+    if texture.empty() {
+        return Ok(())
+    }
+
+    for_each_texel(texture, &layout, |texel| {
+        let [red, green, blue, alpha] = layout.decode(texel);
+        let [red, green, blue] = transform([red, green, blue], alpha);
+        layout.encode([red, green, blue, alpha], texel);
+    });
+
+    Ok(())
+}
+
+/// Replace `texture` by a view of its storage with `format`, which has the same texel layout as its format.
+fn retype<T: GliTexture>(texture: &mut T, format: Format) -> Result<()> {
+
+    if !texture.empty() {
+        *texture = texture.view_as(format)?;
+    }
+    Ok(())
+}
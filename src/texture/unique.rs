@@ -0,0 +1,69 @@
+
+use crate::image::GliImage;
+use crate::texture::{GliTexture, AnyTexture};
+
+use self::sealed::UniqueStorage;
+
+/// A texture or an image which is the only owner of its storage, and can be moved to another thread.
+///
+/// The textures, the images and the samplers are neither `Send` nor `Sync`: the `share_from*` views and `get_level`
+/// share a storage between several handles, and the samplers write to this storage through shared references.
+/// Moving one of these handles to another thread would let two threads access the same texels without synchronization.
+///
+/// `Unique::new` first gives the value its own storage(see `GliTexture::make_unique`), so no other handle refers to
+/// the storage once it is wrapped. The reference count of the storage is atomic in C++, so the value may be dropped
+/// on any thread.
+///
+/// ```ignore
+/// let texture = thread::spawn(|| Unique::new(gli::load_dds(path).unwrap())).join().unwrap().into_inner();
+/// ```
+pub struct Unique<T: UniqueStorage> {
+    value: T,
+}
+
+mod sealed {
+
+    /// The types which can be wrapped in `Unique`. It can not be implemented outside of this crate.
+    pub trait UniqueStorage {
+        /// Give the value its own storage if it shares it with another handle.
+        fn detach_storage(&mut self);
+    }
+}
+
+impl<T: UniqueStorage> Unique<T> {
+
+    /// Wrap a texture or an image, copying its storage if it is shared.
+    pub fn new(mut value: T) -> Unique<T> {
+        value.detach_storage();
+        Unique { value }
+    }
+
+    /// Unwrap the texture or the image on the current thread.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+// The wrapped value is the only handle to its storage, and it can not be borrowed to create views or samplers while it is wrapped.
+unsafe impl<T: UniqueStorage> Send for Unique<T> {}
+
+impl<T: GliTexture> UniqueStorage for T {
+
+    fn detach_storage(&mut self) {
+        self.make_unique();
+    }
+}
+
+impl UniqueStorage for GliImage {
+
+    fn detach_storage(&mut self) {
+        self.make_unique();
+    }
+}
+
+impl UniqueStorage for AnyTexture {
+
+    fn detach_storage(&mut self) {
+        self.make_unique();
+    }
+}
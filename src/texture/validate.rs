@@ -0,0 +1,164 @@
+
+// Validation of the indices and the regions given to the texture views and copies,
+// which the C++ side only checks with assertions, if at all.
+
+use crate::ffi::root::gli;
+use crate::ffi::root::bindings::Texture as bindings;
+
+use crate::format::Format;
+use crate::target::Target;
+use crate::texture::GliTexture;
+use crate::extent::Extent3d;
+use crate::error::{Result, Error};
+
+/// The number of faces of a cube map.
+const CUBE_FACES: usize = 6;
+
+/// Check that `layer`, `face` and `level` identify an image of `texture`, relatively to its base layer, face and level.
+pub(crate) fn check_image<T: GliTexture>(texture: &T, layer: usize, face: usize, level: usize) -> Result<()> {
+
+    if texture.empty() {
+        return Err(Error::invalid_argument("The texture is empty."))
+    }
+    check_index("layer", layer, texture.layers())?;
+    check_index("face", face, texture.faces())?;
+    check_index("level", level, texture.levels())
+}
+
+/// Check that `index` is less than `count`.
+pub(crate) fn check_index(name: &str, index: usize, count: usize) -> Result<()> {
+
+    if index >= count {
+        Err(Error::invalid_argument(format!("The {} {} is out of range, the texture has {} {}s.", name, index, count, name)))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a view of `target` and `format` can reference the layers, faces and levels of the storage of `texture`.
+///
+/// The ranges are inclusive and absolute in the storage, as the C++ view constructors expect them.
+pub(crate) fn check_view(texture: &gli::texture, target: Target, format: &Format, layers: [usize; 2], faces: [usize; 2], levels: [usize; 2]) -> Result<()> {
+
+    let (storage_layers, storage_faces, storage_levels) = unsafe {(
+        bindings::texture_storage_layers(texture),
+        bindings::texture_storage_faces(texture),
+        bindings::texture_storage_levels(texture),
+    )};
+
+    if storage_levels == 0 {
+        return Err(Error::invalid_argument("Can not create a view of an empty texture."))
+    }
+
+    check_range("layers", layers, storage_layers)?;
+    check_range("faces", faces, storage_faces)?;
+    check_range("levels", levels, storage_levels)?;
+
+    let (is_array, is_cube) = match target {
+        | Target::TARGET_1D_ARRAY | Target::TARGET_2D_ARRAY | Target::TARGET_RECT_ARRAY => (true, false),
+        | Target::TARGET_CUBE       => (false, true),
+        | Target::TARGET_CUBE_ARRAY => (true, true),
+        | _                         => (false, false),
+    };
+
+    if !is_array && layers[0] != layers[1] {
+        return Err(Error::invalid_argument(format!("A {} texture has a single layer, but the view references the layers {} to {}.", target, layers[0], layers[1])))
+    }
+    if is_cube && faces[1] - faces[0] + 1 != CUBE_FACES {
+        return Err(Error::invalid_argument(format!("A {} texture has {} faces, but the view references the faces {} to {}.", target, CUBE_FACES, faces[0], faces[1])))
+    }
+    if !is_cube && faces[0] != faces[1] {
+        return Err(Error::invalid_argument(format!("A {} texture has a single face, but the view references the faces {} to {}.", target, faces[0], faces[1])))
+    }
+
+    check_view_format(&Format(texture.Format), format)
+}
+
+/// Check that the texels of `src` can be reinterpreted by a view of `dst`, see `Format::is_view_compatible`.
+pub(crate) fn check_view_format(src: &Format, dst: &Format) -> Result<()> {
+
+    if src.is_view_compatible(dst) {
+        Ok(())
+    } else {
+        Err(Error::unsupported_format(format!("A view of {} can not reinterpret the texels of {}, their compatibility classes differ.", dst, src)))
+    }
+}
+
+/// Check that the texels of `src` can be copied as texels of `dst`, which requires the same block layout.
+pub(crate) fn check_formats(src: &Format, dst: &Format) -> Result<()> {
+
+    let (src_block, dst_block) = (src.block_extent(), dst.block_extent());
+
+    if src.block_size() != dst.block_size() || [src_block.width, src_block.height, src_block.depth] != [dst_block.width, dst_block.height, dst_block.depth] {
+        Err(Error::unsupported_format(format!("The format {} is not compatible with the format {}, their blocks differ.", src, dst)))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a region of `extent` texels at `offset` lies in a level of `level_extent` texels and is aligned to the blocks of `format`.
+pub(crate) fn check_region(name: &str, offset: Extent3d, extent: Extent3d, level_extent: [u32; 3], format: &Format) -> Result<()> {
+
+    let offset = [offset.width, offset.height, offset.depth];
+    let extent = [extent.width, extent.height, extent.depth];
+    let block = format.block_extent();
+    let block = [block.width, block.height, block.depth];
+
+    for axis in 0..3 {
+        if offset[axis].checked_add(extent[axis]).is_none_or(|end| end > level_extent[axis]) {
+            return Err(Error::invalid_argument(format!("The {} region at {:?} of {:?} texels exceeds the level of {:?} texels.", name, offset, extent, level_extent)))
+        }
+        if !offset[axis].is_multiple_of(block[axis]) || !extent[axis].is_multiple_of(block[axis]) {
+            return Err(Error::invalid_argument(format!("The {} region at {:?} of {:?} texels is not aligned to the {:?} texel blocks of {}.", name, offset, extent, block, format)))
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that a storage of `levels` levels of `extent` texels, with `layers` layers and `faces` faces, can be allocated by gli.
+///
+/// gli stores the extents as signed integers and computes the size of the storage without checking for overflows.
+pub(crate) fn check_storage(format: &Format, extent: [u32; 3], layers: usize, faces: usize, levels: usize) -> Result<()> {
+
+    if extent.iter().any(|&axis| axis == 0 || axis > i32::MAX as u32) {
+        return Err(Error::invalid_argument(format!("The extent {:?} is not supported, each axis must be in 1 to {}.", extent, i32::MAX)))
+    }
+    if layers == 0 || faces == 0 {
+        return Err(Error::invalid_argument("A texture must have at least one layer and one face."))
+    }
+    if levels == 0 || levels > mipmap_levels(extent) {
+        return Err(Error::invalid_argument(format!("The extent {:?} supports 1 to {} levels, but {} levels are requested.", extent, mipmap_levels(extent), levels)))
+    }
+
+    let block = format.block_extent();
+    let block = [block.width as u64, block.height as u64, block.depth as u64];
+
+    let size = (0..levels).try_fold(0_u64, |size, level| {
+        let level_size = (0..3).try_fold(format.block_size() as u64, |level_size, axis| {
+            let texels = (extent[axis] as u64 >> level).max(1);
+            level_size.checked_mul(texels.div_ceil(block[axis]))
+        })?;
+        size.checked_add(level_size)
+    });
+
+    match size.and_then(|size| size.checked_mul(layers as u64)).and_then(|size| size.checked_mul(faces as u64)) {
+        | Some(size) if size <= isize::MAX as u64 => Ok(()),
+        | _ => Err(Error::out_of_memory(format!("The storage of {} levels of {:?} texels, {} layers and {} faces in {} is too large.", levels, extent, layers, faces, format))),
+    }
+}
+
+/// The number of levels of a complete mipmap chain of `extent` texels.
+pub(crate) fn mipmap_levels(extent: [u32; 3]) -> usize {
+    let max_axis = extent.iter().cloned().max().unwrap_or(1).max(1);
+    (32 - max_axis.leading_zeros()) as usize
+}
+
+fn check_range(name: &str, range: [usize; 2], count: usize) -> Result<()> {
+
+    if range[0] > range[1] || range[1] >= count {
+        Err(Error::invalid_argument(format!("The {} {} to {} are out of range, the storage has {} {}.", name, range[0], range[1], count, name)))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+
+//! Layout of gli textures in a Vulkan staging buffer, and the buffer to image copies uploading them.
+//!
+//! The computation does not depend on any Vulkan binding. With the `ash` feature, the regions convert to `ash::vk::BufferImageCopy`.
+
+use crate::ffi::root::glm;
+use crate::ffi::root::bindings::Texture as texture_bindings;
+use crate::target::Target;
+use crate::texture::GliTexture;
+use crate::error::{Result, Error};
+
+use std::slice;
+
+/// The fields of a `VkBufferImageCopy` copying a single layer, face and level of a texture.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BufferImageCopy {
+    /// The offset in bytes of the subresource in the staging buffer.
+    pub buffer_offset: u64,
+    /// Always 0 since the subresources are tightly packed.
+    pub buffer_row_length: u32,
+    /// Always 0 since the subresources are tightly packed.
+    pub buffer_image_height: u32,
+    pub mip_level: u32,
+    /// The array layer of the Vulkan image, which is `layer * faces + face` for the non 3d textures.
+    pub base_array_layer: u32,
+    pub layer_count: u32,
+    pub image_offset: [i32; 3],
+    /// The extent of the copy in texels, which is the extent of the level.
+    pub image_extent: [u32; 3],
+}
+
+/// The placement of all the layers, faces and levels of a texture in a single staging buffer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StagingLayout {
+    /// The minimum size in bytes of the staging buffer.
+    pub size: u64,
+    /// The copy regions, in the order of their layer, face and level.
+    pub regions: Vec<BufferImageCopy>,
+    /// The size in bytes of the data of each region.
+    pub region_sizes: Vec<u64>,
+}
+
+impl StagingLayout {
+
+    /// Compute the placement of the subresources of `texture` in a staging buffer.
+    ///
+    /// `offset_alignment` is the `optimalBufferCopyOffsetAlignment` of the device.
+    /// Each offset is a multiple of this alignment, of the texel block size of the format and of 4, as required by `vkCmdCopyBufferToImage`.
+    ///
+    /// Return an error for combined depth-stencil formats, whose aspects can not be copied from a single buffer region.
+    pub fn new<T: GliTexture>(texture: &T, offset_alignment: u64) -> Result<StagingLayout> {
+
+        let format = texture.format();
+        if format.is_depth_stencil() {
+            return Err(Error::unsupported_format(format!("{} stores depth and stencil aspects together.", format)))
+        }
+
+        let alignment = lcm(lcm(offset_alignment.max(1), format.block_size() as u64), 4);
+        let is_3d = texture.target() == Target::TARGET_3D;
+
+        let (layers, faces, levels) = (texture.layers(), texture.faces(), texture.levels());
+        let mut regions = Vec::with_capacity(layers * faces * levels);
+        let mut region_sizes = Vec::with_capacity(layers * faces * levels);
+        let mut size = 0;
+
+        for layer in 0..layers {
+            for face in 0..faces {
+                for level in 0..levels {
+
+                    let buffer_offset = align_up(size, alignment);
+                    let region_size = texture.size_at_level(level) as u64;
+                    let extent: glm::ivec3 = unsafe { texture_bindings::texture_extent(texture.raw_texture(), level) };
+
+                    regions.push(BufferImageCopy {
+                        buffer_offset,
+                        buffer_row_length: 0,
+                        buffer_image_height: 0,
+                        mip_level: level as u32,
+                        base_array_layer: if is_3d { 0 } else { (layer * faces + face) as u32 },
+                        layer_count: 1,
+                        image_offset: [0, 0, 0],
+                        image_extent: *extent,
+                    });
+                    region_sizes.push(region_size);
+
+                    size = buffer_offset + region_size;
+                }
+            }
+        }
+
+        let layout = StagingLayout { size, regions, region_sizes };
+        Ok(layout)
+    }
+
+    /// Copy the subresources of `texture` to `staging` at the offsets of this layout.
+    ///
+    /// `texture` must be the texture this layout was computed from, and `staging` must be at least `size` bytes long.
+    pub fn copy_to<T: GliTexture>(&self, texture: &T, staging: &mut [u8]) {
+
+        assert!(staging.len() as u64 >= self.size, "The staging buffer is smaller than the layout.");
+
+        let (layers, faces, levels) = (texture.layers(), texture.faces(), texture.levels());
+        assert_eq!(self.regions.len(), layers * faces * levels, "The layout does not match the texture.");
+
+        let mut regions = self.regions.iter().zip(self.region_sizes.iter());
+
+        for layer in 0..layers {
+            for face in 0..faces {
+                for level in 0..levels {
+
+                    let (region, &region_size) = regions.next().unwrap();
+                    let src = unsafe {
+                        slice::from_raw_parts(texture.data_detail(layer, face, level) as *const u8, region_size as usize)
+                    };
+
+                    let offset = region.buffer_offset as usize;
+                    staging[offset..(offset + src.len())].copy_from_slice(src);
+                }
+            }
+        }
+    }
+}
+
+fn align_up(value: u64, alignment: u64) -> u64 {
+    value.div_ceil(alignment) * alignment
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let r = x % y;
+        x = y;
+        y = r;
+    }
+
+    a / x * b
+}
+
+#[cfg(feature = "ash")]
+mod ash_convert {
+
+    use ash::vk;
+
+    use crate::format::Format;
+    use super::BufferImageCopy;
+
+    impl BufferImageCopy {
+
+        /// Convert the region to the Vulkan structure, copying the aspect of the image matching `format`.
+        pub fn to_vk(&self, format: &Format) -> vk::BufferImageCopy {
+
+            let aspect_mask = if format.is_depth() {
+                vk::ImageAspectFlags::DEPTH
+            } else if format.is_stencil() {
+                vk::ImageAspectFlags::STENCIL
+            } else {
+                vk::ImageAspectFlags::COLOR
+            };
+
+            vk::BufferImageCopy {
+                buffer_offset: self.buffer_offset,
+                buffer_row_length: self.buffer_row_length,
+                buffer_image_height: self.buffer_image_height,
+                image_subresource: vk::ImageSubresourceLayers {
+                    aspect_mask,
+                    mip_level: self.mip_level,
+                    base_array_layer: self.base_array_layer,
+                    layer_count: self.layer_count,
+                },
+                image_offset: vk::Offset3D { x: self.image_offset[0], y: self.image_offset[1], z: self.image_offset[2] },
+                image_extent: vk::Extent3D { width: self.image_extent[0], height: self.image_extent[1], depth: self.image_extent[2] },
+            }
+        }
+    }
+}
@@ -0,0 +1,268 @@
+
+//! Conversion of gli textures into the types used to create and upload textures with wgpu.
+//!
+//! This module is only available with the `wgpu` feature.
+
+use ::wgpu::{TextureFormat, TextureDimension, TextureViewDimension, TextureDescriptor, TextureUsages};
+use ::wgpu::{AstcBlock, AstcChannel, Extent3d as WgpuExtent3d, Origin3d, ImageDataLayout};
+
+use crate::ffi::root::glm;
+use crate::ffi::root::bindings::Texture as texture_bindings;
+use crate::format::Format;
+use crate::target::Target;
+use crate::texture::GliTexture;
+use crate::error::{Result, Error};
+
+use std::slice;
+
+/// Convert a gli format into the matching wgpu texture format.
+///
+/// Return `None` if wgpu has no format with the same memory layout.
+pub fn texture_format(format: &Format) -> Option<TextureFormat> {
+
+    let astc = |block: AstcBlock, srgb: bool| {
+        TextureFormat::Astc { block, channel: if srgb { AstcChannel::UnormSrgb } else { AstcChannel::Unorm } }
+    };
+
+    let wgpu_format = match *format {
+        | Format::R8_UNORM_PACK8          => TextureFormat::R8Unorm,
+        | Format::R8_SNORM_PACK8          => TextureFormat::R8Snorm,
+        | Format::R8_UINT_PACK8           => TextureFormat::R8Uint,
+        | Format::R8_SINT_PACK8           => TextureFormat::R8Sint,
+        | Format::RG8_UNORM_PACK8         => TextureFormat::Rg8Unorm,
+        | Format::RG8_SNORM_PACK8         => TextureFormat::Rg8Snorm,
+        | Format::RG8_UINT_PACK8          => TextureFormat::Rg8Uint,
+        | Format::RG8_SINT_PACK8          => TextureFormat::Rg8Sint,
+        | Format::RGBA8_UNORM_PACK8       => TextureFormat::Rgba8Unorm,
+        | Format::RGBA8_SRGB_PACK8        => TextureFormat::Rgba8UnormSrgb,
+        | Format::RGBA8_SNORM_PACK8       => TextureFormat::Rgba8Snorm,
+        | Format::RGBA8_UINT_PACK8        => TextureFormat::Rgba8Uint,
+        | Format::RGBA8_SINT_PACK8        => TextureFormat::Rgba8Sint,
+        | Format::BGRA8_UNORM_PACK8       => TextureFormat::Bgra8Unorm,
+        | Format::BGRA8_SRGB_PACK8        => TextureFormat::Bgra8UnormSrgb,
+        | Format::RGB10A2_UNORM_PACK32    => TextureFormat::Rgb10a2Unorm,
+        | Format::RGB10A2_UINT_PACK32     => TextureFormat::Rgb10a2Uint,
+        | Format::RG11B10_UFLOAT_PACK32   => TextureFormat::Rg11b10Ufloat,
+        | Format::RGB9E5_UFLOAT_PACK32    => TextureFormat::Rgb9e5Ufloat,
+        | Format::R16_UNORM_PACK16        => TextureFormat::R16Unorm,
+        | Format::R16_SNORM_PACK16        => TextureFormat::R16Snorm,
+        | Format::R16_UINT_PACK16         => TextureFormat::R16Uint,
+        | Format::R16_SINT_PACK16         => TextureFormat::R16Sint,
+        | Format::R16_SFLOAT_PACK16       => TextureFormat::R16Float,
+        | Format::RG16_UNORM_PACK16       => TextureFormat::Rg16Unorm,
+        | Format::RG16_SNORM_PACK16       => TextureFormat::Rg16Snorm,
+        | Format::RG16_UINT_PACK16        => TextureFormat::Rg16Uint,
+        | Format::RG16_SINT_PACK16        => TextureFormat::Rg16Sint,
+        | Format::RG16_SFLOAT_PACK16      => TextureFormat::Rg16Float,
+        | Format::RGBA16_UNORM_PACK16     => TextureFormat::Rgba16Unorm,
+        | Format::RGBA16_SNORM_PACK16     => TextureFormat::Rgba16Snorm,
+        | Format::RGBA16_UINT_PACK16      => TextureFormat::Rgba16Uint,
+        | Format::RGBA16_SINT_PACK16      => TextureFormat::Rgba16Sint,
+        | Format::RGBA16_SFLOAT_PACK16    => TextureFormat::Rgba16Float,
+        | Format::R32_UINT_PACK32         => TextureFormat::R32Uint,
+        | Format::R32_SINT_PACK32         => TextureFormat::R32Sint,
+        | Format::R32_SFLOAT_PACK32       => TextureFormat::R32Float,
+        | Format::RG32_UINT_PACK32        => TextureFormat::Rg32Uint,
+        | Format::RG32_SINT_PACK32        => TextureFormat::Rg32Sint,
+        | Format::RG32_SFLOAT_PACK32      => TextureFormat::Rg32Float,
+        | Format::RGBA32_UINT_PACK32      => TextureFormat::Rgba32Uint,
+        | Format::RGBA32_SINT_PACK32      => TextureFormat::Rgba32Sint,
+        | Format::RGBA32_SFLOAT_PACK32    => TextureFormat::Rgba32Float,
+        | Format::S8_UINT_PACK8           => TextureFormat::Stencil8,
+        | Format::D16_UNORM_PACK16        => TextureFormat::Depth16Unorm,
+        | Format::D32_SFLOAT_PACK32       => TextureFormat::Depth32Float,
+        | Format::D32_SFLOAT_S8_UINT_PACK64 => TextureFormat::Depth32FloatStencil8,
+
+        | Format::RGBA_DXT1_UNORM_BLOCK8  => TextureFormat::Bc1RgbaUnorm,
+        | Format::RGBA_DXT1_SRGB_BLOCK8   => TextureFormat::Bc1RgbaUnormSrgb,
+        | Format::RGBA_DXT3_UNORM_BLOCK16 => TextureFormat::Bc2RgbaUnorm,
+        | Format::RGBA_DXT3_SRGB_BLOCK16  => TextureFormat::Bc2RgbaUnormSrgb,
+        | Format::RGBA_DXT5_UNORM_BLOCK16 => TextureFormat::Bc3RgbaUnorm,
+        | Format::RGBA_DXT5_SRGB_BLOCK16  => TextureFormat::Bc3RgbaUnormSrgb,
+        | Format::R_ATI1N_UNORM_BLOCK8    => TextureFormat::Bc4RUnorm,
+        | Format::R_ATI1N_SNORM_BLOCK8    => TextureFormat::Bc4RSnorm,
+        | Format::RG_ATI2N_UNORM_BLOCK16  => TextureFormat::Bc5RgUnorm,
+        | Format::RG_ATI2N_SNORM_BLOCK16  => TextureFormat::Bc5RgSnorm,
+        | Format::RGB_BP_UFLOAT_BLOCK16   => TextureFormat::Bc6hRgbUfloat,
+        | Format::RGB_BP_SFLOAT_BLOCK16   => TextureFormat::Bc6hRgbFloat,
+        | Format::RGBA_BP_UNORM_BLOCK16   => TextureFormat::Bc7RgbaUnorm,
+        | Format::RGBA_BP_SRGB_BLOCK16    => TextureFormat::Bc7RgbaUnormSrgb,
+
+        | Format::RGB_ETC2_UNORM_BLOCK8   => TextureFormat::Etc2Rgb8Unorm,
+        | Format::RGB_ETC2_SRGB_BLOCK8    => TextureFormat::Etc2Rgb8UnormSrgb,
+        | Format::RGBA_ETC2_UNORM_BLOCK8  => TextureFormat::Etc2Rgb8A1Unorm,
+        | Format::RGBA_ETC2_SRGB_BLOCK8   => TextureFormat::Etc2Rgb8A1UnormSrgb,
+        | Format::RGBA_ETC2_UNORM_BLOCK16 => TextureFormat::Etc2Rgba8Unorm,
+        | Format::RGBA_ETC2_SRGB_BLOCK16  => TextureFormat::Etc2Rgba8UnormSrgb,
+        | Format::R_EAC_UNORM_BLOCK8      => TextureFormat::EacR11Unorm,
+        | Format::R_EAC_SNORM_BLOCK8      => TextureFormat::EacR11Snorm,
+        | Format::RG_EAC_UNORM_BLOCK16    => TextureFormat::EacRg11Unorm,
+        | Format::RG_EAC_SNORM_BLOCK16    => TextureFormat::EacRg11Snorm,
+
+        | Format::RGBA_ASTC_4X4_UNORM_BLOCK16   => astc(AstcBlock::B4x4,   false),
+        | Format::RGBA_ASTC_4X4_SRGB_BLOCK16    => astc(AstcBlock::B4x4,   true),
+        | Format::RGBA_ASTC_5X4_UNORM_BLOCK16   => astc(AstcBlock::B5x4,   false),
+        | Format::RGBA_ASTC_5X4_SRGB_BLOCK16    => astc(AstcBlock::B5x4,   true),
+        | Format::RGBA_ASTC_5X5_UNORM_BLOCK16   => astc(AstcBlock::B5x5,   false),
+        | Format::RGBA_ASTC_5X5_SRGB_BLOCK16    => astc(AstcBlock::B5x5,   true),
+        | Format::RGBA_ASTC_6X5_UNORM_BLOCK16   => astc(AstcBlock::B6x5,   false),
+        | Format::RGBA_ASTC_6X5_SRGB_BLOCK16    => astc(AstcBlock::B6x5,   true),
+        | Format::RGBA_ASTC_6X6_UNORM_BLOCK16   => astc(AstcBlock::B6x6,   false),
+        | Format::RGBA_ASTC_6X6_SRGB_BLOCK16    => astc(AstcBlock::B6x6,   true),
+        | Format::RGBA_ASTC_8X5_UNORM_BLOCK16   => astc(AstcBlock::B8x5,   false),
+        | Format::RGBA_ASTC_8X5_SRGB_BLOCK16    => astc(AstcBlock::B8x5,   true),
+        | Format::RGBA_ASTC_8X6_UNORM_BLOCK16   => astc(AstcBlock::B8x6,   false),
+        | Format::RGBA_ASTC_8X6_SRGB_BLOCK16    => astc(AstcBlock::B8x6,   true),
+        | Format::RGBA_ASTC_8X8_UNORM_BLOCK16   => astc(AstcBlock::B8x8,   false),
+        | Format::RGBA_ASTC_8X8_SRGB_BLOCK16    => astc(AstcBlock::B8x8,   true),
+        | Format::RGBA_ASTC_10X5_UNORM_BLOCK16  => astc(AstcBlock::B10x5,  false),
+        | Format::RGBA_ASTC_10X5_SRGB_BLOCK16   => astc(AstcBlock::B10x5,  true),
+        | Format::RGBA_ASTC_10X6_UNORM_BLOCK16  => astc(AstcBlock::B10x6,  false),
+        | Format::RGBA_ASTC_10X6_SRGB_BLOCK16   => astc(AstcBlock::B10x6,  true),
+        | Format::RGBA_ASTC_10X8_UNORM_BLOCK16  => astc(AstcBlock::B10x8,  false),
+        | Format::RGBA_ASTC_10X8_SRGB_BLOCK16   => astc(AstcBlock::B10x8,  true),
+        | Format::RGBA_ASTC_10X10_UNORM_BLOCK16 => astc(AstcBlock::B10x10, false),
+        | Format::RGBA_ASTC_10X10_SRGB_BLOCK16  => astc(AstcBlock::B10x10, true),
+        | Format::RGBA_ASTC_12X10_UNORM_BLOCK16 => astc(AstcBlock::B12x10, false),
+        | Format::RGBA_ASTC_12X10_SRGB_BLOCK16  => astc(AstcBlock::B12x10, true),
+        | Format::RGBA_ASTC_12X12_UNORM_BLOCK16 => astc(AstcBlock::B12x12, false),
+        | Format::RGBA_ASTC_12X12_SRGB_BLOCK16  => astc(AstcBlock::B12x12, true),
+
+        | _ => return None,
+    };
+
+    Some(wgpu_format)
+}
+
+/// Return the dimension of the texture and the dimension of a view over the whole texture for a gli target.
+///
+/// Return `None` for the 1d array targets, which wgpu does not support.
+pub fn texture_dimension(target: &Target) -> Option<(TextureDimension, TextureViewDimension)> {
+
+    let dimensions = match *target {
+        | Target::TARGET_1D         => (TextureDimension::D1, TextureViewDimension::D1),
+        | Target::TARGET_2D
+        | Target::TARGET_RECT       => (TextureDimension::D2, TextureViewDimension::D2),
+        | Target::TARGET_2D_ARRAY
+        | Target::TARGET_RECT_ARRAY => (TextureDimension::D2, TextureViewDimension::D2Array),
+        | Target::TARGET_3D         => (TextureDimension::D3, TextureViewDimension::D3),
+        | Target::TARGET_CUBE       => (TextureDimension::D2, TextureViewDimension::Cube),
+        | Target::TARGET_CUBE_ARRAY => (TextureDimension::D2, TextureViewDimension::CubeArray),
+        | _ => return None,
+    };
+
+    Some(dimensions)
+}
+
+/// Build the descriptor of a wgpu texture able to hold all the levels, layers and faces of `texture`.
+///
+/// The layers and faces are stored in the array layers of the wgpu texture, the faces of a layer being contiguous.
+/// Return an error if wgpu does not support the format or the target of the texture.
+pub fn texture_descriptor<'a, T: GliTexture>(texture: &T, label: Option<&'a str>, usage: TextureUsages) -> Result<TextureDescriptor<'a>> {
+
+    let format = texture.format();
+    let wgpu_format = texture_format(&format)
+        .ok_or_else(|| Error::unsupported_format(format!("{} has no equivalent wgpu texture format.", format)))?;
+    let target = texture.target();
+    let (dimension, _) = texture_dimension(&target)
+        .ok_or_else(|| Error::unsupported_format(format!("{} has no equivalent wgpu texture dimension.", target)))?;
+
+    let extent = level_extent(texture, 0);
+    let depth_or_array_layers = if target == Target::TARGET_3D {
+        extent[2]
+    } else {
+        (texture.layers() * texture.faces()) as u32
+    };
+
+    let descriptor = TextureDescriptor {
+        label,
+        size: WgpuExtent3d { width: extent[0], height: extent[1], depth_or_array_layers },
+        mip_level_count: texture.levels() as u32,
+        sample_count: 1,
+        dimension,
+        format: wgpu_format,
+        usage,
+        view_formats: &[],
+    };
+    Ok(descriptor)
+}
+
+/// The data of a single layer, face and level of a texture, with the information to upload it to a wgpu texture.
+#[derive(Debug, Clone)]
+pub struct Subresource<'t> {
+    /// The layer of the subresource in the gli texture.
+    pub layer: usize,
+    /// The face of the subresource in the gli texture.
+    pub face: usize,
+    /// The level of the subresource in the gli texture, which is also the mip level in the wgpu texture.
+    pub level: usize,
+    /// The origin of the subresource in the wgpu texture created from `texture_descriptor`.
+    pub origin: Origin3d,
+    /// The size of the copy in texels, rounded up to whole blocks for compressed formats.
+    pub size: WgpuExtent3d,
+    /// The layout of `data`.
+    pub layout: ImageDataLayout,
+    /// The tightly packed texel data of the subresource.
+    pub data: &'t [u8],
+}
+
+/// Enumerate all the subresources of `texture`, in the order of their layer, face and level.
+///
+/// The returned layouts are tightly packed, which suits `wgpu::Queue::write_texture`.
+/// Return an error if wgpu does not support the target of the texture.
+pub fn subresources<T: GliTexture>(texture: &T) -> Result<Vec<Subresource<'_>>> {
+
+    let target = texture.target();
+    if texture_dimension(&target).is_none() {
+        return Err(Error::unsupported_format(format!("{} has no equivalent wgpu texture dimension.", target)));
+    }
+
+    let format = texture.format();
+    let block_size = format.block_size() as u32;
+    let block_extent = format.block_extent();
+    let is_3d = target == Target::TARGET_3D;
+
+    let (layers, faces, levels) = (texture.layers(), texture.faces(), texture.levels());
+    let mut subresources = Vec::with_capacity(layers * faces * levels);
+
+    for layer in 0..layers {
+        for face in 0..faces {
+            for level in 0..levels {
+
+                let extent = level_extent(texture, level);
+                let blocks_x = extent[0].div_ceil(block_extent.width);
+                let blocks_y = extent[1].div_ceil(block_extent.height);
+
+                let size = WgpuExtent3d {
+                    width : blocks_x * block_extent.width,
+                    height: blocks_y * block_extent.height,
+                    depth_or_array_layers: if is_3d { extent[2] } else { 1 },
+                };
+                let origin = Origin3d {
+                    x: 0,
+                    y: 0,
+                    z: if is_3d { 0 } else { (layer * faces + face) as u32 },
+                };
+                let layout = ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(blocks_x * block_size),
+                    rows_per_image: Some(blocks_y),
+                };
+
+                let data = unsafe {
+                    slice::from_raw_parts(texture.data_detail(layer, face, level) as *const u8, texture.size_at_level(level))
+                };
+
+                subresources.push(Subresource { layer, face, level, origin, size, layout, data });
+            }
+        }
+    }
+
+    Ok(subresources)
+}
+
+/// Return the extent of a level of any kind of texture, as width, height and depth.
+fn level_extent<T: GliTexture>(texture: &T, level: usize) -> [u32; 3] {
+    let extent: glm::ivec3 = unsafe { texture_bindings::texture_extent(texture.raw_texture(), level) };
+    *extent
+}
@@ -0,0 +1,115 @@
+
+#[cfg(all(test, feature = "cli"))]
+mod cli {
+
+    extern crate gli_rs as gli;
+
+    use self::gli::{Format, Extent2d, Texture2D, GliTexture};
+
+    use std::path::PathBuf;
+    use std::process::{Command, Output};
+    use std::slice;
+
+    fn run_gli(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_gli"))
+            .args(args)
+            .output()
+            .expect("Failed to run the gli command line tool.")
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("gli-rs-cli-{}-{}", std::process::id(), name))
+    }
+
+    /// Save a RGBA8 texture whose texels are all `value` at a temporary path.
+    fn save_texture(name: &str, levels: usize, value: u8) -> String {
+
+        let mut texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, levels).unwrap();
+        unsafe {
+            slice::from_raw_parts_mut(texture.data_mut() as *mut u8, texture.size()).iter_mut().for_each(|b| *b = value);
+        }
+
+        let path = temp_path(name);
+        gli::save(&texture, &path).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn info_test() {
+
+        let input = save_texture("info.dds", 3, 0);
+        let output = run_gli(&["info", &input]);
+
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("Format: RGBA8_UNORM_PACK8"));
+        assert!(stdout.contains("Levels: 3"));
+    }
+
+    #[test]
+    fn extract_test() {
+
+        let input = save_texture("extract.dds", 3, 0);
+        let output_path = temp_path("extract-level.ktx");
+
+        let output = run_gli(&["extract", &input, output_path.to_str().unwrap(), "--level", "1"]);
+        assert!(output.status.success());
+
+        let level: Texture2D = gli::load(&output_path).unwrap();
+        assert_eq!(level.levels(), 1);
+        assert_eq!((level.extent(0).width, level.extent(0).height), (2, 2));
+
+        // The level does not exist, which is an error.
+        let output = run_gli(&["extract", &input, output_path.to_str().unwrap(), "--level", "3"]);
+        assert_eq!(output.status.code(), Some(2));
+    }
+
+    #[test]
+    fn diff_test() {
+
+        let first  = save_texture("diff-first.dds", 1, 10);
+        let second = save_texture("diff-second.dds", 1, 10);
+        let third  = save_texture("diff-third.dds", 1, 20);
+
+        assert_eq!(run_gli(&["diff", &first, &second]).status.code(), Some(0));
+        assert_eq!(run_gli(&["diff", &first, &third]).status.code(), Some(1));
+
+        let missing = temp_path("diff-missing.dds");
+        assert_eq!(run_gli(&["diff", &first, missing.to_str().unwrap()]).status.code(), Some(2));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn convert_test() {
+
+        let input = save_texture("convert.dds", 1, 51);
+        let output_path = temp_path("convert-r8.dds");
+
+        let output = run_gli(&["convert", &input, output_path.to_str().unwrap(), "--format", "R8_UNORM_PACK8"]);
+        assert!(output.status.success());
+
+        let converted: Texture2D = gli::load(&output_path).unwrap();
+        assert_eq!(converted.format(), Format::R8_UNORM_PACK8);
+        let bytes = unsafe { slice::from_raw_parts(converted.data() as *const u8, converted.size()) };
+        assert_eq!(bytes, &[51; 4 * 4][..]);
+
+        let output = run_gli(&["convert", &input, output_path.to_str().unwrap(), "--format", "RGBA_DXT1_UNORM_BLOCK8"]);
+        assert_eq!(output.status.code(), Some(2));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn mipgen_test() {
+
+        let input = save_texture("mipgen.dds", 1, 80);
+        let output_path = temp_path("mipgen-chain.dds");
+
+        let output = run_gli(&["mipgen", &input, output_path.to_str().unwrap(), "--filter", "nearest"]);
+        assert!(output.status.success());
+
+        let chain: Texture2D = gli::load(&output_path).unwrap();
+        assert_eq!(chain.levels(), 3);
+        let bytes = unsafe { slice::from_raw_parts(chain.data_detail(0, 0, 2) as *const u8, chain.size_at_level(2)) };
+        assert_eq!(bytes, &[80; 4][..]);
+    }
+}
@@ -0,0 +1,75 @@
+
+#[cfg(test)]
+mod dx {
+
+    extern crate gli_rs as gli;
+
+    use self::gli::{Format, Extent2d, Texture2DArray, GliTexture};
+    use self::gli::dx::PlacedFootprints;
+
+    use std::slice;
+
+    #[test]
+    fn placed_footprints_test() {
+
+        let mut texture = Texture2DArray::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 16, height: 4 }, 2, 2).unwrap();
+        for layer in 0..texture.layers() {
+            for level in 0..texture.levels() {
+                let size = texture.size_at_level(level);
+                let texels = unsafe { slice::from_raw_parts_mut(texture.data_detail_mut(layer, 0, level) as *mut u8, size) };
+                for (index, byte) in texels.iter_mut().enumerate() {
+                    *byte = ((layer * 2 + level) * 37 + index) as u8;
+                }
+            }
+        }
+        let footprints = PlacedFootprints::new(&texture);
+        assert_eq!(footprints.footprints.len(), 2 * 2);
+
+        // 16 RGBA8 texels are 64 bytes, padded to 256 bytes.
+        let first = &footprints.footprints[0];
+        assert_eq!((first.subresource, first.offset), (0, 0));
+        assert_eq!((first.row_size, first.row_pitch, first.num_rows), (64, 256, 4));
+
+        // Level 1 starts after 3 padded rows and an unpadded row, aligned to 512 bytes.
+        let second = &footprints.footprints[1];
+        assert_eq!((second.subresource, second.offset), (1, 1024));
+        assert_eq!((second.width, second.height, second.num_rows), (8, 2, 2));
+
+        let third = &footprints.footprints[2];
+        assert_eq!((third.subresource, third.offset), (2, 1536));
+
+        let last = footprints.footprints.last().unwrap();
+        assert_eq!(footprints.total_size, last.offset + 256 + 32);
+
+        let mut buffer = vec![0xff; footprints.total_size as usize];
+        footprints.copy_to(&texture, &mut buffer);
+
+        for (index, footprint) in footprints.footprints.iter().enumerate() {
+
+            let (layer, level) = (index / 2, index % 2);
+            let src = unsafe { slice::from_raw_parts(texture.data_detail(layer, 0, level) as *const u8, texture.size_at_level(level)) };
+            let (offset, row_size, row_pitch) = (footprint.offset as usize, footprint.row_size as usize, footprint.row_pitch as usize);
+
+            // Each row is placed at its pitch, and followed by untouched padding bytes, except the last row.
+            for row in 0..(footprint.num_rows as usize) {
+                let start = offset + row * row_pitch;
+                assert_eq!(&buffer[start..(start + row_size)], &src[(row * row_size)..((row + 1) * row_size)]);
+                if row + 1 < footprint.num_rows as usize {
+                    assert!(buffer[(start + row_size)..(start + row_pitch)].iter().all(|&byte| byte == 0xff));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn placed_footprints_compressed_test() {
+
+        let texture = Texture2DArray::new(Format::RGBA_DXT5_UNORM_BLOCK16, Extent2d { width: 6, height: 6 }, 1, 1).unwrap();
+        let footprints = PlacedFootprints::new(&texture);
+        assert_eq!(footprints.footprints.len(), texture.layers());
+
+        let footprint = &footprints.footprints[0];
+        assert_eq!((footprint.width, footprint.height), (8, 8));
+        assert_eq!((footprint.row_size, footprint.num_rows), (32, 2));
+    }
+}
@@ -11,4 +11,57 @@ mod format {
 
         assert!(test_format.is_compressed());
     }
+
+    #[test]
+    fn parse_format_name() {
+
+        let format: gli::Format = "RGBA8_UNORM_PACK8".parse().unwrap();
+        assert_eq!(format, gli::Format::RGBA8_UNORM_PACK8);
+        assert_eq!(format.to_string().parse::<gli::Format>().unwrap(), format);
+
+        assert!("RGBA8".parse::<gli::Format>().is_err());
+    }
+
+    #[test]
+    fn format_kind_test() {
+
+        use std::convert::TryFrom;
+        use self::gli::{Format, FormatKind};
+
+        assert_eq!(Format::all().count(), 209);
+        assert!(Format::all().all(|format| format.is_valid() && format.kind().is_some()));
+
+        for format in Format::all() {
+            assert_eq!(format.to_string().parse::<Format>().unwrap(), format);
+            assert_eq!(Format::try_from(u32::from(format.clone())).unwrap(), format);
+        }
+
+        assert_eq!(Format::RGB8_SSCALED_PACK8.to_string(), "RGB8_SSCALED_PACK8");
+        assert_eq!(Format::RGBA8_UNORM_PACK8.kind(), Some(FormatKind::RGBA8_UNORM_PACK8));
+        assert_eq!(Format::from(FormatKind::D24_UNORM_S8_UINT_PACK32), Format::D24_UNORM_S8_UINT_PACK32);
+        assert_eq!(Format::try_from(0).unwrap(), Format::UNDEFINED);
+
+        let unknown = u32::from(Format::LAST) + 1;
+        assert!(Format::try_from(unknown).is_err());
+    }
+
+    #[test]
+    fn view_compatibility_test() {
+
+        use self::gli::{Format, CompatibilityClass};
+
+        assert!(Format::RGBA8_UNORM_PACK8.is_view_compatible(&Format::RGBA8_SRGB_PACK8));
+        assert!(Format::RGBA8_UNORM_PACK8.is_view_compatible(&Format::R32_UINT_PACK32));
+        assert!(!Format::RGBA8_UNORM_PACK8.is_view_compatible(&Format::RG8_UNORM_PACK8));
+        assert!(!Format::R32_SFLOAT_PACK32.is_view_compatible(&Format::D32_SFLOAT_PACK32));
+
+        assert!(Format::RGBA_DXT5_UNORM_BLOCK16.is_view_compatible(&Format::RGBA_DXT5_SRGB_BLOCK16));
+        assert!(Format::RG_ATI2N_UNORM_BLOCK16.is_view_compatible(&Format::RG_ATI2N_SNORM_BLOCK16));
+        assert!(!Format::RGBA_DXT5_UNORM_BLOCK16.is_view_compatible(&Format::RGBA_DXT3_UNORM_BLOCK16));
+        assert!(!Format::RGBA_DXT5_UNORM_BLOCK16.is_view_compatible(&Format::RGBA32_UINT_PACK32));
+
+        assert_eq!(Format::RGB_BP_SFLOAT_BLOCK16.compatibility_class(), Some(CompatibilityClass::Compressed(Format::RGB_BP_UFLOAT_BLOCK16)));
+        assert_eq!(Format::RG16_UINT_PACK16.compatibility_class(), Some(CompatibilityClass::Bits(32)));
+        assert_eq!(Format::UNDEFINED.compatibility_class(), None);
+    }
 }
@@ -0,0 +1,124 @@
+
+#[cfg(test)]
+mod gl {
+
+    extern crate gli_rs as gli;
+
+    use self::gli::{Format, Extent2d, Texture2D, TextureCube, Texture2DArray};
+    use self::gli::gl::{GLConverter, GLProfile, GLUploadCommand, GLSubImageFormat};
+
+    #[test]
+    fn upload_cube_commands_test() {
+
+        let texture = TextureCube::new(Format::RGBA_DXT5_UNORM_BLOCK16, Extent2d { width: 8, height: 8 }, 2).unwrap();
+        let converter = GLConverter::new(GLProfile::GL33);
+        let commands = converter.upload_commands(&texture);
+
+        // Base level, max level, swizzles, storage, then one image per face and level.
+        assert_eq!(commands.len(), 4 + 6 * 2);
+
+        match commands[3] {
+            | GLUploadCommand::TexStorage { dimensions, levels, extent, .. } => {
+                assert_eq!(dimensions, 2);
+                assert_eq!(levels, 2);
+                assert_eq!(&extent[0..2], &[8, 8]);
+            },
+            | ref command => panic!("Expected the storage command, got {:?}", command),
+        }
+
+        match commands[4 + 2 * 2 + 1] {
+            | GLUploadCommand::TexSubImage(ref image) => {
+                // GL_TEXTURE_CUBE_MAP_POSITIVE_X + 2.
+                assert_eq!(image.target, 0x8515 + 2);
+                assert_eq!(image.level, 1);
+                assert_eq!(&image.extent[0..2], &[4, 4]);
+                match image.format {
+                    | GLSubImageFormat::Compressed { image_size, .. } => assert_eq!(image_size, 16),
+                    | format => panic!("Expected a compressed format, got {:?}", format),
+                }
+                assert_eq!(image.data.len(), 16);
+            },
+            | ref command => panic!("Expected a sub-image command, got {:?}", command),
+        }
+    }
+
+    #[test]
+    fn upload_array_commands_test() {
+
+        let texture = Texture2DArray::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 2 }, 3, 1).unwrap();
+        let converter = GLConverter::new(GLProfile::GL33);
+        let commands = converter.upload_commands(&texture);
+        assert_eq!(commands.len(), 4 + 3);
+
+        match commands[3] {
+            | GLUploadCommand::TexStorage { dimensions, extent, .. } => {
+                assert_eq!(dimensions, 3);
+                assert_eq!(extent, [4, 2, 3]);
+            },
+            | ref command => panic!("Expected the storage command, got {:?}", command),
+        }
+
+        match commands[4 + 2] {
+            | GLUploadCommand::TexSubImage(ref image) => {
+                assert_eq!(image.layer, 2);
+                assert_eq!(image.offset, [0, 0, 2]);
+                assert_eq!(image.extent, [4, 2, 1]);
+                assert!(match image.format { GLSubImageFormat::Uncompressed { .. } => true, _ => false });
+                assert_eq!(image.data.len(), 4 * 2 * 4);
+            },
+            | ref command => panic!("Expected a sub-image command, got {:?}", command),
+        }
+    }
+
+    #[test]
+    fn upload_legacy_commands_test() {
+
+        let texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 2).unwrap();
+        let converter = GLConverter::new(GLProfile::ES20);
+        let commands = converter.upload_commands(&texture);
+        // No level range, no swizzle and no storage, only an image call per level.
+        assert_eq!(commands.len(), 2);
+
+        match commands[1] {
+            | GLUploadCommand::TexImage { dimensions, level, extent, data, .. } => {
+                assert_eq!(dimensions, 2);
+                assert_eq!(level, 1);
+                assert_eq!(&extent[..2], &[2, 2]);
+                assert_eq!(data.map(|data| data.len()), Some(2 * 2 * 4));
+            },
+            | ref command => panic!("Expected an image command, got {:?}", command),
+        }
+    }
+
+    #[test]
+    fn upload_legacy_array_commands_test() {
+
+        let texture = Texture2DArray::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 2 }, 3, 2).unwrap();
+        let converter = GLConverter::new(GLProfile::GL32);
+        let commands = converter.upload_commands(&texture);
+        // The level range, an allocation per level, then a sub-image per layer and level.
+        assert_eq!(commands.len(), 2 + 2 + 3 * 2);
+        assert!(commands.iter().all(|command| match *command {
+            | GLUploadCommand::TexSwizzle { .. } | GLUploadCommand::TexStorage { .. } => false,
+            | _ => true,
+        }));
+
+        match commands[3] {
+            | GLUploadCommand::TexImage { level, extent, data, .. } => {
+                assert_eq!(level, 1);
+                assert_eq!(extent, [2, 1, 3]);
+                assert!(data.is_none());
+            },
+            | ref command => panic!("Expected an image command, got {:?}", command),
+        }
+
+        match commands[4 + 5] {
+            | GLUploadCommand::TexSubImage(ref image) => {
+                assert_eq!(image.layer, 2);
+                assert_eq!(image.level, 1);
+                assert_eq!(image.offset, [0, 0, 2]);
+            },
+            | ref command => panic!("Expected a sub-image command, got {:?}", command),
+        }
+    }
+}
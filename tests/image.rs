@@ -0,0 +1,68 @@
+
+#[cfg(all(test, feature = "image"))]
+mod image {
+
+    extern crate gli_rs as gli;
+    extern crate image;
+
+    use self::gli::{Format, Texture2D, GliTexture};
+    use self::image::{DynamicImage, RgbaImage, Rgba};
+
+    #[test]
+    fn dynamic_image_round_trip_test() {
+
+        let source = RgbaImage::from_fn(4, 2, |x, y| Rgba([x as u8 * 60, y as u8 * 100, 7, 255]));
+        let texture = Texture2D::from_image_buffer(source.clone(), Format::BGRA8_UNORM_PACK8).unwrap();
+        assert_eq!(texture.levels(), 1);
+        assert_eq!(texture.size(), 4 * 2 * 4);
+
+        let image = texture.get_level(0).unwrap().to_dynamic_image().unwrap();
+        assert_eq!(image.to_rgba8(), source);
+
+        let image = texture.to_dynamic_image(0, 0, 0).unwrap();
+        assert_eq!(image.to_rgba8(), source);
+    }
+
+    #[test]
+    fn dynamic_image_channels_test() {
+
+        let source = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([10, 20, 30, 40])));
+
+        let texture = Texture2D::from_dynamic_image(&source, Format::RG8_UNORM_PACK8).unwrap();
+        let image = texture.get_level(0).unwrap().to_dynamic_image().unwrap();
+        assert_eq!(image.to_rgb8().get_pixel(1, 1).0, [10, 20, 0]);
+
+        let texture = Texture2D::from_dynamic_image(&source, Format::RGBA32_SFLOAT_PACK32).unwrap();
+        let image = texture.get_level(0).unwrap().to_dynamic_image().unwrap();
+        assert_eq!(image.to_rgba8().get_pixel(0, 0).0, [10, 20, 30, 40]);
+
+        assert!(Texture2D::from_dynamic_image(&source, Format::RGBA_DXT1_UNORM_BLOCK8).is_err());
+    }
+
+    #[test]
+    fn dynamic_image_single_channel_test() {
+
+        let source = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([51, 20, 30, 40])));
+
+        let texture = Texture2D::from_dynamic_image(&source, Format::R8_UNORM_PACK8).unwrap();
+        match texture.get_level(0).unwrap().to_dynamic_image().unwrap() {
+            | DynamicImage::ImageLuma8(image) => assert_eq!(image.get_pixel(1, 1).0, [51]),
+            | image => panic!("Expected a Luma8 image, got {:?}", image.color()),
+        }
+
+        let texture = Texture2D::from_dynamic_image(&source, Format::R16_UNORM_PACK16).unwrap();
+        match texture.get_level(0).unwrap().to_dynamic_image().unwrap() {
+            | DynamicImage::ImageLuma16(image) => assert_eq!(image.get_pixel(1, 1).0, [51 * 257]),
+            | image => panic!("Expected a Luma16 image, got {:?}", image.color()),
+        }
+
+        // There is no gray float image, so the channel is repeated in an RGB float image.
+        let texture = Texture2D::from_dynamic_image(&source, Format::R32_SFLOAT_PACK32).unwrap();
+        match texture.get_level(0).unwrap().to_dynamic_image().unwrap() {
+            | DynamicImage::ImageRgb32F(image) => {
+                assert!(image.get_pixel(1, 1).0.iter().all(|&channel| (channel - 0.2).abs() < 1e-6));
+            },
+            | image => panic!("Expected a Rgb32F image, got {:?}", image.color()),
+        }
+    }
+}
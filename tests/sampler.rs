@@ -16,12 +16,12 @@ mod sampler {
         // the size of this dds is 256x256.
         const TEST_DDS_PATH: &'static str = "./vendors/gli/data/kueken7_bgra8_unorm.dds";
 
-        let texture_loaded: Texture2D = gli::load_dds(Path::new(TEST_DDS_PATH))
+        let mut texture_loaded: Texture2D = gli::load_dds(Path::new(TEST_DDS_PATH))
             .unwrap();
 
         assert_eq!(texture_loaded.levels(), 1);
 
-        let mut test_sampler = FSampler2D::new(&texture_loaded, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR);
+        let mut test_sampler = FSampler2D::new(&mut texture_loaded, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR);
 
         let test_fetch = test_sampler.texel_fetch(Extent2d { width: 0, height: 0 }, 0);
         assert_ne!(test_fetch, [0.0; 4]);
@@ -42,6 +42,267 @@ mod sampler {
         //assert_eq!(test_sampler.texel_fetch(Extent2d { width: 127, height: 127 }, 0), [0.0; 4]);
     }
 
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn sampler_cube_direction_test() {
+
+        use self::gli::{Format, TextureCube};
+        use self::gli::sampler::FSamplerCube;
+
+        const FACE_COLORS: [[f32; 4]; 6] = [
+            [1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0], [0.0, 0.0, 1.0, 1.0],
+            [1.0, 1.0, 0.0, 1.0], [0.0, 1.0, 1.0, 1.0], [1.0, 0.0, 1.0, 1.0],
+        ];
+
+        let mut texture = TextureCube::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        let mut sampler = FSamplerCube::new(&mut texture, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR);
+
+        for (face, color) in FACE_COLORS.iter().enumerate() {
+            for y in 0..4 {
+                for x in 0..4 {
+                    sampler.texel_write(Extent2d { width: x, height: y }, face, 0, *color);
+                }
+            }
+        }
+
+        let approx_eq = |a: [f32; 4], b: [f32; 4]| a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-5);
+
+        let directions = [[1.0, 0.0, 0.0], [-1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 1.0], [0.0, 0.0, -1.0]];
+        for (direction, color) in directions.iter().zip(FACE_COLORS.iter()) {
+            assert!(approx_eq(sampler.sample_dir(*direction, 0.0), *color));
+            assert!(approx_eq(sampler.sample_dir_seamless(*direction, 0.0), *color));
+        }
+
+        // Close to the edge between +X and +Z, the seamless filtering blends texels of both faces.
+        let near_edge = sampler.sample_dir_seamless([1.0, 0.0, 0.99], 0.0);
+        assert!(near_edge[0] > 0.0 && near_edge[0] < 1.0);
+        assert!(near_edge[1] > 0.0 && near_edge[1] < 1.0);
+        assert!(approx_eq(sampler.sample_dir([1.0, 0.0, 0.99], 0.0), FACE_COLORS[0]));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn sampler2d_gather_test() {
+
+        use self::gli::Format;
+
+        let mut texture = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 2, height: 2 }, 1).unwrap();
+        let mut sampler = FSampler2D::new(&mut texture, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, Filter::LINEAR);
+
+        sampler.texel_write(Extent2d { width: 0, height: 0 }, 0, [0.0, 10.0, 0.0, 1.0]);
+        sampler.texel_write(Extent2d { width: 1, height: 0 }, 0, [1.0, 11.0, 0.0, 1.0]);
+        sampler.texel_write(Extent2d { width: 0, height: 1 }, 0, [2.0, 12.0, 0.0, 1.0]);
+        sampler.texel_write(Extent2d { width: 1, height: 1 }, 0, [3.0, 13.0, 0.0, 1.0]);
+
+        assert_eq!(sampler.gather([0.5, 0.5].into(), 0), [2.0, 3.0, 1.0, 0.0]);
+        assert_eq!(sampler.gather([0.5, 0.5].into(), 1), [12.0, 13.0, 11.0, 10.0]);
+
+        // Texels outside the texture are clamped to the edge.
+        assert_eq!(sampler.gather([0.0, 0.0].into(), 0), [0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn sampler2d_shadow_test() {
+
+        use self::gli::Format;
+        use self::gli::sampler::{CompareFunc, FSampler2DShadow};
+
+        let mut texture = Texture2D::new(Format::D32_SFLOAT_PACK32, Extent2d { width: 2, height: 2 }, 1).unwrap();
+        {
+            let mut writer = FSampler2D::new(&mut texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+            writer.texel_write(Extent2d { width: 0, height: 0 }, 0, [0.25, 0.0, 0.0, 0.0]);
+            writer.texel_write(Extent2d { width: 1, height: 0 }, 0, [0.75, 0.0, 0.0, 0.0]);
+            writer.texel_write(Extent2d { width: 0, height: 1 }, 0, [0.75, 0.0, 0.0, 0.0]);
+            writer.texel_write(Extent2d { width: 1, height: 1 }, 0, [0.25, 0.0, 0.0, 0.0]);
+        }
+
+        let nearest = FSampler2DShadow::new(&texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, CompareFunc::Less).unwrap();
+        assert_eq!(nearest.texel_compare([0.25, 0.25].into(), 0.5, 0), 0.0);
+        assert_eq!(nearest.texel_compare([0.75, 0.25].into(), 0.5, 0), 1.0);
+
+        let linear = FSampler2DShadow::new(&texture, Wrap::CLAMP_TO_EDGE, Filter::LINEAR, CompareFunc::Less).unwrap();
+        assert!((linear.texel_compare([0.5, 0.5].into(), 0.5, 0) - 0.5).abs() < 1e-6);
+
+        let color = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 2, height: 2 }, 1).unwrap();
+        assert!(FSampler2DShadow::new(&color, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, CompareFunc::Less).is_err());
+    }
+
+    #[test]
+    fn sampler2d_shadow_formats_test() {
+
+        use std::slice;
+        use self::gli::Format;
+        use self::gli::sampler::{CompareFunc, FSampler2DShadow};
+
+        // Fill the texels of a 2x1 texture with raw bytes, and fetch their depth.
+        let depths = |format: Format, texels: &[u8]| {
+            let mut texture = Texture2D::new(format, Extent2d { width: 2, height: 1 }, 1).unwrap();
+            unsafe { slice::from_raw_parts_mut(texture.data_mut() as *mut u8, texels.len()).copy_from_slice(texels); }
+            let sampler = FSampler2DShadow::new(&texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, CompareFunc::Less).unwrap();
+            [sampler.depth_fetch(Extent2d { width: 0, height: 0 }, 0), sampler.depth_fetch(Extent2d { width: 1, height: 0 }, 0)]
+        };
+
+        let d16: Vec<u8> = [0_u16, 0xFFFF].iter().flat_map(|depth| depth.to_ne_bytes().to_vec()).collect();
+        assert_eq!(depths(Format::D16_UNORM_PACK16, &d16), [0.0, 1.0]);
+
+        // The stencil bits above the 24 depth bits are ignored.
+        let d24: Vec<u8> = [0xFF00_0000_u32, 0x00FF_FFFF].iter().flat_map(|texel| texel.to_ne_bytes().to_vec()).collect();
+        assert_eq!(depths(Format::D24_UNORM_PACK32, &d24), [0.0, 1.0]);
+        let d24s8: Vec<u8> = [0xAB80_0000_u32, 0xFFFF_FFFF].iter().flat_map(|texel| texel.to_ne_bytes().to_vec()).collect();
+        let [half, one] = depths(Format::D24_UNORM_S8_UINT_PACK32, &d24s8);
+        assert!((half - 0x80_0000 as f32 / 16_777_215.0).abs() < 1e-6);
+        assert_eq!(one, 1.0);
+
+        // The float depth is followed by the stencil in 8 bytes.
+        let d32s8: Vec<u8> = [0.75_f32, 0.25].iter().flat_map(|&depth| {
+            let mut texel = depth.to_ne_bytes().to_vec();
+            texel.extend_from_slice(&[0xFF, 0, 0, 0]);
+            texel
+        }).collect();
+        assert_eq!(depths(Format::D32_SFLOAT_S8_UINT_PACK64, &d32s8), [0.75, 0.25]);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn sampler2d_per_axis_wrap_test() {
+
+        use self::gli::Format;
+
+        let mut texture = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 2, height: 2 }, 2).unwrap();
+        let mut sampler = FSampler2D::with_wraps(&mut texture, [Wrap::REPEAT, Wrap::CLAMP_TO_BORDER], Filter::NEAREST, Filter::NEAREST);
+        sampler.set_border_color([1.0, 0.0, 0.0, 1.0]);
+
+        assert_eq!(sampler.wraps(), &[Wrap::REPEAT, Wrap::CLAMP_TO_BORDER]);
+
+        sampler.texel_write(Extent2d { width: 0, height: 0 }, 0, [0.0, 0.0, 0.0, 0.0]);
+        sampler.texel_write(Extent2d { width: 1, height: 0 }, 0, [0.0, 1.0, 0.0, 0.0]);
+        sampler.texel_write(Extent2d { width: 0, height: 1 }, 0, [0.0, 2.0, 0.0, 0.0]);
+        sampler.texel_write(Extent2d { width: 1, height: 1 }, 0, [0.0, 3.0, 0.0, 0.0]);
+
+        // The s axis repeats, while the t axis uses the border color.
+        assert_eq!(sampler.texel_lod([1.25, 0.25].into(), 0.0), [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(sampler.texel_lod([-0.25, 0.75].into(), 0.0), [0.0, 3.0, 0.0, 0.0]);
+        assert_eq!(sampler.texel_lod([0.25, 1.25].into(), 0.0), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(sampler.texel_lod([0.25, -0.25].into(), 0.0), [1.0, 0.0, 0.0, 1.0]);
+
+        sampler.generate_mipmaps(Filter::LINEAR);
+        let average = sampler.texel_fetch(Extent2d { width: 0, height: 0 }, 1);
+        assert!((average[1] - 1.5).abs() < 1e-5);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn sampler2d_uniform_wrap_test() {
+
+        use self::gli::Format;
+
+        let mut texture = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 4, height: 1 }, 1).unwrap();
+        {
+            let mut writer = FSampler2D::new(&mut texture, Wrap::REPEAT, Filter::NEAREST, Filter::NEAREST);
+            for x in 0..4 {
+                writer.texel_write(Extent2d { width: x, height: 0 }, 0, [0.0, x as f32, 0.0, 1.0]);
+            }
+        }
+
+        // The t axis has a single row, so uniform and mixed wrap modes must sample the same texels.
+        let uniform = FSampler2D::with_wraps(&texture, [Wrap::REPEAT, Wrap::REPEAT], Filter::LINEAR, Filter::LINEAR);
+        let mixed = FSampler2D::with_wraps(&texture, [Wrap::REPEAT, Wrap::CLAMP_TO_EDGE], Filter::LINEAR, Filter::LINEAR);
+
+        for &s in [0.0625_f32, 0.0, 0.5, 0.9375].iter() {
+            assert_eq!(uniform.texel_lod([s, 0.5].into(), 0.0), mixed.texel_lod([s, 0.5].into(), 0.0));
+        }
+
+        // Near s = 0, the footprint blends the texel 0 with the texel 3 wrapped around.
+        let texel = uniform.texel_lod([0.0625, 0.5].into(), 0.0);
+        assert!((texel[1] - (0.75 * 0.0 + 0.25 * 3.0)).abs() < 1e-5);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn sampler_cubic_filter_test() {
+
+        use self::gli::{Format, Texture1D, Extent1d};
+        use self::gli::sampler::FSampler1D;
+
+        let mut texture = Texture1D::new(Format::RGBA32_SFLOAT_PACK32, Extent1d { width: 4 }, 1).unwrap();
+        let mut writer = FSampler1D::new(&mut texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        for (x, value) in [0.0, 0.0, 1.0, 1.0].iter().enumerate() {
+            writer.texel_write(Extent1d { width: x as u32 }, 0, [*value, 2.0, 0.0, 1.0]);
+        }
+
+        let approx_eq = |a: f32, b: f32| (a - b).abs() < 1e-5;
+
+        // Catmull-Rom interpolates the texels at their centers.
+        let catmull_rom = FSampler1D::new(&texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::CATMULL_ROM);
+        assert!(approx_eq(catmull_rom.texel_lod(0.375.into(), 0.0)[0], 0.0));
+        assert!(approx_eq(catmull_rom.texel_lod(0.625.into(), 0.0)[0], 1.0));
+        assert!(approx_eq(catmull_rom.texel_lod(0.5.into(), 0.0)[0], 0.5));
+
+        // The B-spline smooths the texels, and keeps uniform regions unchanged.
+        let bspline = FSampler1D::new(&texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::CUBIC_BSPLINE);
+        let texel = bspline.texel_lod(0.375.into(), 0.0);
+        assert!(approx_eq(texel[0], 1.0 / 6.0));
+        assert!(approx_eq(texel[1], 2.0));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn sampler2d_batch_test() {
+
+        use self::gli::Format;
+        use self::gli::extent::NormalizeCoord2d;
+
+        let mut texture = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 16, height: 16 }, 1).unwrap();
+        let mut sampler = FSampler2D::new(&mut texture, Wrap::REPEAT, Filter::LINEAR, Filter::LINEAR);
+
+        for y in 0..16 {
+            for x in 0..16 {
+                sampler.texel_write(Extent2d { width: x, height: y }, 0, [x as f32, y as f32, 0.0, 1.0]);
+            }
+        }
+
+        // Enough coordinates to split the batch over several threads.
+        let texel_coords: Vec<Extent2d> = (0..10_000).map(|i| Extent2d { width: i % 16, height: i / 16 % 16 }).collect();
+        let mut texels = vec![[0.0; 4]; texel_coords.len()];
+        sampler.texel_fetch_many(&texel_coords, 0, &mut texels);
+        for (texel_coord, texel) in texel_coords.iter().zip(texels.iter()) {
+            assert_eq!(*texel, sampler.texel_fetch(*texel_coord, 0));
+        }
+
+        let sample_coords: Vec<NormalizeCoord2d> = (0..10_000).map(|i| NormalizeCoord2d { x: i as f32 / 3_000.0, y: i as f32 / 7_000.0 }).collect();
+        let mut samples = vec![[0.0; 4]; sample_coords.len()];
+        sampler.texel_lod_many(&sample_coords, 0.0, &mut samples);
+        for (sample_coord, sample) in sample_coords.iter().zip(samples.iter()) {
+            assert_eq!(*sample, sampler.texel_lod(*sample_coord, 0.0));
+        }
+    }
+
+    // The assertion of gli is reported to Rust instead of aborting, and the constructor panics.
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    #[should_panic(expected = "Failed to create the sampler")]
+    fn sampler2d_compressed_texture_test() {
+
+        use self::gli::Format;
+
+        let texture = Texture2D::new(Format::RGBA_DXT1_UNORM_BLOCK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        let _ = FSampler2D::new(&texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+    }
+
+    // Fetching outside the texture fails an assertion of gli, which panics like indexing a slice out of its bounds.
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    #[should_panic(expected = "The sampler failed")]
+    fn sampler2d_out_of_range_test() {
+
+        use self::gli::Format;
+
+        let texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        let sampler = FSampler2D::new(&texture, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        let _ = sampler.texel_fetch(Extent2d { width: 0, height: 0 }, 1);
+    }
+
     // Run this test by 'cargo test --features rc_debug -- --nocapture sampler2d_memory_test'
     #[cfg(feature = "rc_debug")]
     #[test]
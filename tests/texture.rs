@@ -38,6 +38,20 @@ mod texture {
             .unwrap();
     }
 
+    #[test]
+    fn load_any_texture() {
+
+        const TEST_DDS_PATH: &'static str = "./vendors/gli/data/array_r8_uint.dds";
+
+        let texture_loaded = gli::load_any(Path::new(TEST_DDS_PATH))
+            .unwrap();
+
+        match texture_loaded {
+            | gli::AnyTexture::Texture2DArray(ref texture) => print_texture_info(texture),
+            | _ => panic!("The texture is loaded with the type of another target: {}", texture_loaded.target()),
+        }
+    }
+
     #[test]
     fn load_and_save_ktx() {
 
@@ -80,7 +94,7 @@ mod texture {
 
         for _ in 0..10 {
             unsafe {
-                let base_level_image = texture_loaded.get_level(0);
+                let base_level_image = texture_loaded.get_level(0).unwrap();
                 let raw_texture = texture_loaded.raw_texture_mut();
 
                 // println!("Iter {}, count: {}", i, bindings::Texture::get_texture_shared_storage_count(raw_texture));
@@ -94,4 +108,714 @@ mod texture {
             assert_eq!(bindings::Texture::get_texture_shared_storage_count(raw_texture), 1);
         }
     }
+
+    #[test]
+    fn texture_builder_cube_test() {
+
+        use std::slice;
+        use self::gli::{TextureBuilder, Format, Extent2d, GliTexture};
+        use self::gli::sampler::Filter;
+
+        // Each face is filled with its own value, to check that it lands in its slot.
+        let value = |face: usize| (face * 40 + 10) as u8;
+        let faces: Vec<Texture2D> = (0..6)
+            .map(|face| {
+                let mut texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+                unsafe {
+                    slice::from_raw_parts_mut(texture.data_mut() as *mut u8, texture.size()).iter_mut().for_each(|byte| *byte = value(face));
+                }
+                texture
+            })
+            .collect();
+
+        let cube = faces.iter().enumerate()
+            .fold(TextureBuilder::new(), |builder, (face, texture)| builder.texture(0, face, texture))
+            .generate_mipmaps(Filter::LINEAR)
+            .build_cube()
+            .unwrap();
+
+        assert_eq!(cube.faces(), 6);
+        assert_eq!(cube.levels(), 3);
+
+        for face in 0..6 {
+            for level in 0..cube.levels() {
+                let texels = unsafe { slice::from_raw_parts(cube.data_detail(0, face, level) as *const u8, cube.size_at_level(level)) };
+                assert!(texels.iter().all(|&byte| byte == value(face)), "face {} level {}: {:?}", face, level, texels);
+            }
+        }
+
+        // A face is missing.
+        let missing_face = faces.iter().enumerate().skip(1)
+            .fold(TextureBuilder::new(), |builder, (face, texture)| builder.texture(0, face, texture))
+            .build_cube();
+        assert!(missing_face.is_err());
+    }
+
+    #[test]
+    fn texture_builder_validation_test() {
+
+        use self::gli::{TextureBuilder, Format, Extent2d, GliTexture};
+
+        let layer0 = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        let layer1 = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        let array = TextureBuilder::new().texture(0, 0, &layer0).texture(1, 0, &layer1).build_2d_array().unwrap();
+        assert_eq!(array.layers(), 2);
+        assert_eq!(array.levels(), 1);
+
+        let other_format = Texture2D::new(Format::RGBA16_UNORM_PACK16, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        assert!(TextureBuilder::new().texture(0, 0, &layer0).texture(1, 0, &other_format).build_2d_array().is_err());
+
+        let other_extent = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 8, height: 4 }, 1).unwrap();
+        assert!(TextureBuilder::new().texture(0, 0, &layer0).texture(1, 0, &other_extent).build_2d_array().is_err());
+
+        // The 1x1 level 2 of the 4x4 layer is given, but its 2x2 level 1 is missing and not generated.
+        let level1 = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 1, height: 1 }, 1).unwrap();
+        let level1 = level1.get_level(0).unwrap();
+        assert!(TextureBuilder::new().texture(0, 0, &layer0).image(0, 0, 2, &level1).build_2d_array().is_err());
+    }
+
+    #[test]
+    fn cube_layout_test() {
+
+        use self::gli::{CubeLayout, TextureCube, Format, Extent2d, GliTexture};
+
+        // A vertical cross of 2x2 faces, each texel storing its face index and its position in the face.
+        let mut cross = Texture2D::new(Format::R8_UNORM_PACK8, Extent2d { width: 6, height: 8 }, 1).unwrap();
+        let placements = [(2, 1), (0, 1), (1, 0), (1, 2), (1, 1), (1, 3)];
+        {
+            let data = unsafe { std::slice::from_raw_parts_mut(cross.data_mut() as *mut u8, cross.size()) };
+            for (face, &(column, row)) in placements.iter().enumerate() {
+                for y in 0..2 {
+                    for x in 0..2 {
+                        data[(row * 2 + y) * 6 + column * 2 + x] = (face * 10 + y * 2 + x) as u8;
+                    }
+                }
+            }
+        }
+
+        let cube = TextureCube::from_layout(&cross, CubeLayout::VerticalCross).unwrap();
+        assert_eq!(cube.extent(0).width, 2);
+
+        let face = |face: usize| unsafe { std::slice::from_raw_parts(cube.data_detail(0, face, 0) as *const u8, 4).to_vec() };
+        assert_eq!(face(0), vec![0, 1, 2, 3]);
+        assert_eq!(face(4), vec![40, 41, 42, 43]);
+        // The -Z face is rotated by 180 degrees in the vertical cross.
+        assert_eq!(face(5), vec![53, 52, 51, 50]);
+
+        let exported = cube.to_layout(CubeLayout::VerticalCross, 0).unwrap();
+        let exported_data = unsafe { std::slice::from_raw_parts(exported.data() as *const u8, exported.size()) };
+        let cross_data = unsafe { std::slice::from_raw_parts(cross.data() as *const u8, cross.size()) };
+        assert_eq!(exported_data, cross_data);
+
+        assert!(TextureCube::from_layout(&cross, CubeLayout::HorizontalCross).is_err());
+    }
+
+    #[test]
+    fn cube_equirectangular_test() {
+
+        use std::slice;
+        use std::f32::consts::PI;
+        use self::gli::{TextureCube, Format, Extent2d, GliTexture};
+        use self::gli::sampler::{FSampler2D, FSamplerCube, Wrap, Filter};
+
+        // The colors of the faces +X, -X, +Y, -Y, +Z and -Z.
+        let colors = [
+            [1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0, 1.0], [1.0, 1.0, 0.0, 1.0],
+            [0.0, 1.0, 1.0, 1.0], [1.0, 0.0, 1.0, 1.0],
+        ];
+        let assert_color = |texel: [f32; 4], face: usize, at: &str| {
+            for (c, expected) in texel.iter().zip(colors[face].iter()) {
+                assert!((c - expected).abs() < 1e-5, "{}: {:?} is not the color of the face {}", at, texel, face);
+            }
+        };
+
+        // Each texel of the panorama has the color of the face its direction points at, with -Z at the center,
+        // +X at three quarters of the width and +Y at the top.
+        let mut panorama = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 32, height: 16 }, 1).unwrap();
+        unsafe {
+            let texels = slice::from_raw_parts_mut(panorama.data_mut() as *mut [f32; 4], 32 * 16);
+            for (index, texel) in texels.iter_mut().enumerate() {
+                let longitude = (((index % 32) as f32 + 0.5) / 32.0 - 0.5) * 2.0 * PI;
+                let latitude = ((index / 32) as f32 + 0.5) / 16.0 * PI;
+                let direction = [latitude.sin() * longitude.sin(), latitude.cos(), -latitude.sin() * longitude.cos()];
+
+                let axis = (0..3).max_by(|&a, &b| direction[a].abs().partial_cmp(&direction[b].abs()).unwrap()).unwrap();
+                *texel = colors[axis * 2 + if direction[axis] >= 0.0 { 0 } else { 1 }];
+            }
+        }
+
+        let cube = TextureCube::from_equirectangular(&panorama, 4).unwrap();
+        assert_eq!(cube.faces(), 6);
+        assert_eq!(cube.extent(0).width, 4);
+
+        // The texels around the center of each face are far from the edges of its region in the panorama.
+        let faces = FSamplerCube::new(&cube, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        for face in 0..6 {
+            for &(x, y) in [(1, 1), (2, 1), (1, 2), (2, 2)].iter() {
+                assert_color(faces.texel_fetch(Extent2d { width: x, height: y }, face, 0), face, &format!("face {} ({}, {})", face, x, y));
+            }
+        }
+
+        let back = cube.to_equirectangular(Extent2d { width: 32, height: 8 }, 0).unwrap();
+        let sampler = FSampler2D::new(&back, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        for &(x, y, face) in [(24, 4, 0), (8, 4, 1), (5, 0, 2), (5, 7, 3), (0, 4, 4), (31, 4, 4), (16, 4, 5)].iter() {
+            assert_color(sampler.texel_fetch(Extent2d { width: x, height: y }, 0), face, &format!("panorama ({}, {})", x, y));
+        }
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn image_based_lighting_test() {
+
+        use self::gli::{TextureCube, Format, Extent2d, GliTexture};
+        use self::gli::sampler::{FSamplerCube, Wrap, Filter};
+
+        // A uniform environment reflects the same radiance in every direction and for every roughness.
+        let radiance = [0.5, 1.0, 2.0, 1.0];
+        let mut environment = TextureCube::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 8, height: 8 }, 1).unwrap();
+        FSamplerCube::new(&mut environment, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).clear(radiance);
+
+        let sh = environment.spherical_harmonics().unwrap();
+        let irradiance = sh.irradiance([0.3, -0.5, 0.8]);
+        for channel in 0..3 {
+            assert!((irradiance[channel] - radiance[channel]).abs() < 1e-2 * radiance[channel]);
+        }
+
+        let diffuse = environment.irradiance(4, 64).unwrap();
+        assert_eq!(diffuse.levels(), 1);
+        let texel = FSamplerCube::new(&diffuse, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).texel_fetch(Extent2d { width: 1, height: 2 }, 3, 0);
+        for channel in 0..3 {
+            assert!((texel[channel] - radiance[channel]).abs() < 1e-3);
+        }
+
+        let specular = environment.prefilter_specular(8, 4, 32).unwrap();
+        assert_eq!(specular.levels(), 4);
+        let sampler = FSamplerCube::new(&specular, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        for level in 0..4 {
+            let texel = sampler.texel_fetch(Extent2d { width: 0, height: 0 }, 5, level);
+            for channel in 0..3 {
+                assert!((texel[channel] - radiance[channel]).abs() < 1e-3);
+            }
+        }
+
+        assert!(environment.prefilter_specular(8, 5, 32).is_err());
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn directional_image_based_lighting_test() {
+
+        use self::gli::{TextureCube, Format, Extent2d};
+        use self::gli::sampler::{FSamplerCube, Wrap, Filter};
+
+        // Only the +X face is lit, so the lighting must come from +X and vanish towards -X.
+        let radiance = [1.0, 0.5, 0.25, 1.0];
+        let mut environment = TextureCube::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 8, height: 8 }, 1).unwrap();
+        let mut sampler = FSamplerCube::new(&mut environment, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        sampler.clear([0.0; 4]);
+        for y in 0..8 {
+            for x in 0..8 {
+                sampler.texel_write(Extent2d { width: x, height: y }, 0, 0, radiance);
+            }
+        }
+        drop(sampler);
+
+        let assert_near = |value: f32, expected: f32, tolerance: f32, what: &str| {
+            assert!((value - expected).abs() <= tolerance, "{}: {} is not {} ± {}", what, value, expected, tolerance);
+        };
+
+        // The first band points along +X, and the second band is symmetric around the X axis.
+        const EXPECTED_SH: [f32; 9] = [0.59082, 0.0, 0.0, 0.85029, 0.0, 0.0, -0.36351, 0.0, 0.62962];
+        let sh = environment.spherical_harmonics().unwrap();
+        for (index, (coefficient, expected)) in sh.coefficients.iter().zip(EXPECTED_SH.iter()).enumerate() {
+            for channel in 0..3 {
+                assert_near(coefficient[channel], expected * radiance[channel], 1e-3, &format!("SH coefficient {}", index));
+            }
+        }
+
+        let positive_x = sh.irradiance([1.0, 0.0, 0.0]);
+        let negative_x = sh.irradiance([-1.0, 0.0, 0.0]);
+        let positive_y = sh.irradiance([0.0, 1.0, 0.0]);
+        for channel in 0..3 {
+            assert_near(positive_x[channel], 0.55829 * radiance[channel], 1e-3, "SH irradiance +X");
+            assert!(negative_x[channel] < 1e-2, "SH irradiance -X: {}", negative_x[channel]);
+            assert_near(positive_y[channel], 0.10934 * radiance[channel], 1e-3, "SH irradiance +Y");
+        }
+
+        let diffuse = environment.irradiance(1, 256).unwrap();
+        let sampler = FSamplerCube::new(&diffuse, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        let origin = Extent2d { width: 0, height: 0 };
+        let (positive_x, negative_x, positive_y) = (sampler.texel_fetch(origin, 0, 0), sampler.texel_fetch(origin, 1, 0), sampler.texel_fetch(origin, 2, 0));
+        for channel in 0..3 {
+            assert_near(positive_x[channel], 0.554 * radiance[channel], 0.05, "irradiance +X");
+            assert!(negative_x[channel] < 1e-3, "irradiance -X: {}", negative_x[channel]);
+            assert_near(positive_y[channel], 0.111 * radiance[channel], 0.04, "irradiance +Y");
+        }
+
+        let specular = environment.prefilter_specular(4, 3, 64).unwrap();
+        let sampler = FSamplerCube::new(&specular, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        let center = Extent2d { width: 1, height: 1 };
+        for channel in 0..3 {
+            // The mirror level only reflects the +X face, the roughest level spreads it towards +Y.
+            assert_near(sampler.texel_fetch(center, 0, 0)[channel], radiance[channel], 1e-4, "specular +X, level 0");
+            assert_near(sampler.texel_fetch(center, 2, 0)[channel], 0.0, 1e-4, "specular +Y, level 0");
+
+            let (positive_x, positive_y) = (sampler.texel_fetch(origin, 0, 2)[channel], sampler.texel_fetch(origin, 2, 2)[channel]);
+            assert!(positive_x > 0.3 * radiance[channel] && positive_x < 0.95 * radiance[channel], "specular +X, level 2: {}", positive_x);
+            assert!(positive_y > 0.02 * radiance[channel] && positive_y < 0.5 * radiance[channel], "specular +Y, level 2: {}", positive_y);
+
+            for level in 0..3 {
+                let negative_x = sampler.texel_fetch(origin, 1, level)[channel];
+                assert!(negative_x < 1e-3, "specular -X, level {}: {}", level, negative_x);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn compare_test() {
+
+        use self::gli::{Format, Extent2d, GliTexture};
+        use self::gli::sampler::{FSampler2D, Wrap, Filter};
+
+        let mut unorm = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 16, height: 16 }, 1).unwrap();
+        let mut float = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 16, height: 16 }, 1).unwrap();
+        FSampler2D::new(&mut unorm, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).clear([0.0, 1.0, 0.3, 1.0]);
+        FSampler2D::new(&mut float, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).clear([0.0, 1.0, 0.3, 1.0]);
+
+        // 0.3 is not exactly representable in 8 bits.
+        let report = gli::compare(&unorm, &float).unwrap();
+        assert_eq!(report.subresources.len(), 1);
+        assert!(report.max_abs_error() > 0.0 && report.max_abs_error() < 1.0 / 255.0);
+        assert_eq!(report.subresources[0].channels[1].psnr, std::f32::INFINITY);
+        assert!(report.min_ssim() > 0.99);
+
+        assert!(unorm.approx_eq(&float, 1.0 / 255.0));
+        assert!(!unorm.approx_eq(&float, 0.0));
+
+        FSampler2D::new(&mut float, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).texel_write(Extent2d { width: 3, height: 5 }, 0, [0.5, 1.0, 0.3, 1.0]);
+        let report = gli::compare(&unorm, &float).unwrap();
+        assert_eq!(report.subresources[0].channels[0].max_abs_error, 0.5);
+        assert!(report.subresources[0].channels[0].rmse > 0.0);
+        assert!(report.subresources[0].channels[0].ssim < 1.0);
+
+        let smaller = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 8, height: 16 }, 1).unwrap();
+        assert!(gli::compare(&unorm, &smaller).is_err());
+        assert!(!unorm.approx_eq(&smaller, 1.0));
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn compare_compressed_test() {
+
+        use std::slice;
+        use self::gli::{Format, Extent2d, GliTexture};
+        use self::gli::sampler::{FSampler2D, Wrap, Filter};
+
+        // A DXT1 block whose colors are red and blue, with the texel (1, 0) blue and the others red.
+        let mut dxt1 = Texture2D::new(Format::RGBA_DXT1_UNORM_BLOCK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        unsafe {
+            slice::from_raw_parts_mut(dxt1.data_mut() as *mut u8, 8).copy_from_slice(&[0x00, 0xf8, 0x1f, 0x00, 0x04, 0x00, 0x00, 0x00]);
+        }
+
+        let mut rgba8 = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        FSampler2D::new(&mut rgba8, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).clear([1.0, 0.0, 0.0, 1.0]);
+
+        let report = gli::compare(&dxt1, &rgba8).unwrap();
+        assert_eq!(report.subresources[0].channels[0].max_abs_error, 1.0);
+        assert_eq!(report.subresources[0].channels[1].max_abs_error, 0.0);
+        assert_eq!(report.subresources[0].channels[2].max_abs_error, 1.0);
+        assert!(!dxt1.approx_eq(&rgba8, 0.5));
+
+        FSampler2D::new(&mut rgba8, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).texel_write(Extent2d { width: 1, height: 0 }, 0, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(gli::compare(&dxt1, &rgba8).unwrap().max_abs_error(), 0.0);
+        assert!(dxt1.approx_eq(&rgba8, 0.0));
+
+        // The formats which gli can not decode return an error instead of comparing garbage.
+        let etc2 = Texture2D::new(Format::RGB_ETC2_UNORM_BLOCK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        assert!(gli::compare(&etc2, &rgba8).is_err());
+        assert!(!etc2.approx_eq(&rgba8, 1.0));
+    }
+
+    #[test]
+    fn shared_storage_test() {
+
+        use self::gli::{Format, Extent2d, GliTexture};
+
+        let mut texture = Texture2D::new(Format::R8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 3).unwrap();
+        texture.clear();
+        assert_eq!(texture.shared_count(), 1);
+
+        let mut view = Texture2D::share_from_subset(&texture, 1, 2).unwrap();
+        assert!(texture.is_shared());
+        assert_eq!(view.shared_count(), 2);
+
+        let copy = view.clone();
+        assert!(!copy.is_shared());
+        assert_eq!(copy.levels(), 2);
+        assert!(copy == view);
+
+        // Writing through the view gives it its own storage, and leaves the source texture untouched.
+        unsafe { *(view.data_mut() as *mut u8) = 0xFF; }
+        assert!(!view.is_shared());
+        assert!(!texture.is_shared());
+        assert_eq!(unsafe { *(texture.data_detail(0, 0, 1) as *const u8) }, 0);
+        assert_eq!(unsafe { *(view.data() as *const u8) }, 0xFF);
+
+        let mut level = texture.get_level(0).unwrap();
+        assert!(level.is_shared());
+        assert!(texture.is_shared());
+        unsafe { *(level.data_mut() as *mut u8) = 0xFF; }
+        assert!(!level.is_shared());
+        assert_eq!(unsafe { *(texture.data() as *const u8) }, 0);
+        assert!(level.clone() == level);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "windows"))]
+    fn shared_storage_sampler_test() {
+
+        use self::gli::{Format, Extent2d, GliTexture};
+        use self::gli::sampler::{Wrap, Filter, FSampler2D};
+
+        let mut texture = Texture2D::new(Format::R8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 3).unwrap();
+        texture.clear();
+
+        // A sampler created from a mutable reference detaches the view before writing to it.
+        let mut view = Texture2D::share_from_subset(&texture, 1, 2).unwrap();
+        FSampler2D::new(&mut view, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST).clear([1.0; 4]);
+        assert!(!view.is_shared());
+        assert!(!texture.is_shared());
+        assert_eq!(unsafe { *(texture.data_detail(0, 0, 1) as *const u8) }, 0);
+        assert_eq!(unsafe { *(view.data() as *const u8) }, 0xFF);
+
+        // A sampler created from a shared reference only reads, and keeps sharing the storage.
+        let view = Texture2D::share_from_subset(&texture, 1, 2).unwrap();
+        let sampler = FSampler2D::new(&view, Wrap::CLAMP_TO_EDGE, Filter::NEAREST, Filter::NEAREST);
+        assert_eq!(sampler.texel_fetch(Extent2d { width: 0, height: 0 }, 0)[0], 0.0);
+        assert!(view.is_shared());
+    }
+
+    #[test]
+    fn unique_texture_thread_test() {
+
+        use self::gli::{Format, Extent2d, GliTexture, GliImage, Unique, AnyTexture};
+
+        fn assert_send<T: Send>() {}
+        assert_send::<Unique<Texture2D>>();
+        assert_send::<Unique<GliImage>>();
+        assert_send::<Unique<AnyTexture>>();
+
+        let mut texture = Texture2D::new(Format::R8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        texture.clear();
+
+        // Wrapping a view copies its storage, so the view can be modified on another thread.
+        let view = Unique::new(Texture2D::share_from(&texture));
+        let handles: Vec<_> = (0..4_u8).map(|i| {
+            let view = Unique::new(Texture2D::share_from(&texture));
+            std::thread::spawn(move || {
+                let mut view = view.into_inner();
+                assert!(!view.is_shared());
+                unsafe { *(view.data_mut() as *mut u8) = i + 1; }
+                Unique::new(view)
+            })
+        }).collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            let view = handle.join().unwrap().into_inner();
+            assert_eq!(unsafe { *(view.data() as *const u8) }, i as u8 + 1);
+        }
+
+        assert!(!texture.is_shared());
+        assert_eq!(unsafe { *(texture.data() as *const u8) }, 0);
+        assert!(view.into_inner() == texture);
+    }
+
+    #[test]
+    fn texture_validation_test() {
+
+        use self::gli::{Format, Extent2d, Extent3d, TextureCube};
+
+        let mut texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 3).unwrap();
+        texture.clear();
+
+        assert!(texture.get_level(2).is_ok());
+        assert!(texture.get_level(3).is_err());
+        assert!(Texture2D::share_from_subset(&texture, 1, 3).is_err());
+
+        // The blocks of RGBA8 and R32 have the same size, the blocks of RGBA8 and RG8 do not.
+        assert!(Texture2D::share_from_detail(&texture, Format::R32_UINT_PACK32, 0, 0, 0, 0, 0, 2).is_ok());
+        assert!(Texture2D::share_from_detail(&texture, Format::RG8_UNORM_PACK8, 0, 0, 0, 0, 0, 2).is_err());
+        assert!(Texture2D::share_from_detail(&texture, Format::RGBA8_UNORM_PACK8, 0, 0, 0, 0, 2, 1).is_err());
+
+        let cube = TextureCube::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        assert!(TextureCube::new_detail(&cube, Format::RGBA8_UNORM_PACK8, 0, 0, 0, 5, 0, 0).is_ok());
+        assert!(TextureCube::new_detail(&cube, Format::RGBA8_UNORM_PACK8, 0, 0, 0, 4, 0, 0).is_err());
+        assert!(Texture2D::share_from_detail(&cube, Format::RGBA8_UNORM_PACK8, 0, 0, 0, 5, 0, 0).is_err());
+
+        let mut other = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 3).unwrap();
+        let offset = Extent3d { width: 2, height: 2, depth: 0 };
+        assert!(other.copy_subset(&texture, 0, 0, 0, offset, 0, 0, 0, offset, Extent3d { width: 2, height: 2, depth: 1 }).is_ok());
+        assert!(other.copy_subset(&texture, 0, 0, 0, offset, 0, 0, 0, offset, Extent3d { width: 4, height: 2, depth: 1 }).is_err());
+        assert!(other.copy_subset(&texture, 0, 0, 1, offset, 0, 0, 1, offset, Extent3d { width: 2, height: 2, depth: 1 }).is_err());
+        assert!(other.copy(&texture, 0, 0, 3, 0, 0, 3).is_err());
+
+        let small = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 2, height: 2 }, 1).unwrap();
+        assert!(other.copy(&small, 0, 0, 0, 0, 0, 0).is_err());
+        assert!(other.copy(&small, 0, 0, 0, 0, 0, 1).is_ok());
+    }
+
+    #[test]
+    fn texture_allocation_test() {
+
+        use self::gli::{Format, Extent2d, ErrorKind, Texture2DArray, GliImage, Extent3d};
+
+        let format = Format::RGBA32_SFLOAT_PACK32;
+
+        assert!(Texture2D::new(format.clone(), Extent2d { width: 4, height: 4 }, 3).is_ok());
+        assert!(Texture2D::new(format.clone(), Extent2d { width: 4, height: 4 }, 0).is_err());
+        assert!(Texture2D::new(format.clone(), Extent2d { width: 4, height: 4 }, 4).is_err());
+        assert!(Texture2D::new(format.clone(), Extent2d { width: 0, height: 4 }, 1).is_err());
+
+        // The extents are signed in gli.
+        let error = Texture2D::new(format.clone(), Extent2d { width: u32::MAX, height: 1 }, 1).err().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::InvalidArgument(_)));
+
+        // The size of the storage does not fit in memory.
+        let error = Texture2D::new_with_mipmap_chain(format.clone(), Extent2d { width: 1 << 30, height: 1 << 30 }).err().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::OutOfMemory(_)));
+        let error = Texture2DArray::new(format.clone(), Extent2d { width: 1024, height: 1024 }, usize::MAX / 2, 1).err().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::OutOfMemory(_)));
+        assert!(GliImage::new(format, Extent3d { width: 1 << 30, height: 1 << 30, depth: 1 << 30 }).is_err());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn non_utf8_path_test() {
+
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use self::gli::{Format, Extent2d, ErrorKind, TexFormatType};
+
+        let texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+
+        let path = std::env::temp_dir().join(OsStr::from_bytes(b"gli-\xff.dds"));
+        gli::save(&texture, &path).unwrap();
+        let texture_loaded: Texture2D = gli::load(&path).unwrap();
+        assert!(texture == texture_loaded);
+        std::fs::remove_file(&path).unwrap();
+
+        // The error carries the path of the missing file.
+        let error = gli::load::<Texture2D>(&path).err().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::Path(ref missing) if *missing == path));
+
+        let bytes = gli::save_to_memory(&texture, TexFormatType::KTX).unwrap();
+        let texture_loaded: Texture2D = gli::load_from_memory(&bytes, TexFormatType::KTX).unwrap();
+        assert!(texture == texture_loaded);
+    }
+
+    #[test]
+    fn view_as_test() {
+
+        use self::gli::{Format, Extent2d, Extent3d, GliImage, ErrorKind};
+
+        let mut texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        texture.clear();
+
+        let view = texture.view_as(Format::RGBA8_SRGB_PACK8).unwrap();
+        assert_eq!(view.format(), Format::RGBA8_SRGB_PACK8);
+        assert_eq!(view.levels(), texture.levels());
+        assert!(view.is_shared());
+
+        let error = texture.view_as(Format::RGB_DXT1_UNORM_BLOCK8).err().unwrap();
+        assert!(matches!(error.kind(), ErrorKind::UnsupportedFormat(_)));
+        assert!(Texture2D::new_empty().view_as(Format::RGBA8_SRGB_PACK8).is_err());
+
+        let image = GliImage::new(Format::RGBA8_UNORM_PACK8, Extent3d { width: 4, height: 4, depth: 1 }).unwrap();
+        assert!(GliImage::share_from(&image, Format::R32_UINT_PACK32).is_ok());
+        assert!(GliImage::share_from(&image, Format::R16_UINT_PACK16).is_err());
+    }
+
+    #[test]
+    fn bake_swizzles_test() {
+
+        use std::slice;
+        use self::gli::{Format, Extent2d, Swizzle};
+
+        let identity = [Swizzle::RED.into(), Swizzle::GREEN.into(), Swizzle::BLUE.into(), Swizzle::ALPHA.into()];
+
+        let mut texture = Texture2D::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 2, height: 1 }, 1).unwrap();
+        unsafe {
+            slice::from_raw_parts_mut(texture.data_mut() as *mut u8, 8).copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+        assert_eq!(texture.swizzles(), identity);
+
+        texture.set_swizzles([Swizzle::BLUE.into(), Swizzle::RED.into(), Swizzle::ZERO.into(), Swizzle::ONE.into()]);
+        texture.bake_swizzles().unwrap();
+
+        let texels = unsafe { slice::from_raw_parts(texture.data() as *const u8, 8) };
+        assert_eq!(texels, &[3, 1, 0, 255, 7, 5, 0, 255]);
+        assert_eq!(texture.swizzles(), identity);
+
+        // The channels of a packed format have different sizes.
+        let mut texture = Texture2D::new(Format::R5G6B5_UNORM_PACK16, Extent2d { width: 1, height: 1 }, 1).unwrap();
+        unsafe {
+            *(texture.data_mut() as *mut u16) = 0b11111_000000_00000;
+        }
+        texture.set_swizzles([Swizzle::ZERO.into(), Swizzle::RED.into(), Swizzle::ONE.into(), Swizzle::ALPHA.into()]);
+        texture.bake_swizzles().unwrap();
+        assert_eq!(unsafe { *(texture.data() as *const u16) }, 0b00000_111111_11111);
+
+        // The half floats are not converted to the one of the integer formats.
+        let mut texture = Texture2D::new(Format::RG16_SFLOAT_PACK16, Extent2d { width: 1, height: 1 }, 1).unwrap();
+        texture.set_swizzles([Swizzle::ONE.into(), Swizzle::ZERO.into(), Swizzle::BLUE.into(), Swizzle::ALPHA.into()]);
+        texture.bake_swizzles().unwrap();
+        assert_eq!(unsafe { slice::from_raw_parts(texture.data() as *const u16, 2) }, &[0x3c00, 0]);
+
+        let mut texture = Texture2D::new(Format::RGBA_DXT5_UNORM_BLOCK16, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        texture.set_swizzles([Swizzle::ALPHA.into(), Swizzle::GREEN.into(), Swizzle::BLUE.into(), Swizzle::RED.into()]);
+        assert!(texture.bake_swizzles().is_err());
+
+        // The grayscale swizzles of a red format can not be stored in its texels, which are left unchanged.
+        let mut texture = Texture2D::new(Format::R8_UNORM_PACK8, Extent2d { width: 1, height: 1 }, 1).unwrap();
+        unsafe { *(texture.data_mut() as *mut u8) = 100; }
+        let grayscale = [Swizzle::RED.into(), Swizzle::RED.into(), Swizzle::RED.into(), Swizzle::ONE.into()];
+        texture.set_swizzles(grayscale);
+        assert!(texture.bake_swizzles().is_err());
+        assert_eq!(texture.swizzles(), grayscale);
+        assert_eq!(unsafe { *(texture.data() as *const u8) }, 100);
+
+        // The swizzles which select what the format already reads in its missing channels can be baked.
+        texture.set_swizzles([Swizzle::RED.into(), Swizzle::ZERO.into(), Swizzle::GREEN.into(), Swizzle::ONE.into()]);
+        texture.bake_swizzles().unwrap();
+        assert_eq!(texture.swizzles(), identity);
+        assert_eq!(unsafe { *(texture.data() as *const u8) }, 100);
+    }
+
+    #[test]
+    fn color_transform_test() {
+
+        use std::slice;
+        use self::gli::{Format, Extent2d};
+
+        assert_eq!(Format::RGBA8_UNORM_PACK8.to_srgb(), Some(Format::RGBA8_SRGB_PACK8));
+        assert_eq!(Format::BGR8_SRGB_PACK32.to_linear(), Some(Format::BGR8_UNORM_PACK32));
+        assert_eq!(Format::RGBA16_UNORM_PACK16.to_srgb(), None);
+
+        let mut texture = Texture2D::new(Format::RGBA8_SRGB_PACK8, Extent2d { width: 2, height: 1 }, 1).unwrap();
+        unsafe {
+            slice::from_raw_parts_mut(texture.data_mut() as *mut u8, 8).copy_from_slice(&[0, 188, 255, 128, 255, 255, 255, 0]);
+        }
+
+        texture.convert_srgb_to_linear().unwrap();
+        assert_eq!(texture.format(), Format::RGBA8_UNORM_PACK8);
+        assert_eq!(unsafe { slice::from_raw_parts(texture.data() as *const u8, 8) }, &[0, 128, 255, 128, 255, 255, 255, 0]);
+
+        texture.premultiply_alpha().unwrap();
+        assert_eq!(unsafe { slice::from_raw_parts(texture.data() as *const u8, 8) }, &[0, 64, 128, 128, 0, 0, 0, 0]);
+        texture.unpremultiply_alpha().unwrap();
+        assert_eq!(unsafe { slice::from_raw_parts(texture.data() as *const u8, 8) }, &[0, 128, 255, 128, 0, 0, 0, 0]);
+
+        texture.convert_linear_to_srgb().unwrap();
+        assert_eq!(texture.format(), Format::RGBA8_SRGB_PACK8);
+        assert!(texture.convert_linear_to_srgb().is_err());
+
+        let mut texture = Texture2D::new(Format::R32_SFLOAT_PACK32, Extent2d { width: 1, height: 1 }, 1).unwrap();
+        unsafe { *(texture.data_mut() as *mut f32) = 0.25; }
+        texture.adjust_gamma(0.5).unwrap();
+        assert_eq!(unsafe { *(texture.data() as *const f32) }, 0.5);
+        assert!(texture.adjust_gamma(0.0).is_err());
+
+        let mut texture = Texture2D::new(Format::RGBA8_UINT_PACK8, Extent2d { width: 1, height: 1 }, 1).unwrap();
+        assert!(texture.premultiply_alpha().is_err());
+    }
+
+    #[test]
+    fn resize_test() {
+
+        use std::slice;
+        use self::gli::{Format, Extent2d, ResizeOptions, PowerOfTwo};
+        use self::gli::sampler::{Filter, Wrap};
+
+        let texels = |texture: &Texture2D| -> Vec<[f32; 4]> {
+            unsafe { slice::from_raw_parts(texture.data() as *const [f32; 4], texture.size() / 16).to_vec() }
+        };
+
+        // A gradient along each axis, and a pattern which differs in every row and column.
+        let mut texture = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 4, height: 4 }, 3).unwrap();
+        unsafe {
+            let data = slice::from_raw_parts_mut(texture.data_mut() as *mut [f32; 4], 16);
+            for (index, texel) in data.iter_mut().enumerate() {
+                let (x, y) = ((index % 4) as f32, (index / 4) as f32);
+                *texel = [x / 3.0, y / 3.0, ((x + 2.0 * y) % 3.0) / 2.0, 1.0];
+            }
+        }
+
+        let resized = texture.resize(Extent2d { width: 8, height: 6 }, Filter::LINEAR).unwrap();
+        let extent = resized.extent(0);
+        assert_eq!((extent.width, extent.height), (8, 6));
+        assert_eq!(resized.levels(), 3);
+        assert_eq!(resized.format(), Format::RGBA32_SFLOAT_PACK32);
+
+        // When enlarged, the resized texels are the texels sampled at their centers.
+        #[cfg(not(target_os = "windows"))]
+        {
+            use self::gli::sampler::FSampler2D;
+            use self::gli::extent::NormalizeCoord2d;
+
+            let options = [
+                (Filter::LINEAR, [Wrap::REPEAT, Wrap::CLAMP_TO_EDGE]),
+                (Filter::CATMULL_ROM, [Wrap::REPEAT, Wrap::REPEAT]),
+                (Filter::CUBIC_BSPLINE, [Wrap::CLAMP_TO_EDGE, Wrap::CLAMP_TO_EDGE]),
+            ];
+            for (filter, wraps) in options.iter() {
+
+                let resize_options = ResizeOptions::new(filter.clone())
+                    .wraps([wraps[0].clone(), wraps[1].clone(), Wrap::CLAMP_TO_EDGE])
+                    .levels(1);
+                let resized = texture.resize_with(Extent2d { width: 8, height: 6 }, &resize_options).unwrap();
+                let sampler = FSampler2D::with_wraps(&texture, wraps.clone(), Filter::NEAREST, filter.clone());
+
+                for (index, texel) in texels(&resized).iter().enumerate() {
+                    let coord = NormalizeCoord2d { x: ((index % 8) as f32 + 0.5) / 8.0, y: ((index / 8) as f32 + 0.5) / 6.0 };
+                    let expected = sampler.texel_lod(coord, 0.0);
+                    for (channel, value) in texel.iter().enumerate() {
+                        assert!((value - expected[channel]).abs() < 1e-5, "{:?} at {}: {:?} != {:?}", filter, index, texel, expected);
+                    }
+                }
+            }
+        }
+
+        // When shrunk, the lines between the centers of the resized texels are not skipped.
+        let mut lines = Texture2D::new(Format::RGBA32_SFLOAT_PACK32, Extent2d { width: 16, height: 1 }, 1).unwrap();
+        unsafe {
+            let data = slice::from_raw_parts_mut(lines.data_mut() as *mut [f32; 4], 16);
+            for (x, texel) in data.iter_mut().enumerate() {
+                *texel = if x % 4 == 0 { [1.0; 4] } else { [0.0; 4] };
+            }
+        }
+        for filter in [Filter::LINEAR, Filter::CUBIC_BSPLINE].iter() {
+            let options = ResizeOptions::new(filter.clone()).wrap(Wrap::REPEAT);
+            let shrunk = lines.resize_with(Extent2d { width: 4, height: 1 }, &options).unwrap();
+            for texel in texels(&shrunk) {
+                assert!((texel[0] - 0.25).abs() < 1e-5, "{:?}: {:?}", filter, texel);
+            }
+        }
+
+        let options = ResizeOptions::new(Filter::CATMULL_ROM)
+            .wrap(Wrap::REPEAT)
+            .levels(1)
+            .power_of_two(PowerOfTwo::Nearest);
+        let resized = texture.resize_with(Extent2d { width: 5, height: 7 }, &options).unwrap();
+        let extent = resized.extent(0);
+        assert_eq!((extent.width, extent.height), (4, 8));
+        assert_eq!(resized.levels(), 1);
+
+        let options = ResizeOptions::new(Filter::NEAREST).levels(4).generate_mipmaps(false);
+        assert!(texture.resize_with(Extent2d { width: 8, height: 8 }, &options).is_err());
+
+        let compressed = Texture2D::new(Format::RGBA_DXT1_UNORM_BLOCK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        assert!(compressed.resize(Extent2d { width: 8, height: 8 }, Filter::LINEAR).is_err());
+    }
 }
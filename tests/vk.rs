@@ -0,0 +1,71 @@
+
+#[cfg(test)]
+mod vk {
+
+    extern crate gli_rs as gli;
+
+    use self::gli::{Format, Extent2d, Texture2D, TextureCube, GliTexture};
+    use self::gli::vk::StagingLayout;
+
+    use std::slice;
+
+    /// The byte at `index` in the level `level` of the textures of these tests.
+    fn pattern(level: usize, index: usize) -> u8 {
+        (level * 64 + index + 1) as u8
+    }
+
+    #[test]
+    fn staging_layout_test() {
+
+        // RGB8 texels are 3 bytes, so the offsets are aligned to lcm(16, 3, 4) = 48 bytes.
+        let mut texture = Texture2D::new_with_mipmap_chain(Format::RGB8_UNORM_PACK8, Extent2d { width: 4, height: 4 }).unwrap();
+        for level in 0..texture.levels() {
+            let size = texture.size_at_level(level);
+            let texels = unsafe { slice::from_raw_parts_mut(texture.data_detail_mut(0, 0, level) as *mut u8, size) };
+            for (index, byte) in texels.iter_mut().enumerate() {
+                *byte = pattern(level, index);
+            }
+        }
+        let layout = StagingLayout::new(&texture, 16).unwrap();
+
+        assert_eq!(layout.regions.len(), texture.levels());
+        for (region, size) in layout.regions.iter().zip(layout.region_sizes.iter()) {
+            assert_eq!(region.buffer_offset % 48, 0);
+            assert_eq!(*size, (region.image_extent[0] * region.image_extent[1] * 3) as u64);
+        }
+        assert_eq!(layout.regions[1].buffer_offset, 48);
+        assert_eq!(layout.regions[1].image_extent, [2, 2, 1]);
+
+        let last = layout.regions.last().unwrap();
+        assert_eq!(layout.size, last.buffer_offset + layout.region_sizes.last().unwrap());
+
+        let mut staging = vec![0xff; layout.size as usize];
+        layout.copy_to(&texture, &mut staging);
+
+        for (level, (region, &size)) in layout.regions.iter().zip(layout.region_sizes.iter()).enumerate() {
+            let offset = region.buffer_offset as usize;
+            let expected: Vec<u8> = (0..size as usize).map(|index| pattern(level, index)).collect();
+            assert_eq!(&staging[offset..(offset + size as usize)], &expected[..]);
+        }
+
+        // The level 1 ends at 48 + 12 bytes, and the level 2 starts at the next multiple of 48.
+        assert_eq!(layout.regions[2].buffer_offset, 96);
+        assert!(staging[60..96].iter().all(|&byte| byte == 0xff));
+    }
+
+    #[test]
+    fn staging_layout_cube_test() {
+
+        let texture = TextureCube::new(Format::RGBA_DXT1_UNORM_BLOCK8, Extent2d { width: 8, height: 8 }, 1).unwrap();
+        let layout = StagingLayout::new(&texture, 64).unwrap();
+
+        assert_eq!(layout.regions.len(), 6);
+        for (face, region) in layout.regions.iter().enumerate() {
+            assert_eq!(region.base_array_layer, face as u32);
+            assert_eq!(region.buffer_offset, face as u64 * 64);
+        }
+
+        let depth_stencil = Texture2D::new(Format::D24_UNORM_S8_UINT_PACK32, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        assert!(StagingLayout::new(&depth_stencil, 4).is_err());
+    }
+}
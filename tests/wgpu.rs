@@ -0,0 +1,53 @@
+
+#[cfg(all(test, feature = "wgpu"))]
+mod wgpu {
+
+    extern crate gli_rs as gli;
+    extern crate wgpu;
+
+    use self::gli::{Format, Extent2d, Texture2DArray, TextureCube, GliTexture};
+    use self::wgpu::{TextureFormat, TextureDimension, TextureUsages};
+
+    #[test]
+    fn texture_descriptor_test() {
+
+        let texture = Texture2DArray::new_with_mipmap_chain(Format::RGBA8_UNORM_PACK8, Extent2d { width: 8, height: 4 }, 3).unwrap();
+        let descriptor = gli::wgpu::texture_descriptor(&texture, Some("array"), TextureUsages::TEXTURE_BINDING).unwrap();
+
+        assert_eq!(descriptor.format, TextureFormat::Rgba8Unorm);
+        assert_eq!(descriptor.dimension, TextureDimension::D2);
+        assert_eq!(descriptor.size.width, 8);
+        assert_eq!(descriptor.size.height, 4);
+        assert_eq!(descriptor.size.depth_or_array_layers, 3);
+        assert_eq!(descriptor.mip_level_count, texture.levels() as u32);
+
+        let cube = TextureCube::new(Format::RGBA8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1).unwrap();
+        let descriptor = gli::wgpu::texture_descriptor(&cube, None, TextureUsages::TEXTURE_BINDING).unwrap();
+        assert_eq!(descriptor.size.depth_or_array_layers, 6);
+
+        let unsupported = Texture2DArray::new(Format::RGB8_UNORM_PACK8, Extent2d { width: 4, height: 4 }, 1, 1).unwrap();
+        assert!(gli::wgpu::texture_descriptor(&unsupported, None, TextureUsages::TEXTURE_BINDING).is_err());
+    }
+
+    #[test]
+    fn subresources_test() {
+
+        let texture = TextureCube::new(Format::RGBA_DXT1_UNORM_BLOCK8, Extent2d { width: 8, height: 8 }, 2).unwrap();
+        let subresources = gli::wgpu::subresources(&texture).unwrap();
+        assert_eq!(subresources.len(), 6 * 2);
+
+        // Face 3, level 1 is 4x4 texels, one DXT1 block of 8 bytes.
+        let subresource = &subresources[3 * 2 + 1];
+        assert_eq!((subresource.face, subresource.level), (3, 1));
+        assert_eq!(subresource.origin.z, 3);
+        assert_eq!(subresource.layout.bytes_per_row, Some(8));
+        assert_eq!(subresource.layout.rows_per_image, Some(1));
+        assert_eq!(subresource.data.len(), 8);
+
+        // Level 0 is 8x8 texels, 2x2 blocks.
+        let subresource = &subresources[0];
+        assert_eq!(subresource.layout.bytes_per_row, Some(16));
+        assert_eq!(subresource.layout.rows_per_image, Some(2));
+        assert_eq!(subresource.data.len(), 32);
+    }
+}
@@ -33,21 +33,21 @@ extern "C" {
 
 		namespace Comparison {
 
-			 bool is_image_equal(const gli::image & image_a, const gli::image & image_b) {
+			 bool is_image_equal(const gli::image & image_a, const gli::image & image_b) try {
 			 	return image_a == image_b;
-			 }
+			 } GLI_BINDING_CATCH(bool)
 
-			 bool is_image_unequal(const gli::image & image_a, const gli::image & image_b) {
+			 bool is_image_unequal(const gli::image & image_a, const gli::image & image_b) try {
 			 	return image_a != image_b;
-			 }
+			 } GLI_BINDING_CATCH(bool)
 
-			 bool is_texture_equal(const gli::texture & a, const gli::texture & b) {
+			 bool is_texture_equal(const gli::texture & a, const gli::texture & b) try {
 			 	return a == b;
-			 }
+			 } GLI_BINDING_CATCH(bool)
 
-			 bool is_texture_unequal(const gli::texture & a, const gli::texture & b) {
+			 bool is_texture_unequal(const gli::texture & a, const gli::texture & b) try {
 			 	return a != b;
-			 }
+			 } GLI_BINDING_CATCH(bool)
 		}
 	}
 }
@@ -491,25 +491,25 @@ extern "C" {
 
 		namespace DX {
 
-		    gli::dx new_dx_converter() {
+		    gli::dx new_dx_converter() try {
 		        return gli::dx();
-		    }
+		    } GLI_BINDING_CATCH(gli::dx)
 
-			gli::dx::format dx_translate(const gli::dx & converter, gli::format Format) {
+			gli::dx::format dx_translate(const gli::dx & converter, gli::format Format) try {
 				return converter.translate(Format);
-			}
+			} GLI_BINDING_CATCH(gli::dx::format)
 
-			gli::format dx_find(const gli::dx & converter, gli::dx::d3dfmt FourCC) {
+			gli::format dx_find(const gli::dx & converter, gli::dx::d3dfmt FourCC) try {
 				return converter.find(FourCC);
-			}
+			} GLI_BINDING_CATCH(gli::format)
 
-			gli::format dx_find2(const gli::dx & converter, gli::dx::d3dfmt FourCC, gli::dx::dxgiFormat Format) {
+			gli::format dx_find2(const gli::dx & converter, gli::dx::d3dfmt FourCC, gli::dx::dxgiFormat Format) try {
 				return converter.find(FourCC, Format);
-			}
+			} GLI_BINDING_CATCH(gli::format)
 
-			bool is_dds_ext(gli::target Target, gli::format Format) {
+			bool is_dds_ext(gli::target Target, gli::format Format) try {
 				return gli::is_dds_ext(Target, Format);
-			}
+			} GLI_BINDING_CATCH(bool)
 		}
 	}
 }
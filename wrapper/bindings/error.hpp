@@ -0,0 +1,113 @@
+/// @brief Report the C++ exceptions and the failed assertions to Rust, instead of unwinding through the FFI boundary.
+/// @file error.hpp
+
+#pragma once
+
+#include <new>
+#include <stdexcept>
+#include <string>
+
+namespace gli {
+
+    /// Thrown by GLI_ASSERT when the condition of an assertion is false.
+    class assertion_error : public std::logic_error {
+    public:
+        assertion_error(char const* Test, char const* File, int Line)
+            : std::logic_error(std::string(Test) + " (" + File + ":" + std::to_string(Line) + ")") {}
+    };
+}//namespace gli
+
+/// Catch every exception escaping from a binding, declared as a function-try-block:
+///
+///     bool texture_empty(const texture & tex) try {
+///         return tex.empty();
+///     } GLI_BINDING_CATCH(bool)
+///
+/// The error is recorded for the current thread, and the binding returns a value initialized `Type`.
+#define GLI_BINDING_CATCH(Type) GLI_BINDING_CATCH_WITH(bindings::Error::error_fallback<Type>())
+
+/// Same as GLI_BINDING_CATCH, for the bindings returning a type which can not be value initialized.
+#define GLI_BINDING_CATCH_WITH(Fallback) \
+    catch (...) { \
+        bindings::Error::error_catch(); \
+        return Fallback; \
+    }
+
+extern "C" {
+
+    namespace bindings {
+
+        namespace Error {
+
+            /// The error codes of the bindings, mapped to `ErrorKind` in src/error.rs.
+            enum error_code {
+                ERROR_NONE = 0,
+                ERROR_OUT_OF_MEMORY = 1,
+                ERROR_INVALID_ARGUMENT = 2,
+                ERROR_OUT_OF_RANGE = 3,
+                ERROR_ASSERTION = 4,
+                ERROR_UNKNOWN = 5,
+            };
+
+            static thread_local int LastError = ERROR_NONE;
+            static thread_local std::string LastMessage;
+
+            /// Return the code of the last error caught on the current thread, or ERROR_NONE.
+            int error_code() {
+                return LastError;
+            }
+
+            /// Return the message of the last error caught on the current thread. The pointer is valid until the next error or error_clear.
+            char const* error_message() {
+                return LastMessage.c_str();
+            }
+
+            /// Forget the last error caught on the current thread.
+            void error_clear() {
+                LastError = ERROR_NONE;
+                LastMessage.clear();
+            }
+
+            static void error_record(int Code, char const* Message) {
+                LastError = Code;
+                try {
+                    LastMessage = Message;
+                } catch (...) {
+                    LastMessage.clear();
+                }
+            }
+
+            /// Record the exception being handled. Must be called from a catch block.
+            static void error_catch() {
+                try {
+                    throw;
+                } catch (gli::assertion_error const& Error) {
+                    error_record(ERROR_ASSERTION, Error.what());
+                } catch (std::bad_alloc const& Error) {
+                    error_record(ERROR_OUT_OF_MEMORY, Error.what());
+                } catch (std::length_error const& Error) {
+                    error_record(ERROR_OUT_OF_MEMORY, Error.what());
+                } catch (std::out_of_range const& Error) {
+                    error_record(ERROR_OUT_OF_RANGE, Error.what());
+                } catch (std::invalid_argument const& Error) {
+                    error_record(ERROR_INVALID_ARGUMENT, Error.what());
+                } catch (std::exception const& Error) {
+                    error_record(ERROR_UNKNOWN, Error.what());
+                } catch (...) {
+                    error_record(ERROR_UNKNOWN, "Unknown exception.");
+                }
+            }
+        }
+    }
+}
+
+namespace bindings {
+
+    namespace Error {
+
+        template <typename Type>
+        Type error_fallback() {
+            return Type();
+        }
+    }
+}
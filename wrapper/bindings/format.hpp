@@ -337,4 +337,26 @@ extern "C" {
 
 #ifdef GLI_IMPLEMENTATION
 #include "format.inl"
+
+extern "C" {
+
+    namespace bindings {
+
+        namespace Format {
+
+            // The block queries are only available with the implementation of gli.
+            std::size_t format_block_size(gli::format Format) try {
+                return gli::block_size(Format);
+            } GLI_BINDING_CATCH(std::size_t)
+
+            gli::extent3d format_block_extent(gli::format Format) try {
+                return gli::block_extent(Format);
+            } GLI_BINDING_CATCH(gli::extent3d)
+
+            std::size_t format_component_count(gli::format Format) try {
+                return gli::component_count(Format);
+            } GLI_BINDING_CATCH(std::size_t)
+        }
+    }
+}
 #endif
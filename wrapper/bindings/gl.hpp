@@ -377,22 +377,22 @@ extern "C" {
 
         namespace GL {
 
-            gli::gl new_gl_converter(gli::gl::profile Profile) {
+            gli::gl new_gl_converter(gli::gl::profile Profile) try {
                 return gli::gl(Profile);
-            }
+            } GLI_BINDING_CATCH(gli::gl)
 
-            gli::gl::target gl_translate(const gli::gl & converter, gli::target Target) {
+            gli::gl::target gl_translate(const gli::gl & converter, gli::target Target) try {
                 return converter.translate(Target);
-            }
+            } GLI_BINDING_CATCH(gli::gl::target)
 
-            gli::gl::format gl_translate1(const gli::gl & converter, gli::format Format, const gli::swizzles & Swizzle) {
+            gli::gl::format gl_translate1(const gli::gl & converter, gli::format Format, const gli::swizzles & Swizzle) try {
                 return converter.translate(Format, Swizzle);
-            }
+            } GLI_BINDING_CATCH(gli::gl::format)
 
-            gli::format gl_find(gli::gl & converter, gli::gl::internal_format InternalFormat, gli::gl::external_format ExternalFormat, gli::gl::type_format Type) {
+            gli::format gl_find(gli::gl & converter, gli::gl::internal_format InternalFormat, gli::gl::external_format ExternalFormat, gli::gl::type_format Type) try {
 
                 return converter.find(InternalFormat, ExternalFormat, Type);
-            }
+            } GLI_BINDING_CATCH(gli::format)
         }
     }
 }
@@ -5,6 +5,7 @@
 
 #include "storage_linear.hpp"
 #include "texture.hpp"
+#include <cstring>
 #include <iostream>
 
 namespace gli {
@@ -132,58 +133,70 @@ extern "C" {
 
             using gli::image;
 
-            image image_new_empty() {
+            image image_new_empty() try {
                 return image();
-            }
+            } GLI_BINDING_CATCH(image)
 
-            image image_new_(image::format_type format, image::extent_type extent) {
+            image image_new_(image::format_type format, image::extent_type extent) try {
                 return image(format, extent);
-            }
+            } GLI_BINDING_CATCH(image)
 
-            image image_share_from(const image & img, image::format_type format) {
+            image image_share_from(const image & img, image::format_type format) try {
                 return image(img, format);
-            }
+            } GLI_BINDING_CATCH(image)
 
-            image image_share_from_texture(const gli::texture & tex, image::format_type format, image::size_type base_layer, image::size_type base_face, image::size_type base_level) {
+            image image_share_from_texture(const gli::texture & tex, image::format_type format, image::size_type base_layer, image::size_type base_face, image::size_type base_level) try {
                 return image(tex, format, base_layer, base_face, base_level);
-            }
+            } GLI_BINDING_CATCH(image)
 
-            bool image_empty(const image & img) {
+            bool image_empty(const image & img) try {
                 return img.empty();
-            }
+            } GLI_BINDING_CATCH(bool)
 
-            image::format_type image_format(const image & img) {
+            image::format_type image_format(const image & img) try {
                 return img.format();
-            }
+            } GLI_BINDING_CATCH(image::format_type)
 
-            image::extent_type image_extent(const image & img) {
+            image::extent_type image_extent(const image & img) try {
                 return img.extent();
-            }
+            } GLI_BINDING_CATCH(image::extent_type)
 
-            image::size_type image_size(const image & img) {
+            image::size_type image_size(const image & img) try {
                 return img.size();
-            }
+            } GLI_BINDING_CATCH(image::size_type)
 
-            void * image_data_mut(image & img) {
+            void * image_data_mut(image & img) try {
                 return img.data();
-            }
+            } GLI_BINDING_CATCH(void *)
 
-            void const * image_data(const image & img) {
+            void const * image_data(const image & img) try {
                 return img.data();
-            }
+            } GLI_BINDING_CATCH(void const *)
 
-            void image_clear(image & img) {
+            void image_clear(image & img) try {
                 img.clear();
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_image(image && img) {
+            void destroy_image(image && img) try {
                 img.~image();
-            }
+            } GLI_BINDING_CATCH(void)
 
-            int get_image_shared_storage_count(const image & img) {
+            int get_image_shared_storage_count(const image & img) try {
                 return gli::get_image_shared_storage_count(img);
-            }
+            } GLI_BINDING_CATCH(int)
+
+            /// Copy the texels of an image into a new storage.
+            image image_duplicate(const image & img) try {
+
+                if (img.empty()) {
+                    return image();
+                }
+
+                image dup(img.format(), img.extent());
+                std::memcpy(dup.data(), img.data(), img.size());
+                return dup;
+            } GLI_BINDING_CATCH(image)
         }
     }
 }
@@ -80,37 +80,37 @@ extern "C" {
 
         namespace Load {
 
-            Texture::texture load_memory(char const* Data, std::size_t Size) {
+            Texture::texture load_memory(char const* Data, std::size_t Size) try {
                 return gli::load(Data, Size);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
 
-            Texture::texture load_by_path(char const* Path) {
+            Texture::texture load_by_path(char const* Path) try {
                 return gli::load(Path);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
 
-            Texture::texture load_dds_memory(char const* Data, std::size_t Size) {
+            Texture::texture load_dds_memory(char const* Data, std::size_t Size) try {
                 return gli::load_dds(Data, Size);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
 
-            Texture::texture load_dds_by_path(char const* Path) {
+            Texture::texture load_dds_by_path(char const* Path) try {
                 return gli::load_dds(Path);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
 
-            Texture::texture load_kmg_memory(char const* Data, std::size_t Size) {
+            Texture::texture load_kmg_memory(char const* Data, std::size_t Size) try {
                 return gli::load_kmg(Data, Size);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
 
-            Texture::texture load_kmg_by_path(char const* Path) {
+            Texture::texture load_kmg_by_path(char const* Path) try {
                 return gli::load_kmg(Path);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
 
-            Texture::texture load_ktx_memory(char const* Data, std::size_t Size) {
+            Texture::texture load_ktx_memory(char const* Data, std::size_t Size) try {
                 return gli::load_ktx(Data, Size);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
 
-            Texture::texture load_ktx_by_path(char const* Path) {
+            Texture::texture load_ktx_by_path(char const* Path) try {
                 return gli::load_ktx(Path);
-            }
+            } GLI_BINDING_CATCH(Texture::texture)
         }
     }//namespace gli
 }
@@ -84,49 +84,51 @@ extern "C" {
         namespace FSampler1D {
 
 #ifndef _WIN32
-            gli::fsampler1D fsampler1d_new(const gli::texture1d & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) {
+            gli::fsampler1D fsampler1d_new(const gli::texture1d & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) try {
                 return gli::fsampler1D(Texture, Wrap, Mip, Min);
-            }
+            } GLI_BINDING_CATCH_WITH(gli::fsampler1D(gli::texture1d(gli::FORMAT_RGBA32_SFLOAT_PACK32, gli::texture1d::extent_type(1), 1), Wrap, Mip, Min))
 #endif
 
-            void fsampler1d_set_border_color(gli::fsampler1D & Sampler, TexelType4F BorderColor) {
+            void fsampler1d_set_border_color(gli::fsampler1D & Sampler, TexelType4F BorderColor) try {
                 Sampler.set_border_color(gli::tex4FToVec4(BorderColor));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler1d_clear(gli::fsampler1D & Sampler, TexelType4F Texel) {
+            void fsampler1d_clear(gli::fsampler1D & Sampler, TexelType4F Texel) try {
                 Sampler.clear(gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler1d_texel_fetch(const gli::fsampler1D & Sampler, gli::fsampler1D::extent_type TexelCoord, gli::texture::size_type Level) {
+            TexelType4F fsampler1d_texel_fetch(const gli::fsampler1D & Sampler, gli::fsampler1D::extent_type TexelCoord, gli::texture::size_type Level) try {
                 gli::vec4 raw = Sampler.texel_fetch(TexelCoord, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
 
-            void fsampler1d_texel_write(gli::fsampler1D & Sampler, gli::fsampler1D::extent_type TexelCoord, gli::texture::size_type Level, TexelType4F Texel) {
+            void fsampler1d_texel_write(gli::fsampler1D & Sampler, gli::fsampler1D::extent_type TexelCoord, gli::texture::size_type Level, TexelType4F Texel) try {
                 Sampler.texel_write(TexelCoord, Level, gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler1d_texel_lod(const gli::fsampler1D & Sampler, float SampleCoord, float Level) {
+            TexelType4F fsampler1d_texel_lod(const gli::fsampler1D & Sampler, float SampleCoord, float Level) try {
                 gli::vec4 raw = Sampler.texture_lod(gli::fsampler1D::normalized_type(SampleCoord), Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
 
-            const gli::texture1d & fsampler1d_target_texture(const gli::fsampler1D & Sampler) {
-                return Sampler.operator()();
-            }
+            void fsampler1d_texel_fetch_many(const gli::fsampler1D & Sampler, const gli::fsampler1D::extent_type * TexelCoords, gli::texture::size_type Count, gli::texture::size_type Level, TexelType4F * Texels) try {
+                for(gli::texture::size_type i = 0; i < Count; ++i) {
+                    Texels[i] = fsampler1d_texel_fetch(Sampler, TexelCoords[i], Level);
+                }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler1d_generate_mipmaps1(gli::fsampler1D & Sampler, gli::filter Minification) {
+            void fsampler1d_generate_mipmaps1(gli::fsampler1D & Sampler, gli::filter Minification) try {
                 Sampler.generate_mipmaps(Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler1d_generate_mipmaps2(gli::fsampler1D & Sampler, gli::texture::size_type BaseLevel, gli::texture::size_type  MaxLevel, gli::filter Minification) {
+            void fsampler1d_generate_mipmaps2(gli::fsampler1D & Sampler, gli::texture::size_type BaseLevel, gli::texture::size_type  MaxLevel, gli::filter Minification) try {
                 Sampler.generate_mipmaps(BaseLevel, MaxLevel, Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_sampler1d(gli::fsampler1D & Sampler) {
+            void destroy_sampler1d(gli::fsampler1D & Sampler) try {
                 Sampler.~sampler1d();
-            }
+            } GLI_BINDING_CATCH(void)
         }
 
 //        namespace DSampler1D {
@@ -85,49 +85,51 @@ extern "C" {
         namespace FSampler1DArray {
 
 #ifndef _WIN32
-            gli::fsampler1DArray fsampler1darray_new(const gli::texture1d_array & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) {
+            gli::fsampler1DArray fsampler1darray_new(const gli::texture1d_array & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) try {
                 return gli::fsampler1DArray(Texture, Wrap, Mip, Min);
-            }
+            } GLI_BINDING_CATCH_WITH(gli::fsampler1DArray(gli::texture1d_array(gli::FORMAT_RGBA32_SFLOAT_PACK32, gli::texture1d_array::extent_type(1), 1, 1), Wrap, Mip, Min))
 #endif
 
-            void fsampler1darray_set_border_color(gli::fsampler1DArray & Sampler, TexelType4F BorderColor) {
+            void fsampler1darray_set_border_color(gli::fsampler1DArray & Sampler, TexelType4F BorderColor) try {
                 Sampler.set_border_color(gli::tex4FToVec4(BorderColor));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler1darray_clear(gli::fsampler1DArray & Sampler, TexelType4F Texel) {
+            void fsampler1darray_clear(gli::fsampler1DArray & Sampler, TexelType4F Texel) try {
                 Sampler.clear(gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler1darray_texel_fetch(const gli::fsampler1DArray & Sampler, gli::fsampler1DArray::extent_type TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level) {
+            TexelType4F fsampler1darray_texel_fetch(const gli::fsampler1DArray & Sampler, gli::fsampler1DArray::extent_type TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level) try {
                 gli::vec4 raw = Sampler.texel_fetch(TexelCoord, Layer, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
 
-            void fsampler1darray_texel_write(gli::fsampler1DArray & Sampler, gli::fsampler1DArray::extent_type TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level, TexelType4F Texel) {
+            void fsampler1darray_texel_write(gli::fsampler1DArray & Sampler, gli::fsampler1DArray::extent_type TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level, TexelType4F Texel) try {
                 Sampler.texel_write(TexelCoord, Layer, Level, gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler1darray_texel_lod(const gli::fsampler1DArray & Sampler, float SampleCoord, gli::texture::size_type Layer, float Level) {
+            TexelType4F fsampler1darray_texel_lod(const gli::fsampler1DArray & Sampler, float SampleCoord, gli::texture::size_type Layer, float Level) try {
                 gli::vec4 raw = Sampler.texture_lod(gli::fsampler1DArray::normalized_type(SampleCoord), Layer, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
 
-            const gli::texture1d_array & fsampler1darray_target_texture(const gli::fsampler1DArray & Sampler) {
-                return Sampler.operator()();
-            }
+            void fsampler1darray_texel_fetch_many(const gli::fsampler1DArray & Sampler, const gli::fsampler1DArray::extent_type * TexelCoords, gli::texture::size_type Count, gli::texture::size_type Layer, gli::texture::size_type Level, TexelType4F * Texels) try {
+                for(gli::texture::size_type i = 0; i < Count; ++i) {
+                    Texels[i] = fsampler1darray_texel_fetch(Sampler, TexelCoords[i], Layer, Level);
+                }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler1darray_generate_mipmaps1(gli::fsampler1DArray & Sampler, gli::filter Minification) {
+            void fsampler1darray_generate_mipmaps1(gli::fsampler1DArray & Sampler, gli::filter Minification) try {
                 Sampler.generate_mipmaps(Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler1darray_generate_mipmaps2(gli::fsampler1DArray & Sampler, gli::texture::size_type BaseLayer, gli::texture::size_type MaxLayer, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) {
+            void fsampler1darray_generate_mipmaps2(gli::fsampler1DArray & Sampler, gli::texture::size_type BaseLayer, gli::texture::size_type MaxLayer, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) try {
                 Sampler.generate_mipmaps(BaseLayer, MaxLayer, BaseLevel, MaxLevel, Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_sampler1d_array(gli::fsampler1DArray & Sampler) {
+            void destroy_sampler1d_array(gli::fsampler1DArray & Sampler) try {
                 Sampler.~sampler1d_array();
-            }
+            } GLI_BINDING_CATCH(void)
         }
     }
 }
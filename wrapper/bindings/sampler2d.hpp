@@ -89,49 +89,63 @@ extern "C" {
         namespace FSampler2D {
 
 #ifndef _WIN32
-            gli::fsampler2D fsampler2d_new(const gli::texture2d & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) {
+            gli::fsampler2D fsampler2d_new(const gli::texture2d & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) try {
                 return gli::fsampler2D(Texture, Wrap, Mip, Min);
-            }
+            } GLI_BINDING_CATCH_WITH(gli::fsampler2D(gli::texture2d(gli::FORMAT_RGBA32_SFLOAT_PACK32, gli::texture2d::extent_type(1), 1), Wrap, Mip, Min))
 #endif
     
-            void fsampler2d_set_border_color(gli::fsampler2D & Sampler, TexelType4F BorderColor) {
+            void fsampler2d_set_border_color(gli::fsampler2D & Sampler, TexelType4F BorderColor) try {
                 Sampler.set_border_color(gli::tex4FToVec4(BorderColor));
-            }
+            } GLI_BINDING_CATCH(void)
     
-            void fsampler2d_clear(gli::fsampler2D & Sampler, TexelType4F Texel) {
+            void fsampler2d_clear(gli::fsampler2D & Sampler, TexelType4F Texel) try {
                 Sampler.clear(gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
     
-            TexelType4F fsampler2d_texel_fetch(const gli::fsampler2D & Sampler, const gli::fsampler2D::extent_type & TexelCoord, gli::texture::size_type Level) {
+            TexelType4F fsampler2d_texel_fetch(const gli::fsampler2D & Sampler, const gli::fsampler2D::extent_type & TexelCoord, gli::texture::size_type Level) try {
                 gli::vec4 raw = Sampler.texel_fetch(TexelCoord, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
     
-            void fsampler2d_texel_write(gli::fsampler2D & Sampler, const gli::fsampler2D::extent_type & TexelCoord, gli::texture::size_type Level, TexelType4F Texel) {
+            void fsampler2d_texel_write(gli::fsampler2D & Sampler, const gli::fsampler2D::extent_type & TexelCoord, gli::texture::size_type Level, TexelType4F Texel) try {
                 return Sampler.texel_write(TexelCoord, Level, gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
     
-            TexelType4F fsampler2d_texel_lod(const gli::fsampler2D & Sampler, const float SampleCoord[2], float Level) {
+            TexelType4F fsampler2d_texel_lod(const gli::fsampler2D & Sampler, const float SampleCoord[2], float Level) try {
                 gli::vec4 raw = Sampler.texture_lod(gli::fsampler2D::normalized_type(SampleCoord[0], SampleCoord[1]), Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
+
+            /// Decode every texel of a level of a texture, row by row, with the fetch functions of the samplers.
+            /// Unlike the samplers, this also accepts the DXT1, DXT3, DXT5, BC4 and BC5 compressed formats.
+            void texture2d_decode_texels(const gli::texture2d & Texture, gli::texture::size_type Level, TexelType4F * Texels) try {
+                gli::detail::convert<gli::texture2d, float, gli::defaultp>::fetchFunc Fetch = gli::detail::convert<gli::texture2d, float, gli::defaultp>::call(Texture.format()).Fetch;
+                gli::texture2d::extent_type const Extent = Texture.extent(Level);
+                for(int y = 0; y < Extent.y; ++y) {
+                    for(int x = 0; x < Extent.x; ++x) {
+                        Texels[y * Extent.x + x] = vec4ToTex4F(Fetch(Texture, gli::texture2d::extent_type(x, y), 0, 0, Level));
+                    }
+                }
+            } GLI_BINDING_CATCH(void)
+
+            void fsampler2d_texel_fetch_many(const gli::fsampler2D & Sampler, const gli::fsampler2D::extent_type * TexelCoords, gli::texture::size_type Count, gli::texture::size_type Level, TexelType4F * Texels) try {
+                for(gli::texture::size_type i = 0; i < Count; ++i) {
+                    Texels[i] = fsampler2d_texel_fetch(Sampler, TexelCoords[i], Level);
+                }
+            } GLI_BINDING_CATCH(void)
     
-            const gli::texture2d & fsampler2d_target_texture(const gli::fsampler2D & Sampler) {
-                return Sampler.operator()();
-            }
-    
-            void fsampler2d_generate_mipmaps1(gli::fsampler2D & Sampler, gli::filter Minification) {
+            void fsampler2d_generate_mipmaps1(gli::fsampler2D & Sampler, gli::filter Minification) try {
                 Sampler.generate_mipmaps(Minification);
-            }
+            } GLI_BINDING_CATCH(void)
     
-            void fsampler2d_generate_mipmaps2(gli::fsampler2D & Sampler, gli::texture::size_type BaseLevel, gli::texture::size_type  MaxLevel, gli::filter Minification) {
+            void fsampler2d_generate_mipmaps2(gli::fsampler2D & Sampler, gli::texture::size_type BaseLevel, gli::texture::size_type  MaxLevel, gli::filter Minification) try {
                 Sampler.generate_mipmaps(BaseLevel, MaxLevel, Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_sampler2d(gli::fsampler2D & Sampler) {
+            void destroy_sampler2d(gli::fsampler2D & Sampler) try {
                 Sampler.~sampler2d();
-            }
+            } GLI_BINDING_CATCH(void)
         }
     }
 }
@@ -86,49 +86,51 @@ extern "C" {
         namespace FSampler2DArray {
 
 #ifndef _WIN32
-            gli::fsampler2DArray fsampler2darray_new(const gli::texture2d_array & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) {
+            gli::fsampler2DArray fsampler2darray_new(const gli::texture2d_array & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) try {
                 return gli::fsampler2DArray(Texture, Wrap, Mip, Min);
-            }
+            } GLI_BINDING_CATCH_WITH(gli::fsampler2DArray(gli::texture2d_array(gli::FORMAT_RGBA32_SFLOAT_PACK32, gli::texture2d_array::extent_type(1), 1, 1), Wrap, Mip, Min))
 #endif
 
-            void fsampler2darray_set_border_color(gli::fsampler2DArray & Sampler, TexelType4F BorderColor) {
+            void fsampler2darray_set_border_color(gli::fsampler2DArray & Sampler, TexelType4F BorderColor) try {
                 Sampler.set_border_color(gli::tex4FToVec4(BorderColor));
-            }
+            } GLI_BINDING_CATCH(void)
     
-            void fsampler2darray_clear(gli::fsampler2DArray & Sampler, TexelType4F Texel) {
+            void fsampler2darray_clear(gli::fsampler2DArray & Sampler, TexelType4F Texel) try {
                 Sampler.clear(gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
     
-            TexelType4F fsampler2darray_texel_fetch(const gli::fsampler2DArray & Sampler, const gli::fsampler2DArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level) {
+            TexelType4F fsampler2darray_texel_fetch(const gli::fsampler2DArray & Sampler, const gli::fsampler2DArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level) try {
                 gli::vec4 raw = Sampler.texel_fetch(TexelCoord, Layer, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
     
-            void fsampler2darray_texel_write(gli::fsampler2DArray & Sampler, const gli::fsampler2DArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level, TexelType4F Texel) {
+            void fsampler2darray_texel_write(gli::fsampler2DArray & Sampler, const gli::fsampler2DArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Level, TexelType4F Texel) try {
                 Sampler.texel_write(TexelCoord, Layer, Level, gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
     
-            TexelType4F fsampler2darray_texel_lod(const gli::fsampler2DArray & Sampler, const float SampleCoord[2], gli::texture::size_type Layer, float Level) {
+            TexelType4F fsampler2darray_texel_lod(const gli::fsampler2DArray & Sampler, const float SampleCoord[2], gli::texture::size_type Layer, float Level) try {
                 gli::vec4 raw = Sampler.texture_lod(gli::fsampler2DArray::normalized_type(SampleCoord[0], SampleCoord[1]), Layer, Level);
                 return vec4ToTex4F(raw);
-            }
-    
-            const gli::texture2d_array & fsampler2darray_target_texture(const gli::fsampler2DArray & Sampler) {
-                return Sampler.operator()();
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
+
+            void fsampler2darray_texel_fetch_many(const gli::fsampler2DArray & Sampler, const gli::fsampler2DArray::extent_type * TexelCoords, gli::texture::size_type Count, gli::texture::size_type Layer, gli::texture::size_type Level, TexelType4F * Texels) try {
+                for(gli::texture::size_type i = 0; i < Count; ++i) {
+                    Texels[i] = fsampler2darray_texel_fetch(Sampler, TexelCoords[i], Layer, Level);
+                }
+            } GLI_BINDING_CATCH(void)
     
-            void fsampler2darray_generate_mipmaps1(gli::fsampler2DArray & Sampler, gli::filter Minification) {
+            void fsampler2darray_generate_mipmaps1(gli::fsampler2DArray & Sampler, gli::filter Minification) try {
                 Sampler.generate_mipmaps(Minification);
-            }
+            } GLI_BINDING_CATCH(void)
     
-            void fsampler2darray_generate_mipmaps2(gli::fsampler2DArray & Sampler, gli::texture::size_type BaseLayer, gli::texture::size_type MaxLayer, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) {
+            void fsampler2darray_generate_mipmaps2(gli::fsampler2DArray & Sampler, gli::texture::size_type BaseLayer, gli::texture::size_type MaxLayer, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) try {
                 Sampler.generate_mipmaps(BaseLayer, MaxLayer, BaseLevel, MaxLevel, Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_sampler2d_array(gli::fsampler2DArray & Sampler) {
+            void destroy_sampler2d_array(gli::fsampler2DArray & Sampler) try {
                 Sampler.~sampler2d_array();
-            }
+            } GLI_BINDING_CATCH(void)
         }
     }
 }
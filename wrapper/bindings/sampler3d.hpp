@@ -86,49 +86,65 @@ extern "C" {
         namespace FSampler3D {
 
 #ifndef _WIN32
-            gli::fsampler3D fsampler3d_new(const gli::texture3d & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) {
+            gli::fsampler3D fsampler3d_new(const gli::texture3d & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) try {
                 return gli::fsampler3D(Texture, Wrap, Mip, Min);
-            }
+            } GLI_BINDING_CATCH_WITH(gli::fsampler3D(gli::texture3d(gli::FORMAT_RGBA32_SFLOAT_PACK32, gli::texture3d::extent_type(1), 1), Wrap, Mip, Min))
 #endif
 
-            void fsampler3d_set_border_color(gli::fsampler3D & Sampler, TexelType4F BorderColor) {
+            void fsampler3d_set_border_color(gli::fsampler3D & Sampler, TexelType4F BorderColor) try {
                 Sampler.set_border_color(gli::tex4FToVec4(BorderColor));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler3d_clear(gli::fsampler3D & Sampler, TexelType4F Texel) {
+            void fsampler3d_clear(gli::fsampler3D & Sampler, TexelType4F Texel) try {
                 Sampler.clear(gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler3d_texel_fetch(const gli::fsampler3D & Sampler, const gli::fsampler3D::extent_type & TexelCoord, gli::texture::size_type Level) {
+            TexelType4F fsampler3d_texel_fetch(const gli::fsampler3D & Sampler, const gli::fsampler3D::extent_type & TexelCoord, gli::texture::size_type Level) try {
                 gli::vec4 raw = Sampler.texel_fetch(TexelCoord, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
 
-            void fsampler3d_texel_write(gli::fsampler3D & Sampler, const gli::fsampler3D::extent_type & TexelCoord, gli::texture::size_type Level, TexelType4F Texel) {
+            void fsampler3d_texel_write(gli::fsampler3D & Sampler, const gli::fsampler3D::extent_type & TexelCoord, gli::texture::size_type Level, TexelType4F Texel) try {
                 return Sampler.texel_write(TexelCoord, Level, gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler3d_texel_lod(const gli::fsampler3D & Sampler, const float SampleCoord[3], float Level) {
+            TexelType4F fsampler3d_texel_lod(const gli::fsampler3D & Sampler, const float SampleCoord[3], float Level) try {
                 gli::vec4 raw = Sampler.texture_lod(gli::fsampler3D::normalized_type(SampleCoord[0], SampleCoord[1], SampleCoord[2]), Level);
                 return vec4ToTex4F(raw);
-            }
-
-            const gli::texture3d & fsampler3d_target_texture(const gli::fsampler3D & Sampler) {
-                return Sampler.operator()();
-            }
-
-            void fsampler3d_generate_mipmaps1(gli::fsampler3D & Sampler, gli::filter Minification) {
+            } GLI_BINDING_CATCH(TexelType4F)
+
+            /// Decode every texel of a level of a texture, row by row, with the fetch functions of the samplers.
+            /// Unlike the samplers, this also accepts the DXT1, DXT3, DXT5, BC4 and BC5 compressed formats.
+            void texture3d_decode_texels(const gli::texture3d & Texture, gli::texture::size_type Level, TexelType4F * Texels) try {
+                gli::detail::convert<gli::texture3d, float, gli::defaultp>::fetchFunc Fetch = gli::detail::convert<gli::texture3d, float, gli::defaultp>::call(Texture.format()).Fetch;
+                gli::texture3d::extent_type const Extent = Texture.extent(Level);
+                for(int z = 0; z < Extent.z; ++z) {
+                    for(int y = 0; y < Extent.y; ++y) {
+                        for(int x = 0; x < Extent.x; ++x) {
+                            Texels[(z * Extent.y + y) * Extent.x + x] = vec4ToTex4F(Fetch(Texture, gli::texture3d::extent_type(x, y, z), 0, 0, Level));
+                        }
+                    }
+                }
+            } GLI_BINDING_CATCH(void)
+
+            void fsampler3d_texel_fetch_many(const gli::fsampler3D & Sampler, const gli::fsampler3D::extent_type * TexelCoords, gli::texture::size_type Count, gli::texture::size_type Level, TexelType4F * Texels) try {
+                for(gli::texture::size_type i = 0; i < Count; ++i) {
+                    Texels[i] = fsampler3d_texel_fetch(Sampler, TexelCoords[i], Level);
+                }
+            } GLI_BINDING_CATCH(void)
+
+            void fsampler3d_generate_mipmaps1(gli::fsampler3D & Sampler, gli::filter Minification) try {
                 Sampler.generate_mipmaps(Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler3d_generate_mipmaps3(gli::fsampler3D & Sampler, gli::texture::size_type BaseLevel, gli::texture::size_type  MaxLevel, gli::filter Minification) {
+            void fsampler3d_generate_mipmaps3(gli::fsampler3D & Sampler, gli::texture::size_type BaseLevel, gli::texture::size_type  MaxLevel, gli::filter Minification) try {
                 Sampler.generate_mipmaps(BaseLevel, MaxLevel, Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_sampler3d(gli::fsampler3D & Sampler) {
+            void destroy_sampler3d(gli::fsampler3D & Sampler) try {
                 Sampler.~sampler3d();
-            }
+            } GLI_BINDING_CATCH(void)
         }
     }
 }
@@ -87,49 +87,51 @@ extern "C" {
         namespace FSamplerCube {
 
 #ifndef _WIN32
-            gli::fsamplerCube fsampler_cube_new(const gli::texture_cube & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) {
+            gli::fsamplerCube fsampler_cube_new(const gli::texture_cube & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) try {
                 return gli::fsamplerCube(Texture, Wrap, Mip, Min);
-            }
+            } GLI_BINDING_CATCH_WITH(gli::fsamplerCube(gli::texture_cube(gli::FORMAT_RGBA32_SFLOAT_PACK32, gli::texture_cube::extent_type(1), 1), Wrap, Mip, Min))
 #endif
 
-            void fsampler_cube_set_border_color(gli::fsamplerCube & Sampler, TexelType4F BorderColor) {
+            void fsampler_cube_set_border_color(gli::fsamplerCube & Sampler, TexelType4F BorderColor) try {
                 Sampler.set_border_color(gli::tex4FToVec4(BorderColor));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler_cube_clear(gli::fsamplerCube & Sampler, TexelType4F Texel) {
+            void fsampler_cube_clear(gli::fsamplerCube & Sampler, TexelType4F Texel) try {
                 Sampler.clear(gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler_cube_texel_fetch(const gli::fsamplerCube & Sampler, const gli::fsamplerCube::extent_type & TexelCoord, gli::texture::size_type Face, gli::texture::size_type Level) {
+            TexelType4F fsampler_cube_texel_fetch(const gli::fsamplerCube & Sampler, const gli::fsamplerCube::extent_type & TexelCoord, gli::texture::size_type Face, gli::texture::size_type Level) try {
                 gli::vec4 raw = Sampler.texel_fetch(TexelCoord, Face, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
 
-            void fsampler_cube_texel_write(gli::fsamplerCube & Sampler, const gli::fsamplerCube::extent_type & TexelCoord, gli::texture::size_type Face, gli::texture::size_type Level, TexelType4F Texel) {
+            void fsampler_cube_texel_write(gli::fsamplerCube & Sampler, const gli::fsamplerCube::extent_type & TexelCoord, gli::texture::size_type Face, gli::texture::size_type Level, TexelType4F Texel) try {
                 return Sampler.texel_write(TexelCoord, Face, Level, gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
 
-            TexelType4F fsampler_cube_texel_lod(const gli::fsamplerCube & Sampler, const float SampleCoord[2], gli::texture::size_type Face, float Level) {
+            TexelType4F fsampler_cube_texel_lod(const gli::fsamplerCube & Sampler, const float SampleCoord[2], gli::texture::size_type Face, float Level) try {
                 gli::vec4 raw = Sampler.texture_lod(gli::fsamplerCube::normalized_type(SampleCoord[0], SampleCoord[1]), Face, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
 
-            const gli::texture_cube & fsampler_cube_target_texture(const gli::fsamplerCube & Sampler) {
-                return Sampler.operator()();
-            }
+            void fsampler_cube_texel_fetch_many(const gli::fsamplerCube & Sampler, const gli::fsamplerCube::extent_type * TexelCoords, gli::texture::size_type Count, gli::texture::size_type Face, gli::texture::size_type Level, TexelType4F * Texels) try {
+                for(gli::texture::size_type i = 0; i < Count; ++i) {
+                    Texels[i] = fsampler_cube_texel_fetch(Sampler, TexelCoords[i], Face, Level);
+                }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler_cube_generate_mipmaps1(gli::fsamplerCube & Sampler, gli::filter Minification) {
+            void fsampler_cube_generate_mipmaps1(gli::fsamplerCube & Sampler, gli::filter Minification) try {
                 Sampler.generate_mipmaps(Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
-            void fsampler_cube_generate_mipmaps2(gli::fsamplerCube & Sampler, gli::texture::size_type BaseFace, gli::texture::size_type MaxFace, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) {
+            void fsampler_cube_generate_mipmaps2(gli::fsamplerCube & Sampler, gli::texture::size_type BaseFace, gli::texture::size_type MaxFace, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) try {
                 Sampler.generate_mipmaps(BaseFace, MaxFace, BaseLevel, MaxLevel, Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_sampler_cube(gli::fsamplerCube & Sampler) {
+            void destroy_sampler_cube(gli::fsamplerCube & Sampler) try {
                 Sampler.~sampler_cube();
-            }
+            } GLI_BINDING_CATCH(void)
         }
     }
 }
@@ -87,49 +87,51 @@ extern "C" {
         namespace FSamplerCubeArray {
 
 #ifndef _WIN32
-            gli::fsamplerCubeArray fsampler_cube_array_new(const gli::texture_cube_array & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) {
+            gli::fsamplerCubeArray fsampler_cube_array_new(const gli::texture_cube_array & Texture, gli::wrap Wrap, gli::filter Mip, gli::filter Min) try {
                 return gli::fsamplerCubeArray(Texture, Wrap, Mip, Min);
-            }
+            } GLI_BINDING_CATCH_WITH(gli::fsamplerCubeArray(gli::texture_cube_array(gli::FORMAT_RGBA32_SFLOAT_PACK32, gli::texture_cube_array::extent_type(1), 1, 1), Wrap, Mip, Min))
 #endif
 
-            void fsampler_cube_array_set_border_color(gli::fsamplerCubeArray & Sampler, TexelType4F BorderColor) {
+            void fsampler_cube_array_set_border_color(gli::fsamplerCubeArray & Sampler, TexelType4F BorderColor) try {
                 Sampler.set_border_color(gli::tex4FToVec4(BorderColor));
-            }
+            } GLI_BINDING_CATCH(void)
             
-            void fsampler_cube_array_clear(gli::fsamplerCubeArray & Sampler, TexelType4F Texel) {
+            void fsampler_cube_array_clear(gli::fsamplerCubeArray & Sampler, TexelType4F Texel) try {
                 Sampler.clear(gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
     
-            TexelType4F fsampler_cube_array_texel_fetch(const gli::fsamplerCubeArray & Sampler, const gli::fsamplerCubeArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Face, gli::texture::size_type Level) {
+            TexelType4F fsampler_cube_array_texel_fetch(const gli::fsamplerCubeArray & Sampler, const gli::fsamplerCubeArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Face, gli::texture::size_type Level) try {
                 gli::vec4 raw = Sampler.texel_fetch(TexelCoord, Layer, Face, Level);
                 return vec4ToTex4F(raw);
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
             
-            void fsampler_cube_array_texel_write(gli::fsamplerCubeArray & Sampler, const gli::fsamplerCubeArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Face, gli::texture::size_type Level, TexelType4F Texel) {
+            void fsampler_cube_array_texel_write(gli::fsamplerCubeArray & Sampler, const gli::fsamplerCubeArray::extent_type & TexelCoord, gli::texture::size_type Layer, gli::texture::size_type Face, gli::texture::size_type Level, TexelType4F Texel) try {
                 return Sampler.texel_write(TexelCoord, Layer, Face, Level, gli::tex4FToVec4(Texel));
-            }
+            } GLI_BINDING_CATCH(void)
             
-            TexelType4F fsampler_cube_array_texel_lod(const gli::fsamplerCubeArray & Sampler, const float SampleCoord[2], gli::texture::size_type Layer, gli::texture::size_type Face, float Level) {
+            TexelType4F fsampler_cube_array_texel_lod(const gli::fsamplerCubeArray & Sampler, const float SampleCoord[2], gli::texture::size_type Layer, gli::texture::size_type Face, float Level) try {
                 gli::vec4 raw = Sampler.texture_lod(gli::fsamplerCubeArray::normalized_type(SampleCoord[0], SampleCoord[1]), Layer, Face, Level);
                 return vec4ToTex4F(raw);
-            }
-            
-            const gli::texture_cube_array & fsampler_cube_array_target_texture(const gli::fsamplerCubeArray & Sampler) {
-                return Sampler.operator()();
-            }
+            } GLI_BINDING_CATCH(TexelType4F)
+
+            void fsampler_cube_array_texel_fetch_many(const gli::fsamplerCubeArray & Sampler, const gli::fsamplerCubeArray::extent_type * TexelCoords, gli::texture::size_type Count, gli::texture::size_type Layer, gli::texture::size_type Face, gli::texture::size_type Level, TexelType4F * Texels) try {
+                for(gli::texture::size_type i = 0; i < Count; ++i) {
+                    Texels[i] = fsampler_cube_array_texel_fetch(Sampler, TexelCoords[i], Layer, Face, Level);
+                }
+            } GLI_BINDING_CATCH(void)
     
-            void fsampler_cube_array_generate_mipmaps1(gli::fsamplerCubeArray & Sampler, gli::filter Minification) {
+            void fsampler_cube_array_generate_mipmaps1(gli::fsamplerCubeArray & Sampler, gli::filter Minification) try {
                 Sampler.generate_mipmaps(Minification);
-            }
+            } GLI_BINDING_CATCH(void)
             
-            void fsampler_cube_array_generate_mipmaps2(gli::fsamplerCubeArray & Sampler, gli::texture::size_type BaseLayer, gli::texture::size_type MaxLayer, gli::texture::size_type BaseFace, gli::texture::size_type MaxFace, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) {
+            void fsampler_cube_array_generate_mipmaps2(gli::fsamplerCubeArray & Sampler, gli::texture::size_type BaseLayer, gli::texture::size_type MaxLayer, gli::texture::size_type BaseFace, gli::texture::size_type MaxFace, gli::texture::size_type BaseLevel, gli::texture::size_type MaxLevel, gli::filter Minification) try {
                 Sampler.generate_mipmaps(BaseLayer, MaxLayer, BaseFace, MaxFace, BaseLevel, MaxLevel, Minification);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for image object. Helper function used in FFI.
-            void destroy_sampler_cube_array(gli::fsamplerCubeArray & Sampler) {
+            void destroy_sampler_cube_array(gli::fsamplerCubeArray & Sampler) try {
                 Sampler.~sampler_cube_array();
-            }
+            } GLI_BINDING_CATCH(void)
         }
     }
 }
@@ -3,6 +3,10 @@
 
 #pragma once
 
+#include <cstdlib>
+#include <cstring>
+#include <vector>
+
 namespace gli {
 
     /// Save a texture storage_linear to a DDS file.
@@ -82,17 +86,58 @@ extern "C" {
 
         namespace Save {
 
-            bool save_save_dds(Texture::texture const & Texture, char const* Path) {
+            bool save_save_dds(Texture::texture const & Texture, char const* Path) try {
                 return gli::save_dds(Texture, Path);
-            }
+            } GLI_BINDING_CATCH(bool)
 
-            bool save_save_kmg(Texture::texture const & Texture, char const * Path) {
+            bool save_save_kmg(Texture::texture const & Texture, char const * Path) try {
                 return gli::save_kmg(Texture, Path);
-            }
+            } GLI_BINDING_CATCH(bool)
 
-            bool save_save_ktx(Texture::texture const & Texture, char const * Path) {
+            bool save_save_ktx(Texture::texture const & Texture, char const * Path) try {
                 return gli::save_ktx(Texture, Path);
+            } GLI_BINDING_CATCH(bool)
+
+            /// Copy a container saved to memory into a buffer allocated by std::malloc, which must be released by save_free_memory.
+            static bool save_copy_memory(bool Saved, std::vector<char> const & Memory, char ** Data, std::size_t * Size) {
+
+                *Data = nullptr;
+                *Size = 0;
+                if (!Saved || Memory.empty()) {
+                    return Saved;
+                }
+
+                *Data = static_cast<char *>(std::malloc(Memory.size()));
+                if (*Data == nullptr) {
+                    throw std::bad_alloc();
+                }
+                std::memcpy(*Data, Memory.data(), Memory.size());
+                *Size = Memory.size();
+                return true;
             }
+
+            bool save_dds_memory(Texture::texture const & Texture, char ** Data, std::size_t * Size) try {
+                std::vector<char> Memory;
+                bool Saved = gli::save_dds(Texture, Memory);
+                return save_copy_memory(Saved, Memory, Data, Size);
+            } GLI_BINDING_CATCH(bool)
+
+            bool save_kmg_memory(Texture::texture const & Texture, char ** Data, std::size_t * Size) try {
+                std::vector<char> Memory;
+                bool Saved = gli::save_kmg(Texture, Memory);
+                return save_copy_memory(Saved, Memory, Data, Size);
+            } GLI_BINDING_CATCH(bool)
+
+            bool save_ktx_memory(Texture::texture const & Texture, char ** Data, std::size_t * Size) try {
+                std::vector<char> Memory;
+                bool Saved = gli::save_ktx(Texture, Memory);
+                return save_copy_memory(Saved, Memory, Data, Size);
+            } GLI_BINDING_CATCH(bool)
+
+            /// Release a buffer returned by the save_*_memory functions.
+            void save_free_memory(char * Data) try {
+                std::free(Data);
+            } GLI_BINDING_CATCH(void)
         }
     }
 }
@@ -312,115 +312,162 @@ extern "C" {
 
             using gli::texture;
 
-            bool texture_empty(const texture & tex) {
+            bool texture_empty(const texture & tex) try {
                 return tex.empty();
-            }
+            } GLI_BINDING_CATCH(bool)
 
-            texture::format_type texture_format(const texture & tex) {
+            texture::format_type texture_format(const texture & tex) try {
                 return tex.format();
-            }
+            } GLI_BINDING_CATCH(texture::format_type)
 
-            texture::target_type texture_target(const texture & tex) {
+            texture::target_type texture_target(const texture & tex) try {
                 return tex.target();
-            }
+            } GLI_BINDING_CATCH(texture::target_type)
 
-            texture::swizzles_type texture_swizzles(const texture & tex) {
+            texture::swizzles_type texture_swizzles(const texture & tex) try {
                 return tex.swizzles();
-            }
+            } GLI_BINDING_CATCH(texture::swizzles_type)
 
-            texture::size_type texture_base_layer(const texture & tex) {
+            texture::size_type texture_base_layer(const texture & tex) try {
                 return tex.base_layer();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_max_layer(const texture & tex) {
+            texture::size_type texture_max_layer(const texture & tex) try {
                 return tex.max_layer();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_layers(const texture & tex) {
+            texture::size_type texture_layers(const texture & tex) try {
                 return tex.layers();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_base_face(const texture & tex) {
+            texture::size_type texture_base_face(const texture & tex) try {
                 return tex.base_face();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_max_face(const texture & tex) {
+            texture::size_type texture_max_face(const texture & tex) try {
                 return tex.max_face();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_faces(const texture & tex) {
+            texture::size_type texture_faces(const texture & tex) try {
                 return tex.faces();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_base_level(const texture & tex) {
+            texture::size_type texture_base_level(const texture & tex) try {
                 return tex.base_level();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_max_level(const texture & tex) {
+            texture::size_type texture_max_level(const texture & tex) try {
                 return tex.max_level();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_levels(const texture & tex) {
+            texture::size_type texture_levels(const texture & tex) try {
                 return tex.levels();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::extent_type texture_extent(const texture & tex, texture::size_type level = 0) {
+            texture::extent_type texture_extent(const texture & tex, texture::size_type level = 0) try {
                 return tex.extent(level);
-            }
+            } GLI_BINDING_CATCH(texture::extent_type)
 
-            texture::size_type texture_size(const texture & tex) {
+            texture::size_type texture_size(const texture & tex) try {
                 return tex.size();
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            texture::size_type texture_size_level(const texture & tex, texture::size_type level) {
+            texture::size_type texture_size_level(const texture & tex, texture::size_type level) try {
                 return tex.size(level);
-            }
+            } GLI_BINDING_CATCH(texture::size_type)
 
-            void const * texture_data(const texture & tex) {
+            void const * texture_data(const texture & tex) try {
                 return tex.data();
-            }
+            } GLI_BINDING_CATCH(void const *)
 
-            void * texture_data_mut(texture & tex) {
+            void * texture_data_mut(texture & tex) try {
                 return tex.data();
-            }
+            } GLI_BINDING_CATCH(void *)
 
-            void const * texture_data_detail(const texture & tex, texture::size_type layer, texture::size_type face, texture::size_type level) {
+            void const * texture_data_detail(const texture & tex, texture::size_type layer, texture::size_type face, texture::size_type level) try {
                 return tex.data(layer, face, level);
-            }
+            } GLI_BINDING_CATCH(void const *)
 
-            void * texture_data_detail_mut(texture & tex, texture::size_type layer, texture::size_type face, texture::size_type level) {
+            void * texture_data_detail_mut(texture & tex, texture::size_type layer, texture::size_type face, texture::size_type level) try {
                 return tex.data(layer, face, level);
-            }
+            } GLI_BINDING_CATCH(void *)
 
-            void texture_clear(texture & tex) {
+            void texture_clear(texture & tex) try {
                 tex.clear();
-            }
+            } GLI_BINDING_CATCH(void)
 
             void texture_copy(
                 texture & tex, const texture & src,
                 size_t src_layer, size_t src_face, size_t src_level,
-                size_t dst_layer, size_t dst_face, size_t dst_level) {
+                size_t dst_layer, size_t dst_face, size_t dst_level) try {
 
                 tex.copy(src, src_layer, src_face, src_level, dst_layer, dst_face, dst_level);
-            }
+            } GLI_BINDING_CATCH(void)
 
             void texture_copy_subset(
                 texture & tex, const texture & src,
                 size_t src_layer, size_t src_face, size_t src_level, const texture::extent_type & src_offset,
                 size_t dst_layer, size_t dst_face, size_t dst_level, const texture::extent_type & dst_offset,
-                const texture::extent_type & extent) {
+                const texture::extent_type & extent) try {
 
                 tex.copy(src, src_layer, src_face, src_level, src_offset, dst_layer, dst_face, dst_level, dst_offset, extent);
-            }
+            } GLI_BINDING_CATCH(void)
 
             /// Manually Call destructor for texture object. Helper function used in FFI.
-            void destroy_texture(texture && tex) {
+            void destroy_texture(texture && tex) try {
                 tex.~texture();
-            }
+            } GLI_BINDING_CATCH(void)
 
-            int get_texture_shared_storage_count(const texture & tex) {
+            int get_texture_shared_storage_count(const texture & tex) try {
                 return gli::get_texture_shared_storage_count(tex);
-            }
+            } GLI_BINDING_CATCH(int)
+
+            /// Return the number of layers of the storage shared by a texture and its views, 0 for an empty texture.
+            texture::size_type texture_storage_layers(const texture & tex) try {
+                return tex.Storage ? tex.Storage->layers() : 0;
+            } GLI_BINDING_CATCH(texture::size_type)
+
+            /// Return the number of faces of the storage shared by a texture and its views, 0 for an empty texture.
+            texture::size_type texture_storage_faces(const texture & tex) try {
+                return tex.Storage ? tex.Storage->faces() : 0;
+            } GLI_BINDING_CATCH(texture::size_type)
+
+            /// Return the number of levels of the storage shared by a texture and its views, 0 for an empty texture.
+            texture::size_type texture_storage_levels(const texture & tex) try {
+                return tex.Storage ? tex.Storage->levels() : 0;
+            } GLI_BINDING_CATCH(texture::size_type)
+
+            /// Copy the layers, faces and levels of a texture(or a texture view) into a new storage.
+            texture texture_duplicate(const texture & tex) try {
+
+                if (tex.empty()) {
+                    return texture();
+                }
+
+                texture dup(tex.target(), tex.format(), tex.extent(), tex.layers(), tex.faces(), tex.levels(), tex.swizzles());
+
+                for (texture::size_type layer = 0; layer < tex.layers(); layer++) {
+                    for (texture::size_type face = 0; face < tex.faces(); face++) {
+                        for (texture::size_type level = 0; level < tex.levels(); level++) {
+                            dup.copy(tex, layer, face, level, layer, face, level);
+                        }
+                    }
+                }
+
+                return dup;
+            } GLI_BINDING_CATCH(texture)
+
+            /// Create a texture with the target, format, layers, faces and swizzles of a texture, and a new extent and number of levels.
+            texture texture_new_like(const texture & tex, const texture::extent_type & extent, texture::size_type levels) try {
+                return texture(tex.target(), tex.format(), extent, tex.layers(), tex.faces(), levels, tex.swizzles());
+            } GLI_BINDING_CATCH(texture)
+
+            /// Create a view of the layers, faces and levels of a texture, reinterpreted with another format.
+            texture texture_view_as(const texture & tex, texture::format_type format) try {
+                return texture(tex, tex.target(), format,
+                    tex.base_layer(), tex.max_layer(), tex.base_face(), tex.max_face(), tex.base_level(), tex.max_level(),
+                    tex.swizzles());
+            } GLI_BINDING_CATCH(texture)
         }
     }
 }
@@ -70,40 +70,40 @@ extern "C" {
 
             using gli::texture1d;
 
-            texture1d tex1d_new_empty() {
+            texture1d tex1d_new_empty() try {
                 return texture1d();
-            }
+            } GLI_BINDING_CATCH(texture1d)
 
-            texture1d tex1d_new_(texture1d::format_type format, texture1d::extent_type extent, texture1d::size_type levels) {
+            texture1d tex1d_new_(texture1d::format_type format, texture1d::extent_type extent, texture1d::size_type levels) try {
                 return texture1d(format, extent, levels);
-            }
+            } GLI_BINDING_CATCH(texture1d)
 
-            texture1d tex1d_new_with_mipmap_chain(texture1d::format_type format, texture1d::extent_type extent) {
+            texture1d tex1d_new_with_mipmap_chain(texture1d::format_type format, texture1d::extent_type extent) try {
                 return texture1d(format, extent);
-            }
+            } GLI_BINDING_CATCH(texture1d)
 
-            texture1d tex1d_share_from(const gli::texture & tex) {
+            texture1d tex1d_share_from(const gli::texture & tex) try {
                 return texture1d(tex);
-            }
+            } GLI_BINDING_CATCH(texture1d)
 
             texture1d tex1d_share_from_detail(
                 const gli::texture & tex,
                 texture1d::format_type format,
                 texture1d::size_type base_layer, texture1d::size_type max_layer,
                 texture1d::size_type base_face,  texture1d::size_type  max_face,
-                texture1d::size_type base_level, texture1d::size_type max_level) {
+                texture1d::size_type base_level, texture1d::size_type max_level) try {
 
                 return texture1d(tex, format, base_layer, max_layer, base_face, max_face, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture1d)
 
-            texture1d tex1d_share_from_subset(const texture1d & tex, texture1d::size_type base_level, texture1d::size_type max_level) {
+            texture1d tex1d_share_from_subset(const texture1d & tex, texture1d::size_type base_level, texture1d::size_type max_level) try {
 
                 return texture1d(tex, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture1d)
 
-            texture1d::extent_type tex1d_extent(const texture1d & tex, texture1d::size_type level = 0) {
+            texture1d::extent_type tex1d_extent(const texture1d & tex, texture1d::size_type level = 0) try {
                 return tex.extent(level);
-            }
+            } GLI_BINDING_CATCH(texture1d::extent_type)
         }
     }
 }
@@ -74,43 +74,43 @@ extern "C" {
 
             using gli::texture1d_array;
 
-            texture1d_array tex1darray_new_empty() {
+            texture1d_array tex1darray_new_empty() try {
                 return texture1d_array();
-            }
+            } GLI_BINDING_CATCH(texture1d_array)
 
-            texture1d_array tex1darray_new_(texture1d_array::format_type format, texture1d_array::extent_type extent, texture1d_array::size_type layers, texture1d_array::size_type levels) {
+            texture1d_array tex1darray_new_(texture1d_array::format_type format, texture1d_array::extent_type extent, texture1d_array::size_type layers, texture1d_array::size_type levels) try {
                 return texture1d_array(format, extent, layers, levels);
-            }
+            } GLI_BINDING_CATCH(texture1d_array)
 
-            texture1d_array tex1darray_new_with_mipmap_chain(texture1d_array::format_type format, texture1d_array::extent_type extent, texture1d_array::size_type layers) {
+            texture1d_array tex1darray_new_with_mipmap_chain(texture1d_array::format_type format, texture1d_array::extent_type extent, texture1d_array::size_type layers) try {
                 return texture1d_array(format, extent, layers);
-            }
+            } GLI_BINDING_CATCH(texture1d_array)
 
-            texture1d_array tex1darray_share_from(const gli::texture & tex) {
+            texture1d_array tex1darray_share_from(const gli::texture & tex) try {
                 return texture1d_array(tex);
-            }
+            } GLI_BINDING_CATCH(texture1d_array)
 
             texture1d_array tex1darray_share_from_detail(
                 const gli::texture & tex,
                 texture1d_array::format_type format,
                 texture1d_array::size_type base_layer, texture1d_array::size_type max_layer,
                 texture1d_array::size_type base_face,  texture1d_array::size_type  max_face,
-                texture1d_array::size_type base_level, texture1d_array::size_type max_level) {
+                texture1d_array::size_type base_level, texture1d_array::size_type max_level) try {
 
                 return texture1d_array(tex, format, base_layer, max_layer, base_face, max_face, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture1d_array)
 
             texture1d_array tex1darray_share_from_subset(
                 const texture1d_array & tex,
                 texture1d_array::size_type base_layer, texture1d_array::size_type max_layer,
-                texture1d_array::size_type base_level, texture1d_array::size_type max_level) {
+                texture1d_array::size_type base_level, texture1d_array::size_type max_level) try {
 
                 return texture1d_array(tex, base_layer, max_layer, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture1d_array)
 
-            texture1d_array::extent_type tex1darray_extent(const texture1d_array & tex, texture1d_array::size_type level = 0) {
+            texture1d_array::extent_type tex1darray_extent(const texture1d_array & tex, texture1d_array::size_type level = 0) try {
                 return tex.extent(level);
-            }
+            } GLI_BINDING_CATCH(texture1d_array::extent_type)
         }
     }
 }
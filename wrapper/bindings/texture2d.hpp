@@ -70,40 +70,40 @@ extern "C" {
 
             using gli::texture2d;
 
-            texture2d tex2d_new_empty() {
+            texture2d tex2d_new_empty() try {
                 return texture2d();
-            }
+            } GLI_BINDING_CATCH(texture2d)
 
-            texture2d tex2d_new_(texture2d::format_type format, texture2d::extent_type extent, texture2d::size_type levels) {
+            texture2d tex2d_new_(texture2d::format_type format, texture2d::extent_type extent, texture2d::size_type levels) try {
                 return texture2d(format, extent, levels);
-            }
+            } GLI_BINDING_CATCH(texture2d)
 
-            texture2d tex2d_new_with_mipmap_chain(texture2d::format_type format, texture2d::extent_type extent) {
+            texture2d tex2d_new_with_mipmap_chain(texture2d::format_type format, texture2d::extent_type extent) try {
                 return texture2d(format, extent);
-            }
+            } GLI_BINDING_CATCH(texture2d)
 
-            texture2d tex2d_share_from(const gli::texture & tex) {
+            texture2d tex2d_share_from(const gli::texture & tex) try {
                 return texture2d(tex);
-            }
+            } GLI_BINDING_CATCH(texture2d)
 
             texture2d tex2d_share_from_detail(
                 const gli::texture & tex,
                 texture2d::format_type format,
                 texture2d::size_type base_layer, texture2d::size_type max_layer,
                 texture2d::size_type base_face,  texture2d::size_type  max_face,
-                texture2d::size_type base_level, texture2d::size_type max_level) {
+                texture2d::size_type base_level, texture2d::size_type max_level) try {
 
                 return texture2d(tex, format, base_layer, max_layer, base_face, max_face, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture2d)
 
-            texture2d tex2d_share_from_subset(const texture2d & tex, texture2d::size_type base_level, texture2d::size_type max_level) {
+            texture2d tex2d_share_from_subset(const texture2d & tex, texture2d::size_type base_level, texture2d::size_type max_level) try {
 
                 return texture2d(tex, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture2d)
 
-            texture2d::extent_type tex2d_extent(const texture2d & tex, texture2d::size_type level = 0) {
+            texture2d::extent_type tex2d_extent(const texture2d & tex, texture2d::size_type level = 0) try {
                 return tex.extent(level);
-            }
+            } GLI_BINDING_CATCH(texture2d::extent_type)
         }
     }
 }
@@ -74,43 +74,43 @@ extern "C" {
 
             using gli::texture2d_array;
 
-            texture2d_array tex2darray_new_empty() {
+            texture2d_array tex2darray_new_empty() try {
                 return texture2d_array();
-            }
+            } GLI_BINDING_CATCH(texture2d_array)
 
-            texture2d_array tex2darray_new_(texture2d_array::format_type format, texture2d_array::extent_type extent, texture2d_array::size_type layers, texture2d_array::size_type levels) {
+            texture2d_array tex2darray_new_(texture2d_array::format_type format, texture2d_array::extent_type extent, texture2d_array::size_type layers, texture2d_array::size_type levels) try {
                 return texture2d_array(format, extent, layers, levels);
-            }
+            } GLI_BINDING_CATCH(texture2d_array)
 
-            texture2d_array tex2darray_new_with_mipmap_chain(texture2d_array::format_type format, texture2d_array::extent_type extent, texture2d_array::size_type layers) {
+            texture2d_array tex2darray_new_with_mipmap_chain(texture2d_array::format_type format, texture2d_array::extent_type extent, texture2d_array::size_type layers) try {
                 return texture2d_array(format, extent, layers);
-            }
+            } GLI_BINDING_CATCH(texture2d_array)
 
-            texture2d_array tex2darray_share_from(const gli::texture & tex) {
+            texture2d_array tex2darray_share_from(const gli::texture & tex) try {
                 return texture2d_array(tex);
-            }
+            } GLI_BINDING_CATCH(texture2d_array)
 
             texture2d_array tex2darray_share_from_detail(
                 const gli::texture & tex,
                 texture2d_array::format_type format,
                 texture2d_array::size_type base_layer, texture2d_array::size_type max_layer,
                 texture2d_array::size_type base_face,  texture2d_array::size_type  max_face,
-                texture2d_array::size_type base_level, texture2d_array::size_type max_level) {
+                texture2d_array::size_type base_level, texture2d_array::size_type max_level) try {
 
                 return texture2d_array(tex, format, base_layer, max_layer, base_face, max_face, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture2d_array)
 
             texture2d_array tex2darray_share_from_subset(
                 const texture2d_array & tex,
                 texture2d_array::size_type base_layer, texture2d_array::size_type max_layer,
-                texture2d_array::size_type base_level, texture2d_array::size_type max_level) {
+                texture2d_array::size_type base_level, texture2d_array::size_type max_level) try {
 
                 return texture2d_array(tex, base_layer, max_layer, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture2d_array)
 
-            texture2d_array::extent_type tex2darray_extent(const texture2d_array & tex, texture2d_array::size_type level = 0) {
+            texture2d_array::extent_type tex2darray_extent(const texture2d_array & tex, texture2d_array::size_type level = 0) try {
                 return tex.extent(level);
-            }
+            } GLI_BINDING_CATCH(texture2d_array::extent_type)
         }
     }
 }
@@ -73,40 +73,40 @@ namespace bindings {
 
         using gli::texture3d;
 
-        texture3d tex3d_new_empty() {
+        texture3d tex3d_new_empty() try {
             return texture3d();
-        }
+        } GLI_BINDING_CATCH(texture3d)
 
-        texture3d tex3d_new_(texture3d::format_type format, texture3d::extent_type extent, texture3d::size_type levels) {
+        texture3d tex3d_new_(texture3d::format_type format, texture3d::extent_type extent, texture3d::size_type levels) try {
             return texture3d(format, extent, levels);
-        }
+        } GLI_BINDING_CATCH(texture3d)
 
-        texture3d tex3d_new_with_mipmap_chain(texture3d::format_type format, texture3d::extent_type extent) {
+        texture3d tex3d_new_with_mipmap_chain(texture3d::format_type format, texture3d::extent_type extent) try {
             return texture3d(format, extent);
-        }
+        } GLI_BINDING_CATCH(texture3d)
 
-        texture3d tex3d_share_from(const gli::texture & tex) {
+        texture3d tex3d_share_from(const gli::texture & tex) try {
             return texture3d(tex);
-        }
+        } GLI_BINDING_CATCH(texture3d)
 
         texture3d tex3d_share_from_detail(
             const gli::texture & tex,
             texture3d::format_type format,
             texture3d::size_type base_layer, texture3d::size_type max_layer,
             texture3d::size_type base_face,  texture3d::size_type  max_face,
-            texture3d::size_type base_level, texture3d::size_type max_level) {
+            texture3d::size_type base_level, texture3d::size_type max_level) try {
 
             return texture3d(tex, format, base_layer, max_layer, base_face, max_face, base_level, max_level);
-        }
+        } GLI_BINDING_CATCH(texture3d)
 
-        texture3d tex3d_share_from_subset(const texture3d & tex, texture3d::size_type base_level, texture3d::size_type max_level) {
+        texture3d tex3d_share_from_subset(const texture3d & tex, texture3d::size_type base_level, texture3d::size_type max_level) try {
 
             return texture3d(tex, base_level, max_level);
-        }
+        } GLI_BINDING_CATCH(texture3d)
 
-        texture3d::extent_type tex3d_extent(const texture3d & tex, texture3d::size_type level = 0) {
+        texture3d::extent_type tex3d_extent(const texture3d & tex, texture3d::size_type level = 0) try {
             return tex.extent(level);
-        }
+        } GLI_BINDING_CATCH(texture3d::extent_type)
     }
 }
 }
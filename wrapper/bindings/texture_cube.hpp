@@ -72,43 +72,43 @@ namespace bindings {
 
         using gli::texture_cube;
 
-        texture_cube texcube_new_empty() {
+        texture_cube texcube_new_empty() try {
             return texture_cube();
-        }
+        } GLI_BINDING_CATCH(texture_cube)
 
-        texture_cube texcube_new_(texture_cube::format_type format, texture_cube::extent_type extent, texture_cube::size_type levels) {
+        texture_cube texcube_new_(texture_cube::format_type format, texture_cube::extent_type extent, texture_cube::size_type levels) try {
             return texture_cube(format, extent, levels);
-        }
+        } GLI_BINDING_CATCH(texture_cube)
 
-        texture_cube texcube_new_with_mipmap_chain(texture_cube::format_type format, texture_cube::extent_type extent) {
+        texture_cube texcube_new_with_mipmap_chain(texture_cube::format_type format, texture_cube::extent_type extent) try {
             return texture_cube(format, extent);
-        }
+        } GLI_BINDING_CATCH(texture_cube)
 
-        texture_cube texcube_share_from(const gli::texture & tex) {
+        texture_cube texcube_share_from(const gli::texture & tex) try {
             return texture_cube(tex);
-        }
+        } GLI_BINDING_CATCH(texture_cube)
 
         texture_cube texcube_share_from_detail(
             const gli::texture & tex,
             texture_cube::format_type format,
             texture_cube::size_type base_layer, texture_cube::size_type max_layer,
             texture_cube::size_type base_face,  texture_cube::size_type  max_face,
-            texture_cube::size_type base_level, texture_cube::size_type max_level) {
+            texture_cube::size_type base_level, texture_cube::size_type max_level) try {
 
             return texture_cube(tex, format, base_layer, max_layer, base_face, max_face, base_level, max_level);
-        }
+        } GLI_BINDING_CATCH(texture_cube)
 
         texture_cube texcube_share_from_subset(
             const texture_cube & tex,
             texture_cube::size_type base_face, texture_cube::size_type max_face,
-            texture_cube::size_type base_level, texture_cube::size_type max_level) {
+            texture_cube::size_type base_level, texture_cube::size_type max_level) try {
 
             return texture_cube(tex, base_face, max_face, base_level, max_level);
-        }
+        } GLI_BINDING_CATCH(texture_cube)
 
-        texture_cube::extent_type texcube_extent(const texture_cube & tex, texture_cube::size_type level = 0) {
+        texture_cube::extent_type texcube_extent(const texture_cube & tex, texture_cube::size_type level = 0) try {
             return tex.extent(level);
-        }
+        } GLI_BINDING_CATCH(texture_cube::extent_type)
     }
 }
 }
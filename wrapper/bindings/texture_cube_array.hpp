@@ -76,44 +76,44 @@ extern "C" {
 
             using gli::texture_cube_array;
 
-            texture_cube_array texcubearray_new_empty() {
+            texture_cube_array texcubearray_new_empty() try {
                 return texture_cube_array();
-            }
+            } GLI_BINDING_CATCH(texture_cube_array)
 
-            texture_cube_array texcubearray_new_(texture_cube_array::format_type format, texture_cube_array::extent_type extent, texture_cube_array::size_type layers, texture_cube_array::size_type levels) {
+            texture_cube_array texcubearray_new_(texture_cube_array::format_type format, texture_cube_array::extent_type extent, texture_cube_array::size_type layers, texture_cube_array::size_type levels) try {
                 return texture_cube_array(format, extent, layers, levels);
-            }
+            } GLI_BINDING_CATCH(texture_cube_array)
 
-            texture_cube_array texcubearray_new_with_mipmap_chain(texture_cube_array::format_type format, texture_cube_array::extent_type extent, texture_cube_array::size_type layers) {
+            texture_cube_array texcubearray_new_with_mipmap_chain(texture_cube_array::format_type format, texture_cube_array::extent_type extent, texture_cube_array::size_type layers) try {
                 return texture_cube_array(format, extent, layers);
-            }
+            } GLI_BINDING_CATCH(texture_cube_array)
 
-            texture_cube_array texcubearray_share_from(const gli::texture & tex) {
+            texture_cube_array texcubearray_share_from(const gli::texture & tex) try {
                 return texture_cube_array(tex);
-            }
+            } GLI_BINDING_CATCH(texture_cube_array)
 
             texture_cube_array texcubearray_share_from_detail(
                 const gli::texture & tex,
                 texture_cube_array::format_type format,
                 texture_cube_array::size_type base_layer, texture_cube_array::size_type max_layer,
                 texture_cube_array::size_type base_face,  texture_cube_array::size_type  max_face,
-                texture_cube_array::size_type base_level, texture_cube_array::size_type max_level) {
+                texture_cube_array::size_type base_level, texture_cube_array::size_type max_level) try {
 
                 return texture_cube_array(tex, format, base_layer, max_layer, base_face, max_face, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture_cube_array)
 
             texture_cube_array texcubearray_share_from_subset(
                 const texture_cube_array & tex,
                 texture_cube_array::size_type base_layer, texture_cube_array::size_type max_layer,
                 texture_cube_array::size_type base_face,  texture_cube_array::size_type max_face,
-                texture_cube_array::size_type base_level, texture_cube_array::size_type max_level) {
+                texture_cube_array::size_type base_level, texture_cube_array::size_type max_level) try {
 
                 return texture_cube_array(tex, base_layer, max_layer, base_face, max_face, base_level, max_level);
-            }
+            } GLI_BINDING_CATCH(texture_cube_array)
 
-            texture_cube_array::extent_type texcubearray_extent(const texture_cube_array & tex, texture_cube_array::size_type level = 0) {
+            texture_cube_array::extent_type texcubearray_extent(const texture_cube_array & tex, texture_cube_array::size_type level = 0) try {
                 return tex.extent(level);
-            }
+            } GLI_BINDING_CATCH(texture_cube_array::extent_type)
         }
     }
 }
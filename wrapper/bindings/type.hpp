@@ -18,12 +18,16 @@
 #	define GLI_FORCE_INLINE inline
 #endif//GLM_COMPILER
 
+#include "error.hpp"
+
 #define GLI_DISABLE_ASSERT 0
 
-#if defined(NDEBUG) || GLI_DISABLE_ASSERT
+// A failed assertion throws in every build, release included, so the binding which called gli reports an error instead of
+// aborting the process. The errors of the sampler bindings become panics on the Rust side, see `call_native` in src/sampler.rs.
+#if GLI_DISABLE_ASSERT
 #	define GLI_ASSERT(test)
 #else
-#	define GLI_ASSERT(test) assert((test))
+#	define GLI_ASSERT(test) ((test) ? static_cast<void>(0) : throw gli::assertion_error(#test, __FILE__, __LINE__))
 #endif
 
 extern "C" {
@@ -10,14 +10,14 @@ extern "C" {
             float content[4];
         };
 
-        TexelType4F vec4ToTex4F(gli::vec4 raw) {
+        TexelType4F vec4ToTex4F(gli::vec4 raw) try {
             TexelType4F value;
             value.content[0] = raw[0];
             value.content[1] = raw[1];
             value.content[2] = raw[2];
             value.content[3] = raw[3];
             return value;
-        }
+        } GLI_BINDING_CATCH(TexelType4F)
     }
 }
 